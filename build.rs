@@ -1,7 +1,26 @@
+use cc::Build;
 use cmake::Config;
 use fs_extra::{dir, dir::CopyOptions};
 use std::{env, error::Error, fs, path::Path};
 
+/// Primary monitor query of raylib, failing on the offscreen platform of GLFW (without monitors).
+const MONITOR_QUERY: &str = "    GLFWmonitor *monitor = glfwGetPrimaryMonitor();
+    if (!monitor)
+    {
+        TRACELOG(LOG_WARNING, \"GLFW: Failed to get primary monitor\");
+        return false;
+    }
+    const GLFWvidmode *mode = glfwGetVideoMode(monitor);
+
+    CORE.Window.display.width = mode->width;
+    CORE.Window.display.height = mode->height;
+";
+
+/// Offscreen replacement of the primary monitor query, the display being the screen.
+const OFFSCREEN_DISPLAY: &str = "    CORE.Window.display.width = CORE.Window.screen.width;
+    CORE.Window.display.height = CORE.Window.screen.height;
+";
+
 fn main() -> Result<(), Box<dyn Error>> {
     // Computes paths
     let source_directory = env::var("CARGO_MANIFEST_DIR")?;
@@ -21,6 +40,29 @@ fn main() -> Result<(), Box<dyn Error>> {
         },
     )?;
 
+    // Applies patches, appended to the copied sources to keep the vendored ones untouched
+    for (file, patch) in &[
+        ("raudio.c", "raudio_seek_music_stream.c"),
+        ("models.c", "models_gltf_animations.c"),
+        ("models.c", "models_load_model_checked.c"),
+    ] {
+        let path = raylib_destination_directory.join("src").join(file);
+        let mut source = fs::read_to_string(&path)?;
+        source += &fs::read_to_string(source_directory.join("external/patches").join(patch))?;
+        fs::write(&path, source)?;
+    }
+    // Lets raylib start without monitors on the offscreen platform of GLFW (OSMesa), which then
+    // replaces the windowing system for every window of the program
+    let headless = env::var("CARGO_FEATURE_HEADLESS").is_ok();
+    if headless {
+        let path = raylib_destination_directory.join("src/core.c");
+        let source = fs::read_to_string(&path)?;
+        if !source.contains(MONITOR_QUERY) {
+            return Err("raylib primary monitor query not found".into());
+        }
+        fs::write(&path, source.replace(MONITOR_QUERY, OFFSCREEN_DISPLAY))?;
+    }
+
     // Compiles raylib
     let mut config = Config::new(&raylib_destination_directory);
     let build_directory = config
@@ -32,22 +74,41 @@ fn main() -> Result<(), Box<dyn Error>> {
         .define("SUPPORT_COMPRESSION_API", "OFF")
         .define("SUPPORT_VR_SIMULATOR", "OFF")
         .define("SUPPORT_QUADS_DRAW_MODE", "OFF")
-        .define("SUPPORT_FILEFORMAT_OBJ", "OFF")
-        .define("SUPPORT_FILEFORMAT_MTL", "OFF")
-        .define("SUPPORT_FILEFORMAT_IQM", "OFF")
-        .define("SUPPORT_FILEFORMAT_GLTF", "OFF")
-        .define("SUPPORT_MESH_GENERATION", "OFF")
-        .define("SUPPORT_FILEFORMAT_WAV", "OFF")
-        .define("SUPPORT_FILEFORMAT_OGG", "OFF")
-        .define("SUPPORT_FILEFORMAT_XM", "OFF")
-        .define("SUPPORT_FILEFORMAT_MOD", "OFF")
-        .define("SUPPORT_FILEFORMAT_FLAC", "OFF")
-        .define("SUPPORT_FILEFORMAT_MP3", "OFF")
+        .define("SUPPORT_MESH_GENERATION", "ON")
+        .define("SUPPORT_FILEFORMAT_BMP", "ON")
+        .define("SUPPORT_FILEFORMAT_TGA", "ON")
+        .define("SUPPORT_FILEFORMAT_JPG", "ON")
+        .define("SUPPORT_FILEFORMAT_GIF", "ON")
+        .define("SUPPORT_FILEFORMAT_PSD", "ON")
+        .define("SUPPORT_FILEFORMAT_WAV", feature("WAV"))
+        .define("SUPPORT_FILEFORMAT_OGG", feature("OGG"))
+        .define("SUPPORT_FILEFORMAT_XM", feature("XM"))
+        .define("SUPPORT_FILEFORMAT_MOD", feature("MOD"))
+        .define("SUPPORT_FILEFORMAT_FLAC", feature("FLAC"))
+        .define("SUPPORT_FILEFORMAT_MP3", feature("MP3"))
+        .define("SUPPORT_FILEFORMAT_OBJ", feature("OBJ"))
+        .define("SUPPORT_FILEFORMAT_MTL", feature("OBJ"))
+        .define("SUPPORT_FILEFORMAT_IQM", feature("IQM"))
+        .define("SUPPORT_FILEFORMAT_GLTF", feature("GLTF"))
         .define("SUPPORT_TRACELOG", "OFF")
+        .define("GLFW_USE_OSMESA", feature("HEADLESS"))
         .define("STATIC", "TRUE")
         .build();
     fs::remove_dir_all(&raylib_destination_directory)?;
 
+    // Compiles stb_truetype (kept private by raylib) for fonts loaded from memory
+    let stb_truetype_path = destination_directory.join("stb_truetype.c");
+    fs::write(
+        &stb_truetype_path,
+        "#define STB_TRUETYPE_IMPLEMENTATION\n#include \"stb_truetype.h\"\n\n\
+         const size_t rayquaza_stbtt_fontinfo_size = sizeof(stbtt_fontinfo);\n",
+    )?;
+    Build::new()
+        .file(&stb_truetype_path)
+        .include(raylib_source_directory.join("src/external"))
+        .warnings(false)
+        .compile("stb_truetype");
+
     // Links libraries
     println!(
         "cargo:rustc-link-search=native={}",
@@ -55,7 +116,9 @@ fn main() -> Result<(), Box<dyn Error>> {
     );
     if cfg!(target_os = "linux") {
         println!("cargo:rustc-link-lib=static=raylib");
-        println!("cargo:rustc-link-lib=X11");
+        if !headless {
+            println!("cargo:rustc-link-lib=X11");
+        }
     } else if cfg!(target_os = "windows") {
         println!("cargo:rustc-link-lib=static=raylib_static");
         println!("cargo:rustc-link-lib=dylib=winmm");
@@ -71,3 +134,12 @@ fn main() -> Result<(), Box<dyn Error>> {
     }
     Ok(())
 }
+
+/// Returns the CMake option value matching the Cargo feature state.
+fn feature(name: &str) -> &'static str {
+    if env::var(format!("CARGO_FEATURE_{}", name)).is_ok() {
+        "ON"
+    } else {
+        "OFF"
+    }
+}
@@ -0,0 +1,58 @@
+use rayquaza::{
+    camera::{Camera3D, Projection},
+    color::Color,
+    input::Key,
+    math::{BoundingBox, Vector3},
+    result::Result,
+    window::WindowBuilder,
+};
+
+const ORBIT_SPEED: f32 = 0.5;
+
+fn main() -> Result {
+    let window = WindowBuilder::new()
+        .title("3D camera")
+        .resizable()
+        .vsync()
+        .msaa_4x()
+        .build()?;
+    let mut camera = Camera3D::new(
+        Vector3::new(10.0, 10.0, 10.0),
+        Vector3::ZERO,
+        Vector3::new(0.0, 1.0, 0.0),
+        45.0,
+        Projection::Perspective,
+    );
+    let bounding_box =
+        BoundingBox::new(Vector3::new(-3.5, 0.0, -1.5), Vector3::new(-0.5, 3.0, 1.5));
+    while !window.should_close() {
+        if window.is_key_pressed(Key::Space) {
+            camera.projection = match camera.projection {
+                Projection::Perspective => Projection::Orthographic,
+                Projection::Orthographic => Projection::Perspective,
+            };
+            camera.fovy = match camera.projection {
+                Projection::Perspective => 45.0,
+                Projection::Orthographic => 20.0,
+            };
+        }
+        let angle = window.time() as f32 * ORBIT_SPEED;
+        camera.position = Vector3::new(angle.cos() * 14.0, 10.0, angle.sin() * 14.0);
+        window.draw(|canvas| {
+            canvas.clear_background(Color::RAYWHITE);
+            canvas.mode_3d(camera, |canvas| {
+                canvas.draw_plane(Vector3::ZERO, (20.0, 20.0), Color::LIGHTGRAY);
+                canvas.draw_cube((-2.0, 1.5, 0.0), 2.0, 2.0, 2.0, Color::RED);
+                canvas.draw_cube_wires((-2.0, 1.5, 0.0), 2.0, 2.0, 2.0, Color::MAROON);
+                canvas.draw_bounding_box(bounding_box, Color::GREEN);
+                canvas.draw_sphere((2.0, 1.0, 0.0), 1.0, Color::BLUE);
+                canvas.draw_sphere_wires((2.0, 1.0, 0.0), 1.0, 16, 16, Color::DARKBLUE);
+                canvas.draw_cylinder((0.0, 0.0, -4.0), 0.5, 1.5, 3.0, 16, Color::GOLD);
+                canvas.draw_line_3d((0.0, 0.0, 4.0), (0.0, 4.0, 4.0), Color::BLACK);
+                canvas.draw_grid(20, 1.0);
+            });
+            canvas.draw_text("Space to switch projection", 10, 10, 20, Color::DARKGRAY);
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use rayquaza::{
+    action::{ActionMap, AxisBinding, Binding},
+    color::Color,
+    input::{Gamepad, GamepadAxis, GamepadButton, Key},
+    math::Vector2,
+    result::Result,
+    window::WindowBuilder,
+};
+
+const SPEED: f32 = 300.0;
+
+fn main() -> Result {
+    let window = WindowBuilder::new()
+        .title("Action map")
+        .resizable()
+        .vsync()
+        .build()?;
+    let mut actions = ActionMap::new();
+    actions.bind_axis("move_x", AxisBinding::digital(Key::Left, Key::Right));
+    actions.bind_axis(
+        "move_x",
+        AxisBinding::analog(Gamepad::One, GamepadAxis::LeftX),
+    );
+    actions.bind_axis("move_y", AxisBinding::digital(Key::Up, Key::Down));
+    actions.bind_axis(
+        "move_y",
+        AxisBinding::analog(Gamepad::One, GamepadAxis::LeftY),
+    );
+    actions.bind_action("grow", Key::Space);
+    actions.bind_action("grow", (Gamepad::One, GamepadButton::RightFaceDown));
+    actions.bind_action("rebind", Binding::new(Key::R).modifier(Key::LeftControl));
+    let mut position = Vector2::new(400.0, 225.0);
+    let mut radius = 20.0;
+    let mut rebinding = false;
+    while !window.should_close() {
+        actions.update(&window);
+        if rebinding {
+            if let Some(input) = actions.detect_input(&window) {
+                actions.set_action_bindings("grow", vec![Binding::new(input)]);
+                rebinding = false;
+            }
+        } else if actions.is_action_pressed("rebind") {
+            rebinding = true;
+        }
+        let movement = Vector2::new(actions.axis_value("move_x"), actions.axis_value("move_y"));
+        position += movement * SPEED * window.frame_time();
+        if actions.is_action_pressed("grow") {
+            radius += 10.0;
+        }
+        window.draw(|canvas| {
+            canvas.clear_background(Color::RAYWHITE);
+            canvas.draw_circle_vec(position, radius, Color::MAROON);
+            let text = if rebinding {
+                "Press any input to bind the grow action".to_string()
+            } else {
+                format!("Grow: {:?}", actions.action_bindings("grow"))
+            };
+            canvas.draw_text(&text, 10, 10, 10, Color::DARKGRAY);
+            canvas.draw_text("Ctrl + R to rebind", 10, 30, 10, Color::GRAY);
+        });
+    }
+    Ok(())
+}
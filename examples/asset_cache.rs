@@ -0,0 +1,44 @@
+use rayquaza::{asset::Assets, color::Color, result::Result, text::CharSet, window::WindowBuilder};
+
+fn main() -> Result {
+    let window = WindowBuilder::new().title("Asset cache").vsync().build()?;
+    let mut assets = Assets::new();
+    // Every sprite shares the same texture, loaded once
+    let sprites = (0..100)
+        .map(|index| {
+            let texture = assets.texture(&window, "resources/image/logo.png")?;
+            Ok((texture, (index % 10) * 70, (index / 10) * 40))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let font = assets.font(
+        &window,
+        "resources/font/pacifico.ttf",
+        32,
+        &CharSet::ascii(),
+    )?;
+    let stats = assets.stats();
+    let text = format!(
+        "{} texture(s), {} font(s), {} KiB of VRAM",
+        stats.textures,
+        stats.fonts,
+        stats.video_memory / 1024
+    );
+    while !window.should_close() {
+        window.draw(|canvas| {
+            canvas.clear_background(Color::RAYWHITE);
+            for (texture, x, y) in &sprites {
+                let texture = texture.borrow();
+                canvas.draw_texture_ex(&texture, (*x as f32, *y as f32), 0.0, 0.25, Color::WHITE);
+            }
+            canvas.draw_text_ex(
+                &font.borrow(),
+                &text,
+                (10.0, 400.0),
+                32.0,
+                0.0,
+                Color::MAROON,
+            );
+        });
+    }
+    Ok(())
+}
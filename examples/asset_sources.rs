@@ -0,0 +1,40 @@
+use rayquaza::{
+    asset::{DirectorySource, EmbeddedSource},
+    color::Color,
+    result::Result,
+    text::CharSet,
+    window::WindowBuilder,
+};
+
+fn main() -> Result {
+    let mut window = WindowBuilder::new()
+        .title("Asset sources")
+        .vsync()
+        .build()?;
+    // Base assets, then a "mod" overriding the font
+    window.mount_assets("", DirectorySource::new("resources"));
+    window.mount_assets(
+        "font",
+        EmbeddedSource::new().add(
+            "title.ttf",
+            include_bytes!("../resources/font/pacifico.ttf"),
+        ),
+    );
+    let texture = window.load_texture("image/logo.png")?;
+    let font = window.load_font_ex("font/title.ttf", 48, &CharSet::ascii())?;
+    while !window.should_close() {
+        window.draw(|canvas| {
+            canvas.clear_background(Color::RAYWHITE);
+            canvas.draw_texture(&texture, 20, 20, Color::WHITE);
+            canvas.draw_text_ex(
+                &font,
+                "Loaded from mounted sources",
+                (20.0, 40.0 + texture.height() as f32),
+                48.0,
+                0.0,
+                Color::DARKGRAY,
+            );
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use rayquaza::{
+    audio::AudioDevice, color::Color, input::Key, result::Result, window::WindowBuilder,
+};
+use std::f32::consts::PI;
+
+const SAMPLE_RATE: u32 = 22050;
+const BUFFER_SIZE: usize = 4096;
+
+fn main() -> Result {
+    let window = WindowBuilder::new()
+        .title("Audio stream")
+        .resizable()
+        .vsync()
+        .build()?;
+    let audio = AudioDevice::init()?;
+    let mut stream = audio.load_audio_stream::<i16>(SAMPLE_RATE, 1)?;
+    let mut samples = vec![0; BUFFER_SIZE];
+    let mut frequency = 440.0;
+    let mut phase = 0.0;
+    stream.play();
+    while !window.should_close() {
+        if window.is_key_down(Key::Up) {
+            frequency += 100.0 * window.frame_time();
+        }
+        if window.is_key_down(Key::Down) {
+            frequency = (frequency - 100.0 * window.frame_time()).max(20.0);
+        }
+        if stream.is_processed() {
+            for sample in samples.iter_mut() {
+                *sample = ((phase * 2.0 * PI).sin() * 16000.0) as i16;
+                phase = (phase + frequency / SAMPLE_RATE as f32).fract();
+            }
+            stream.update(&samples);
+        }
+        window.draw(|canvas| {
+            canvas.clear_background(Color::RAYWHITE);
+            canvas.draw_text(
+                &format!("Sine wave frequency: {:.0} Hz", frequency),
+                20,
+                20,
+                20,
+                Color::DARKGRAY,
+            );
+            canvas.draw_text(
+                "Up / Down keys to change frequency",
+                20,
+                50,
+                10,
+                Color::GRAY,
+            );
+        });
+    }
+    Ok(())
+}
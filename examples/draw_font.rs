@@ -1,4 +1,4 @@
-use rayquaza::{color::Color, math::Vector2, result::Result, window::WindowBuilder};
+use rayquaza::{color::Color, math::Vector2, result::Result, text::CharSet, window::WindowBuilder};
 
 fn main() -> Result {
     let window = WindowBuilder::new()
@@ -6,7 +6,7 @@ fn main() -> Result {
         .resizable()
         .vsync()
         .build()?;
-    let font = window.load_font_ex("resources/font/pacifico.ttf", 100)?;
+    let font = window.load_font_ex("resources/font/pacifico.ttf", 100, &CharSet::ascii())?;
     let text = "Hello world!";
     let size = window.measure_text_ex(&font, text, 100.0, 0.0);
     while !window.should_close() {
@@ -0,0 +1,38 @@
+use rayquaza::{color::Color, math::Vector2, result::Result, text::CharSet, window::WindowBuilder};
+
+const LOGO: &[u8] = include_bytes!("../resources/image/logo.png");
+const FONT: &[u8] = include_bytes!("../resources/font/pacifico.ttf");
+
+fn main() -> Result {
+    let window = WindowBuilder::new()
+        .title("Embedded assets")
+        .vsync()
+        .build()?;
+    let texture = window.load_texture_from_memory(LOGO, "png")?;
+    let font = window.load_font_from_memory(FONT, "ttf", 60, &CharSet::ascii())?;
+    let text = "Embedded!";
+    let size = window.measure_text_ex(&font, text, 60.0, 0.0);
+    while !window.should_close() {
+        window.draw(|canvas| {
+            canvas.clear_background(Color::RAYWHITE);
+            canvas.draw_texture(
+                &texture,
+                window.width() / 2 - texture.width() / 2,
+                window.height() / 2 - texture.height() / 2 - 40,
+                Color::WHITE,
+            );
+            canvas.draw_text_ex(
+                &font,
+                text,
+                Vector2::new(
+                    window.width() as f32 / 2.0 - size.x / 2.0,
+                    window.height() as f32 / 2.0 + texture.height() as f32 / 2.0 - 20.0,
+                ),
+                60.0,
+                0.0,
+                Color::DARKGRAY,
+            );
+        });
+    }
+    Ok(())
+}
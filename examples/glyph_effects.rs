@@ -0,0 +1,57 @@
+use rayquaza::{
+    color::Color,
+    math::{Rectangle, Vector2},
+    result::Result,
+    text::CharSet,
+    window::WindowBuilder,
+};
+
+const TEXT: &str = "Wavy typewriter text!";
+const COLORS: [Color; 6] = [
+    Color::RED,
+    Color::ORANGE,
+    Color::GOLD,
+    Color::LIME,
+    Color::SKYBLUE,
+    Color::VIOLET,
+];
+
+fn main() -> Result {
+    let window = WindowBuilder::new()
+        .title("Glyph effects")
+        .vsync()
+        .build()?;
+    let font = window.load_font_ex("resources/font/pacifico.ttf", 48, &CharSet::ascii())?;
+    while !window.should_close() {
+        let time = window.time() as f32;
+        // Types 10 characters per second, then starts over
+        let count = (time * 10.0) as usize % (TEXT.len() + 20);
+        window.draw(|canvas| {
+            canvas.clear_background(Color::RAYWHITE);
+            let mut x = 40.0;
+            for (index, char) in TEXT.chars().take(count).enumerate() {
+                let glyph = match font.glyph(char) {
+                    Some(glyph) => glyph,
+                    None => continue,
+                };
+                let wave = (time * 4.0 + index as f32 * 0.5).sin() * 10.0;
+                let position = Vector2::new(x, 180.0 + wave) + glyph.offset;
+                canvas.draw_texture_pro(
+                    font.texture(),
+                    glyph.rectangle,
+                    Rectangle::new(
+                        position.x,
+                        position.y,
+                        glyph.rectangle.width,
+                        glyph.rectangle.height,
+                    ),
+                    Vector2::ZERO,
+                    0.0,
+                    COLORS[index % COLORS.len()],
+                );
+                x += glyph.advance;
+            }
+        });
+    }
+    Ok(())
+}
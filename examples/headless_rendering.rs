@@ -0,0 +1,22 @@
+use rayquaza::{color::Color, image::ExportImageError, result::Result, window::WindowBuilder};
+
+fn main() -> Result {
+    let window = WindowBuilder::new().headless().build()?;
+    let mut target = window.load_render_texture(320, 180)?;
+    window.draw(|canvas| {
+        canvas.render_to(&mut target, |canvas| {
+            canvas.clear_background(Color::RAYWHITE);
+            canvas.draw_rectangle(0, 0, 160, 90, Color::RED);
+            canvas.draw_circle(240, 120, 40.0, Color::BLUE);
+        });
+    });
+    let image = target
+        .to_image()
+        .ok_or(ExportImageError::UnsupportedFormat)?;
+    if let Some(pixels) = image.pixels() {
+        println!("Top left pixel: {:?}", pixels[0]);
+        println!("Bottom right pixel: {:?}", pixels[pixels.len() - 1]);
+    }
+    image.export_png("headless_rendering.png")?;
+    Ok(())
+}
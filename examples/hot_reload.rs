@@ -0,0 +1,45 @@
+use rayquaza::{
+    asset::{AssetEvent, Assets},
+    color::Color,
+    result::Result,
+    text::CharSet,
+    window::WindowBuilder,
+};
+
+fn main() -> Result {
+    let window = WindowBuilder::new()
+        .title("Hot reload (edit resources/image/logo.png)")
+        .vsync()
+        .build()?;
+    let mut assets = Assets::new();
+    assets.set_hot_reload(true);
+    let texture = assets.texture(&window, "resources/image/logo.png")?;
+    let font = assets.font(
+        &window,
+        "resources/font/pacifico.ttf",
+        32,
+        &CharSet::ascii(),
+    )?;
+    let mut message = String::from("Waiting for changes...");
+    while !window.should_close() {
+        for event in assets.update(&window) {
+            message = match event {
+                AssetEvent::Reloaded(path) => format!("Reloaded {}", path.display()),
+                event => format!("Reload failed: {:?}", event),
+            };
+        }
+        window.draw(|canvas| {
+            canvas.clear_background(Color::RAYWHITE);
+            canvas.draw_texture(&texture.borrow(), 20, 20, Color::WHITE);
+            canvas.draw_text_ex(
+                &font.borrow(),
+                &message,
+                (20.0, 400.0),
+                32.0,
+                0.0,
+                Color::DARKGRAY,
+            );
+        });
+    }
+    Ok(())
+}
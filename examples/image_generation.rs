@@ -0,0 +1,39 @@
+use rayquaza::{color::Color, image::Image, input::Key, result::Result, window::WindowBuilder};
+
+fn main() -> Result {
+    let window = WindowBuilder::new()
+        .title("Image generation")
+        .resizable()
+        .vsync()
+        .build()?;
+    let (width, height) = (window.width(), window.height());
+    let images = vec![
+        Image::gradient_vertical(width, height, Color::RED, Color::BLUE),
+        Image::gradient_horizontal(width, height, Color::RED, Color::BLUE),
+        Image::gradient_radial(width, height, 0.0, Color::WHITE, Color::BLACK),
+        Image::checked(width, height, 32, 32, Color::RED, Color::BLUE),
+        Image::white_noise(width, height, 0.5),
+        Image::perlin_noise(width, height, 50, 50, 4.0),
+        Image::cellular(width, height, 32),
+    ];
+    let mut textures = Vec::with_capacity(images.len());
+    for image in &images {
+        textures.push(window.load_texture_from_image(image)?);
+    }
+    let mut current = 0;
+    while !window.should_close() {
+        if window.is_key_pressed(Key::Right) {
+            current = (current + 1) % textures.len();
+        }
+        if window.is_key_pressed(Key::Left) {
+            current = (current + textures.len() - 1) % textures.len();
+        }
+        window.draw(|canvas| {
+            canvas.clear_background(Color::RAYWHITE);
+            canvas.draw_texture(&textures[current], 0, 0, Color::WHITE);
+            canvas.draw_rectangle(10, 10, 260, 30, Color::SKYBLUE.fade(0.5));
+            canvas.draw_text("Left / Right keys to switch", 20, 20, 10, Color::DARKBLUE);
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use rayquaza::{
+    color::Color,
+    input::{InputEvent, Key},
+    result::Result,
+    window::WindowBuilder,
+};
+
+const LOG_SIZE: usize = 16;
+
+fn main() -> Result {
+    let window = WindowBuilder::new()
+        .title("Input events")
+        .resizable()
+        .vsync()
+        .build()?;
+    let mut text = String::new();
+    let mut log = Vec::with_capacity(LOG_SIZE);
+    while !window.should_close() {
+        for event in window.events() {
+            match event {
+                InputEvent::Char(character) => text.push(character),
+                InputEvent::KeyDown(Key::Backspace) => {
+                    text.pop();
+                }
+                InputEvent::MouseMove(_) => continue,
+                _ => {}
+            }
+            if log.len() == LOG_SIZE {
+                log.remove(0);
+            }
+            log.push(format!("{:?}", event));
+        }
+        window.draw(|canvas| {
+            canvas.clear_background(Color::RAYWHITE);
+            canvas.draw_text("Type some text:", 20, 20, 20, Color::GRAY);
+            canvas.draw_rectangle_lines(20, 50, 760, 40, Color::DARKGRAY);
+            canvas.draw_text(&text, 30, 60, 20, Color::MAROON);
+            for (index, line) in log.iter().enumerate() {
+                canvas.draw_text(line, 20, 110 + index as i32 * 20, 10, Color::DARKGRAY);
+            }
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use rayquaza::{
+    color::Color, input::Key, math::Vector2, recording::InputRecording, result::Result,
+    window::WindowBuilder,
+};
+
+const RECORDING_PATH: &str = "input.rqir";
+const SPEED: f32 = 200.0;
+
+fn main() -> Result {
+    let mut window = WindowBuilder::new()
+        .title("Input recording")
+        .vsync()
+        .build()?;
+    let mut position = Vector2::new(400.0, 225.0);
+    let mut start = position;
+    while !window.should_close() {
+        if window.is_replaying() {
+            if window.is_key_pressed(Key::P) {
+                window.stop_replay();
+            }
+        } else if window.is_key_pressed(Key::R) {
+            if let Some(recording) = window.stop_input_recording() {
+                recording.save(RECORDING_PATH)?;
+            } else {
+                start = position;
+                window.start_input_recording();
+            }
+        } else if window.is_key_pressed(Key::P) && !window.is_input_recording() {
+            let recording = InputRecording::load(RECORDING_PATH)?;
+            position = start;
+            window.start_replay(&recording);
+        }
+        let delta = window.frame_time() * SPEED;
+        if window.is_key_down(Key::Right) {
+            position.x += delta;
+        }
+        if window.is_key_down(Key::Left) {
+            position.x -= delta;
+        }
+        if window.is_key_down(Key::Down) {
+            position.y += delta;
+        }
+        if window.is_key_down(Key::Up) {
+            position.y -= delta;
+        }
+        let status = if window.is_input_recording() {
+            "Recording (R to stop)"
+        } else if window.is_replaying() {
+            "Replaying (P to stop)"
+        } else {
+            "R to record, P to replay"
+        };
+        window.draw(|canvas| {
+            canvas.clear_background(Color::RAYWHITE);
+            canvas.draw_text("Move the ball with the arrow keys", 20, 20, 20, Color::GRAY);
+            canvas.draw_text(status, 20, 50, 20, Color::MAROON);
+            canvas.draw_circle_vec(position, 30.0, Color::DARKBLUE);
+        });
+    }
+    Ok(())
+}
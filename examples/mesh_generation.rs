@@ -0,0 +1,79 @@
+use rayquaza::{
+    asset::AssetHandle,
+    camera::{Camera3D, Projection},
+    color::Color,
+    image::Image,
+    input::Key,
+    math::Vector3,
+    model::MaterialMap,
+    result::Result,
+    window::WindowBuilder,
+};
+
+fn main() -> Result {
+    let window = WindowBuilder::new()
+        .title("Mesh generation")
+        .resizable()
+        .vsync()
+        .msaa_4x()
+        .build()?;
+    let checked = Image::checked(2, 2, 1, 1, Color::RED, Color::GREEN);
+    let texture = AssetHandle::from(window.load_texture_from_image(&checked)?);
+    let heightmap = Image::perlin_noise(64, 64, 0, 0, 4.0);
+    let meshes = vec![
+        window.generate_plane_mesh(2.0, 2.0, 5, 5),
+        window.generate_cube_mesh(2.0, 1.0, 2.0),
+        window.generate_sphere_mesh(2.0, 32, 32),
+        window.generate_hemisphere_mesh(2.0, 16, 16),
+        window.generate_cylinder_mesh(1.0, 2.0, 16),
+        window.generate_torus_mesh(0.25, 4.0, 16, 32),
+        window.generate_knot_mesh(1.0, 2.0, 16, 128),
+        window.generate_poly_mesh(5, 2.0),
+        window.generate_heightmap_mesh(&heightmap, (4.0, 1.0, 4.0)),
+    ];
+    let mut models = Vec::with_capacity(meshes.len());
+    for mesh in meshes {
+        let mut model = window.load_model_from_mesh(mesh);
+        model.set_material_texture(0, MaterialMap::DIFFUSE, texture.clone());
+        models.push(model);
+    }
+    let camera = Camera3D::new(
+        Vector3::new(5.0, 5.0, 5.0),
+        Vector3::ZERO,
+        Vector3::new(0.0, 1.0, 0.0),
+        45.0,
+        Projection::Perspective,
+    );
+    let mut current = 0;
+    while !window.should_close() {
+        if window.is_key_pressed(Key::Right) {
+            current = (current + 1) % models.len();
+        }
+        if window.is_key_pressed(Key::Left) {
+            current = (current + models.len() - 1) % models.len();
+        }
+        let angle = window.time() as f32 * 30.0;
+        window.draw(|canvas| {
+            canvas.clear_background(Color::RAYWHITE);
+            canvas.mode_3d(camera, |canvas| {
+                canvas.draw_model_ex(
+                    &models[current],
+                    Vector3::ZERO,
+                    (0.0, 1.0, 0.0),
+                    angle,
+                    Vector3::ONE,
+                    Color::WHITE,
+                );
+                canvas.draw_grid(10, 1.0);
+            });
+            canvas.draw_text(
+                "Left / Right keys to change mesh",
+                10,
+                10,
+                20,
+                Color::DARKGRAY,
+            );
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use rayquaza::{color::Color, math::Rectangle, result::Result, window::WindowBuilder};
+
+fn main() -> Result {
+    let window = WindowBuilder::new()
+        .title("Render texture")
+        .resizable()
+        .vsync()
+        .build()?;
+    let mut target = window.load_render_texture(200, 200)?;
+    let mut rotation = 0.0;
+    while !window.should_close() {
+        rotation += 90.0 * window.frame_time();
+        window.draw(|canvas| {
+            canvas.render_to(&mut target, |canvas| {
+                canvas.clear_background(Color::SKYBLUE);
+                canvas.draw_polygon((100.0, 100.0), 6, 80.0, rotation, Color::DARKBLUE);
+                canvas.draw_text("Offscreen", 10, 10, 20, Color::WHITE);
+            });
+            canvas.clear_background(Color::RAYWHITE);
+            let width = target.width() as f32;
+            let height = target.height() as f32;
+            canvas.draw_texture_rec(
+                target.texture(),
+                Rectangle::new(0.0, 0.0, width, -height),
+                (
+                    window.width() as f32 / 2.0 - width / 2.0,
+                    window.height() as f32 / 2.0 - height / 2.0,
+                ),
+                Color::WHITE,
+            );
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use rayquaza::{color::Color, input::Key, result::Result, window::WindowBuilder};
+
+fn main() -> Result {
+    let window = WindowBuilder::new().title("Screenshot").vsync().build()?;
+    let mut count = 0;
+    while !window.should_close() {
+        let capture = window.is_key_pressed(Key::F12);
+        let mut result = Ok(());
+        window.draw(|canvas| {
+            canvas.clear_background(Color::RAYWHITE);
+            canvas.draw_circle(400, 225, 100.0, Color::MAROON);
+            canvas.draw_text("Press F12 to take a screenshot", 20, 20, 20, Color::GRAY);
+            canvas.draw_text(
+                &format!("Screenshots: {}", count),
+                20,
+                50,
+                20,
+                Color::DARKGRAY,
+            );
+            if capture {
+                result = window.save_screenshot(format!("screenshot{:03}.png", count));
+                count += 1;
+            }
+        });
+        result?;
+    }
+    Ok(())
+}
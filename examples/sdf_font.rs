@@ -0,0 +1,27 @@
+use rayquaza::{
+    color::Color,
+    result::Result,
+    text::{FontOptions, FontType},
+    window::WindowBuilder,
+};
+
+fn main() -> Result {
+    let window = WindowBuilder::new()
+        .title("SDF font")
+        .resizable()
+        .vsync()
+        .build()?;
+    let options = FontOptions::new().size(16);
+    let bitmap = window.load_font_with("resources/font/pacifico.ttf", &options)?;
+    let options = options.font_type(FontType::Sdf).padding(0);
+    let sdf = window.load_font_with("resources/font/pacifico.ttf", &options)?;
+    while !window.should_close() {
+        let size = 16.0 + (window.time() as f32).sin().abs() * 112.0;
+        window.draw(|canvas| {
+            canvas.clear_background(Color::RAYWHITE);
+            canvas.draw_text_ex(&bitmap, "Bitmap", (20.0, 20.0), size, 0.0, Color::DARKGRAY);
+            canvas.draw_text_ex(&sdf, "SDF", (20.0, 60.0 + size), size, 0.0, Color::DARKGRAY);
+        });
+    }
+    Ok(())
+}
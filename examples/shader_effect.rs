@@ -0,0 +1,57 @@
+use rayquaza::{color::Color, math::Rectangle, result::Result, window::WindowBuilder};
+
+const FRAGMENT_SHADER: &str = r#"
+#version 330
+
+in vec2 fragTexCoord;
+in vec4 fragColor;
+
+uniform sampler2D texture0;
+uniform vec4 colDiffuse;
+uniform float time;
+
+out vec4 finalColor;
+
+void main()
+{
+    vec2 coord = fragTexCoord;
+    coord.x += sin(coord.y * 40.0 + time * 4.0) * 0.005;
+    vec4 color = texture(texture0, coord) * colDiffuse * fragColor;
+    float gray = dot(color.rgb, vec3(0.299, 0.587, 0.114));
+    finalColor = vec4(mix(color.rgb, vec3(gray), 0.5 + 0.5 * sin(time)), color.a);
+}
+"#;
+
+fn main() -> Result {
+    let window = WindowBuilder::new()
+        .title("Shader effect")
+        .vsync()
+        .build()?;
+    let mut shader = window.load_shader_from_source(None, Some(FRAGMENT_SHADER))?;
+    let time_location = shader.location("time");
+    let mut target = window.load_render_texture(window.width(), window.height())?;
+    while !window.should_close() {
+        if let Some(location) = time_location {
+            shader.set_uniform(location, window.time() as f32);
+        }
+        window.draw(|canvas| {
+            canvas.render_to(&mut target, |canvas| {
+                canvas.clear_background(Color::RAYWHITE);
+                canvas.draw_circle(200, 225, 100.0, Color::RED);
+                canvas.draw_rectangle(350, 125, 200, 200, Color::GREEN);
+                canvas.draw_circle(650, 225, 100.0, Color::BLUE);
+            });
+            let width = target.width() as f32;
+            let height = target.height() as f32;
+            canvas.shader_mode(&shader, |canvas| {
+                canvas.draw_texture_rec(
+                    target.texture(),
+                    Rectangle::new(0.0, 0.0, width, -height),
+                    (0.0, 0.0),
+                    Color::WHITE,
+                );
+            });
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use rayquaza::{
+    color::Color,
+    input::Key,
+    result::Result,
+    sprite::{Animation, PlayMode, SpriteSheet, SpriteTransform},
+    window::WindowBuilder,
+};
+
+fn main() -> Result {
+    let window = WindowBuilder::new()
+        .title("Sprite animation")
+        .vsync()
+        .build()?;
+    let texture = window.load_texture("resources/image/logo.png")?;
+    let frame_width = texture.width() / 4;
+    let frame_height = texture.height() / 4;
+    let sheet = SpriteSheet::from_grid(texture, frame_width, frame_height, 0, 0);
+    let regions: Vec<usize> = (0..sheet.region_count()).collect();
+    let mut animation = Animation::from_regions(&regions, 0.1, PlayMode::PingPong);
+    animation.set_event(0, "first frame");
+    let mut transform = SpriteTransform {
+        origin: (frame_width as f32 / 2.0, frame_height as f32 / 2.0).into(),
+        scale: (2.0, 2.0).into(),
+        ..Default::default()
+    };
+    let mut last_event = String::new();
+    while !window.should_close() {
+        if let Some(event) = animation.update(&window).last() {
+            last_event = event.to_string();
+        }
+        if window.is_key_pressed(Key::H) {
+            transform.flip_x = !transform.flip_x;
+        }
+        if window.is_key_pressed(Key::V) {
+            transform.flip_y = !transform.flip_y;
+        }
+        transform.rotation += window.frame_time() * 45.0;
+        window.draw(|canvas| {
+            canvas.clear_background(Color::RAYWHITE);
+            canvas.draw_animation(&sheet, &animation, (400.0, 225.0), &transform, Color::WHITE);
+            canvas.draw_text("H/V to flip the sprite", 20, 20, 20, Color::GRAY);
+            canvas.draw_text(
+                &format!(
+                    "Frame {}, last event: {}",
+                    animation.current_frame(),
+                    last_event
+                ),
+                20,
+                50,
+                20,
+                Color::DARKGRAY,
+            );
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use rayquaza::{
+    color::Color,
+    input::MouseButton,
+    layout::{HorizontalAlignment, TextLayout, VerticalAlignment},
+    math::Rectangle,
+    result::Result,
+    text::CharSet,
+    window::WindowBuilder,
+};
+
+const TEXT: &str = "Text layouts wrap words within their bounds, align lines and \
+                    truncate what does not fit with an ellipsis.";
+
+fn main() -> Result {
+    let window = WindowBuilder::new()
+        .title("Text layout")
+        .resizable()
+        .vsync()
+        .build()?;
+    let title = window.load_font_ex("resources/font/pacifico.ttf", 32, &CharSet::ascii())?;
+    let font = window.load_font_ex("resources/font/pacifico.ttf", 20, &CharSet::ascii())?;
+    let alignments = [
+        HorizontalAlignment::Left,
+        HorizontalAlignment::Center,
+        HorizontalAlignment::Right,
+        HorizontalAlignment::Justify,
+    ];
+    let mut alignment = 0;
+    while !window.should_close() {
+        if window.is_mouse_button_pressed(MouseButton::Right) {
+            alignment = (alignment + 1) % alignments.len();
+        }
+        let bounds = Rectangle::new(
+            40.0,
+            40.0,
+            window.mouse_x().max(80) as f32 - 40.0,
+            window.mouse_y().max(80) as f32 - 40.0,
+        );
+        let block = TextLayout::new(bounds)
+            .span("Layout ", &title, 32.0, Color::MAROON)
+            .span(TEXT, &font, 20.0, Color::DARKGRAY)
+            .alignment(alignments[alignment])
+            .vertical_alignment(VerticalAlignment::Middle)
+            .ellipsis(true)
+            .build();
+        let hovered = block.hit_test(window.mouse_position());
+        window.draw(|canvas| {
+            canvas.clear_background(Color::RAYWHITE);
+            canvas.draw_rectangle_lines_ex(bounds, 1, Color::LIGHTGRAY);
+            for glyph in block.glyphs() {
+                if Some(glyph.index) == hovered {
+                    canvas.draw_rectangle_rec(glyph.rectangle, Color::SKYBLUE);
+                }
+            }
+            canvas.draw_text_block(&block);
+            canvas.draw_text(
+                "Move the mouse to resize, right click to change the alignment",
+                10,
+                10,
+                10,
+                Color::GRAY,
+            );
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use rayquaza::{
+    color::Color,
+    math::Rectangle,
+    result::Result,
+    texture::{NPatchInfo, NPatchLayout},
+    window::WindowBuilder,
+};
+
+fn main() -> Result {
+    let window = WindowBuilder::new()
+        .title("Texture drawing")
+        .vsync()
+        .build()?;
+    let texture = window.load_texture("resources/image/logo.png")?;
+    let width = texture.width() as f32;
+    let height = texture.height() as f32;
+    let source = Rectangle::new(0.0, 0.0, width, height);
+    let border = (width / 4.0) as i32;
+    let npatch = NPatchInfo::new(
+        source,
+        border,
+        border,
+        border,
+        border,
+        NPatchLayout::NinePatch,
+    );
+    let mut rotation = 0.0;
+    while !window.should_close() {
+        rotation += window.frame_time() * 90.0;
+        let panel_width = 200.0 + (window.time() as f32).sin() * 100.0;
+        window.draw(|canvas| {
+            canvas.clear_background(Color::RAYWHITE);
+            canvas.draw_texture_ex(&texture, (20.0, 20.0), 0.0, 0.5, Color::WHITE);
+            canvas.draw_texture_pro(
+                &texture,
+                Rectangle::new(0.0, 0.0, -width, height),
+                Rectangle::new(300.0, 100.0, width / 2.0, height / 2.0),
+                (width / 4.0, height / 4.0),
+                rotation,
+                Color::WHITE,
+            );
+            canvas.draw_texture_tiled(
+                &texture,
+                source,
+                Rectangle::new(420.0, 20.0, 360.0, 160.0),
+                (0.0, 0.0),
+                0.0,
+                0.25,
+                Color::WHITE,
+            );
+            canvas.draw_texture_npatch(
+                &texture,
+                npatch,
+                Rectangle::new(20.0, 250.0, panel_width, 180.0),
+                (0.0, 0.0),
+                0.0,
+                Color::WHITE,
+            );
+            canvas.draw_texture_quad(
+                &texture,
+                (3.0, 2.0),
+                (0.0, 0.0),
+                Rectangle::new(420.0, 250.0, 360.0, 180.0),
+                Color::SKYBLUE,
+            );
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use rayquaza::{
+    color::Color,
+    image::Image,
+    input::Key,
+    math::Rectangle,
+    result::Result,
+    texture::{TextureFilter, TextureWrap},
+    window::WindowBuilder,
+};
+
+const SIZE: i32 = 64;
+
+fn main() -> Result {
+    let window = WindowBuilder::new()
+        .title("Texture streaming")
+        .vsync()
+        .build()?;
+    let mut texture = window.load_texture_from_image(&Image::new(SIZE, SIZE, Color::BLACK))?;
+    texture.set_wrap(TextureWrap::Repeat);
+    let mut pixels = vec![0; (SIZE * SIZE * 4) as usize];
+    let mut point = true;
+    texture.set_filter(TextureFilter::Point);
+    while !window.should_close() {
+        if window.is_key_pressed(Key::F) {
+            point = !point;
+            texture.set_filter(if point {
+                TextureFilter::Point
+            } else {
+                TextureFilter::Bilinear
+            });
+        }
+        let time = window.time() as f32;
+        for (index, pixel) in pixels.chunks_mut(4).enumerate() {
+            let x = (index as i32 % SIZE) as f32 / 8.0;
+            let y = (index as i32 / SIZE) as f32 / 8.0;
+            let value = ((x + time).sin() + (y + time * 0.5).cos()) * 0.25 + 0.5;
+            pixel.copy_from_slice(&[
+                (value * 255.0) as u8,
+                64,
+                (255.0 - value * 255.0) as u8,
+                255,
+            ]);
+        }
+        texture.update(&pixels);
+        let square = [255; 8 * 8 * 4];
+        let offset = ((time * 20.0) as i32) % (SIZE - 8);
+        texture.update_rect(Rectangle::new(offset as f32, 28.0, 8.0, 8.0), &square);
+        window.draw(|canvas| {
+            canvas.clear_background(Color::RAYWHITE);
+            canvas.draw_texture_ex(&texture, (208.0, 33.0), 0.0, 6.0, Color::WHITE);
+            canvas.draw_texture_quad(
+                &texture,
+                (3.0, 3.0),
+                (time * 0.1, 0.0),
+                Rectangle::new(20.0, 100.0, 150.0, 150.0),
+                Color::WHITE,
+            );
+            let filter = if point { "point" } else { "bilinear" };
+            canvas.draw_text(
+                &format!("F to toggle filter ({})", filter),
+                20,
+                20,
+                20,
+                Color::GRAY,
+            );
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use rayquaza::{color::Color, result::Result, text::CharSet, window::WindowBuilder};
+
+const TEXTS: [&str; 3] = ["Hello world!", "Привет, мир!", "Grüße aus Köln"];
+
+fn main() -> Result {
+    let window = WindowBuilder::new().title("Unicode text").vsync().build()?;
+    // Bakes only the characters used by the localized strings
+    let chars = TEXTS
+        .iter()
+        .fold(CharSet::ascii(), |chars, text| chars.add_text(text));
+    let font = window.load_font_ex("resources/font/pacifico.ttf", 48, &chars)?;
+    while !window.should_close() {
+        window.draw(|canvas| {
+            canvas.clear_background(Color::RAYWHITE);
+            for (index, text) in TEXTS.iter().enumerate() {
+                let position = (20.0, 20.0 + index as f32 * 60.0);
+                canvas.draw_text_ex(&font, text, position, 48.0, 0.0, Color::DARKGRAY);
+            }
+        });
+    }
+    Ok(())
+}
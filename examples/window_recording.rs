@@ -0,0 +1,41 @@
+use rayquaza::{
+    capture::RecordingOptions, color::Color, input::Key, result::Result, window::WindowBuilder,
+};
+
+fn main() -> Result {
+    let mut window = WindowBuilder::new()
+        .title("Window recording")
+        .vsync()
+        .build()?;
+    let mut angle = 0.0f32;
+    while !window.should_close() {
+        if window.is_key_pressed(Key::R) {
+            if window.is_recording() {
+                window.stop_recording()?;
+            } else {
+                let options = RecordingOptions::new().frame_skip(2).scale(0.5);
+                window.start_recording("window_recording.gif", options)?;
+            }
+        }
+        angle += window.frame_time() * 2.0;
+        let status = if window.is_recording() {
+            "Recording window_recording.gif (R to stop)"
+        } else {
+            "R to record"
+        };
+        window.draw(|canvas| {
+            canvas.clear_background(Color::RAYWHITE);
+            canvas.draw_circle(
+                400 + (angle.cos() * 150.0) as i32,
+                225 + (angle.sin() * 150.0) as i32,
+                40.0,
+                Color::MAROON,
+            );
+            canvas.draw_text(status, 20, 20, 20, Color::GRAY);
+        });
+    }
+    if window.is_recording() {
+        window.stop_recording()?;
+    }
+    Ok(())
+}
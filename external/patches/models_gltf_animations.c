@@ -0,0 +1,365 @@
+// rayquaza patch: glTF skeletal animations, missing from raylib 3.0 (LoadGLTF() ignores skins and
+// animations).
+//
+// Appended to models.c by build.rs when compiling raylib (the vendored sources stay untouched), to
+// reuse its cgltf implementation. The skeleton and the animations of the first skin are converted
+// to the data used by UpdateModelAnimation() for IQM models:
+//   - LoadGLTFModelSkeleton() sets the bones, the bind pose and the vertex bone data of a model
+//     loaded by LoadModel()
+//   - LoadGLTFModelAnimations() samples the animations at GLTF_ANIMATION_FRAME_RATE into global
+//     bone poses
+//
+// As for IQM models, vertices follow their joint of greatest weight only, and cubic spline
+// interpolation is approximated by linear interpolation between keyframes.
+
+#if defined(SUPPORT_FILEFORMAT_GLTF)
+
+#define GLTF_ANIMATION_FRAME_RATE   60      // Number of sampled animation frames per second
+
+// Load glTF data and buffers from file
+static cgltf_data *LoadGLTFData(const char *fileName)
+{
+    cgltf_options options = { 0 };
+    cgltf_data *data = NULL;
+
+    if (cgltf_parse_file(&options, fileName, &data) != cgltf_result_success) return NULL;
+
+    if (cgltf_load_buffers(&options, data, fileName) != cgltf_result_success)
+    {
+        cgltf_free(data);
+        return NULL;
+    }
+
+    return data;
+}
+
+// Get the accessor of the first attribute of a type, NULL if missing
+static const cgltf_accessor *GetGLTFAttribute(const cgltf_primitive *primitive, cgltf_attribute_type type)
+{
+    for (int i = 0; i < (int)primitive->attributes_count; i++)
+    {
+        if ((primitive->attributes[i].type == type) && (primitive->attributes[i].index == 0)) return primitive->attributes[i].data;
+    }
+
+    return NULL;
+}
+
+// Get the bone info of a skin joint, its parent being the closest ancestor joint
+static BoneInfo GetGLTFBoneInfo(const cgltf_skin *skin, const cgltf_node *joint)
+{
+    BoneInfo bone = { 0 };
+
+    if (joint->name != NULL) strncpy(bone.name, joint->name, sizeof(bone.name) - 1);
+
+    bone.parent = -1;
+
+    for (const cgltf_node *node = joint->parent; (node != NULL) && (bone.parent < 0); node = node->parent)
+    {
+        for (int i = 0; i < (int)skin->joints_count; i++)
+        {
+            if (skin->joints[i] == node) bone.parent = i;
+        }
+    }
+
+    return bone;
+}
+
+// Decompose an affine matrix into a transform (shear is lost)
+static Transform GetGLTFMatrixTransform(Matrix matrix)
+{
+    Transform transform = { 0 };
+
+    transform.translation = (Vector3){ matrix.m12, matrix.m13, matrix.m14 };
+    transform.scale.x = Vector3Length((Vector3){ matrix.m0, matrix.m1, matrix.m2 });
+    transform.scale.y = Vector3Length((Vector3){ matrix.m4, matrix.m5, matrix.m6 });
+    transform.scale.z = Vector3Length((Vector3){ matrix.m8, matrix.m9, matrix.m10 });
+
+    Matrix rotation = MatrixIdentity();
+
+    if (transform.scale.x > 0.0f) { rotation.m0 = matrix.m0/transform.scale.x; rotation.m1 = matrix.m1/transform.scale.x; rotation.m2 = matrix.m2/transform.scale.x; }
+    if (transform.scale.y > 0.0f) { rotation.m4 = matrix.m4/transform.scale.y; rotation.m5 = matrix.m5/transform.scale.y; rotation.m6 = matrix.m6/transform.scale.y; }
+    if (transform.scale.z > 0.0f) { rotation.m8 = matrix.m8/transform.scale.z; rotation.m9 = matrix.m9/transform.scale.z; rotation.m10 = matrix.m10/transform.scale.z; }
+
+    transform.rotation = QuaternionNormalize(QuaternionFromMatrix(rotation));
+
+    return transform;
+}
+
+// Get the matrix stored by glTF in column-major order
+static Matrix GetGLTFMatrix(const float *m)
+{
+    return (Matrix){ m[0], m[4], m[8], m[12], m[1], m[5], m[9], m[13], m[2], m[6], m[10], m[14], m[3], m[7], m[11], m[15] };
+}
+
+// Get the local transform of a node at rest
+static Transform GetGLTFNodeTransform(const cgltf_node *node)
+{
+    if (node->has_matrix) return GetGLTFMatrixTransform(GetGLTFMatrix(node->matrix));
+
+    Transform transform = { (Vector3){ 0.0f, 0.0f, 0.0f }, QuaternionIdentity(), (Vector3){ 1.0f, 1.0f, 1.0f } };
+
+    if (node->has_translation) transform.translation = (Vector3){ node->translation[0], node->translation[1], node->translation[2] };
+    if (node->has_rotation) transform.rotation = (Quaternion){ node->rotation[0], node->rotation[1], node->rotation[2], node->rotation[3] };
+    if (node->has_scale) transform.scale = (Vector3){ node->scale[0], node->scale[1], node->scale[2] };
+
+    return transform;
+}
+
+// Get the global transform of a node from the local transforms of all the nodes
+static Transform GetGLTFGlobalTransform(const cgltf_data *data, const Transform *locals, const cgltf_node *node)
+{
+    Transform global = locals[node - data->nodes];
+
+    for (const cgltf_node *parent = node->parent; parent != NULL; parent = parent->parent)
+    {
+        Transform local = locals[parent - data->nodes];
+
+        global.translation = Vector3Add(Vector3RotateByQuaternion(Vector3Multiply(global.translation, local.scale), local.rotation), local.translation);
+        global.rotation = QuaternionNormalize(QuaternionMultiply(local.rotation, global.rotation));
+        global.scale = Vector3Multiply(global.scale, local.scale);
+    }
+
+    return global;
+}
+
+// Apply the value of an animation channel at a given time to a node transform
+static void ApplyGLTFAnimationChannel(const cgltf_animation_channel *channel, float time, Transform *transform)
+{
+    const cgltf_animation_sampler *sampler = channel->sampler;
+    int count = (int)sampler->input->count;
+
+    if (count == 0) return;
+
+    // Find the keyframes surrounding the time
+    int previous = 0;
+    float previousTime = 0.0f;
+    float nextTime = 0.0f;
+
+    for (int i = 0; i < count; i++)
+    {
+        float keyTime = 0.0f;
+        cgltf_accessor_read_float(sampler->input, i, &keyTime, 1);
+
+        if ((i > 0) && (keyTime > time)) break;
+
+        previous = i;
+        previousTime = keyTime;
+    }
+
+    int next = (previous + 1 < count)? previous + 1 : previous;
+    cgltf_accessor_read_float(sampler->input, next, &nextTime, 1);
+
+    float amount = 0.0f;
+
+    if ((sampler->interpolation != cgltf_interpolation_type_step) && (nextTime > previousTime))
+    {
+        amount = (time - previousTime)/(nextTime - previousTime);
+        amount = (amount < 0.0f)? 0.0f : (amount > 1.0f)? 1.0f : amount;
+    }
+
+    // Cubic spline outputs store an in-tangent, a value and an out-tangent per keyframe
+    int stride = (sampler->interpolation == cgltf_interpolation_type_cubic_spline)? 3 : 1;
+    int offset = (sampler->interpolation == cgltf_interpolation_type_cubic_spline)? 1 : 0;
+    float a[4] = { 0 };
+    float b[4] = { 0 };
+
+    switch (channel->target_path)
+    {
+        case cgltf_animation_path_type_translation:
+        case cgltf_animation_path_type_scale:
+        {
+            cgltf_accessor_read_float(sampler->output, previous*stride + offset, a, 3);
+            cgltf_accessor_read_float(sampler->output, next*stride + offset, b, 3);
+
+            Vector3 value = Vector3Lerp((Vector3){ a[0], a[1], a[2] }, (Vector3){ b[0], b[1], b[2] }, amount);
+
+            if (channel->target_path == cgltf_animation_path_type_translation) transform->translation = value;
+            else transform->scale = value;
+        } break;
+        case cgltf_animation_path_type_rotation:
+        {
+            cgltf_accessor_read_float(sampler->output, previous*stride + offset, a, 4);
+            cgltf_accessor_read_float(sampler->output, next*stride + offset, b, 4);
+
+            // Interpolate along the shortest path
+            if (a[0]*b[0] + a[1]*b[1] + a[2]*b[2] + a[3]*b[3] < 0.0f) for (int i = 0; i < 4; i++) b[i] = -b[i];
+
+            Quaternion value = QuaternionSlerp((Quaternion){ a[0], a[1], a[2], a[3] }, (Quaternion){ b[0], b[1], b[2], b[3] }, amount);
+            transform->rotation = QuaternionNormalize(value);
+        } break;
+        default: break;     // Morph target weights are not supported
+    }
+}
+
+// Load the skeleton of the first skin of a glTF file into a model loaded from it
+// NOTE: Every mesh must be skinned by this skin and have normals, the model is left untouched otherwise
+bool LoadGLTFModelSkeleton(const char *fileName, Model *model)
+{
+    cgltf_data *data = LoadGLTFData(fileName);
+
+    if (data == NULL) return false;
+
+    bool valid = (data->skins_count > 0) && (model->boneCount == 0);
+
+    for (int i = 0; valid && (i < (int)data->nodes_count); i++)
+    {
+        if ((data->nodes[i].skin != NULL) && (data->nodes[i].skin != &data->skins[0])) valid = false;
+    }
+
+    // Meshes are loaded from the primitives of all the glTF meshes, in order
+    int meshIndex = 0;
+
+    for (int i = 0; valid && (i < (int)data->meshes_count); i++)
+    {
+        for (int p = 0; valid && (p < (int)data->meshes[i].primitives_count); p++, meshIndex++)
+        {
+            const cgltf_accessor *joints = GetGLTFAttribute(&data->meshes[i].primitives[p], cgltf_attribute_type_joints);
+            const cgltf_accessor *weights = GetGLTFAttribute(&data->meshes[i].primitives[p], cgltf_attribute_type_weights);
+
+            valid = (meshIndex < model->meshCount) && (joints != NULL) && (weights != NULL) &&
+                    (model->meshes[meshIndex].vertices != NULL) && (model->meshes[meshIndex].normals != NULL) &&
+                    ((int)joints->count == model->meshes[meshIndex].vertexCount) && ((int)weights->count == model->meshes[meshIndex].vertexCount);
+        }
+    }
+
+    if (!valid || (meshIndex != model->meshCount))
+    {
+        cgltf_free(data);
+        return false;
+    }
+
+    const cgltf_skin *skin = &data->skins[0];
+
+    model->boneCount = (int)skin->joints_count;
+    model->bones = RL_MALLOC(model->boneCount*sizeof(BoneInfo));
+    model->bindPose = RL_MALLOC(model->boneCount*sizeof(Transform));
+
+    for (int i = 0; i < model->boneCount; i++)
+    {
+        model->bones[i] = GetGLTFBoneInfo(skin, skin->joints[i]);
+
+        // The bind pose is the inverse of the inverse bind matrix (identity if missing)
+        float inverseBindMatrix[16] = { 1.0f, 0.0f, 0.0f, 0.0f, 0.0f, 1.0f, 0.0f, 0.0f, 0.0f, 0.0f, 1.0f, 0.0f, 0.0f, 0.0f, 0.0f, 1.0f };
+
+        if (skin->inverse_bind_matrices != NULL) cgltf_accessor_read_float(skin->inverse_bind_matrices, i, inverseBindMatrix, 16);
+
+        model->bindPose[i] = GetGLTFMatrixTransform(MatrixInvert(GetGLTFMatrix(inverseBindMatrix)));
+    }
+
+    meshIndex = 0;
+
+    for (int i = 0; i < (int)data->meshes_count; i++)
+    {
+        for (int p = 0; p < (int)data->meshes[i].primitives_count; p++, meshIndex++)
+        {
+            Mesh *mesh = &model->meshes[meshIndex];
+            const cgltf_accessor *joints = GetGLTFAttribute(&data->meshes[i].primitives[p], cgltf_attribute_type_joints);
+            const cgltf_accessor *weights = GetGLTFAttribute(&data->meshes[i].primitives[p], cgltf_attribute_type_weights);
+
+            mesh->boneIds = RL_MALLOC(mesh->vertexCount*4*sizeof(int));
+            mesh->boneWeights = RL_MALLOC(mesh->vertexCount*4*sizeof(float));
+            mesh->animVertices = RL_MALLOC(mesh->vertexCount*3*sizeof(float));
+            mesh->animNormals = RL_MALLOC(mesh->vertexCount*3*sizeof(float));
+            memcpy(mesh->animVertices, mesh->vertices, mesh->vertexCount*3*sizeof(float));
+            memcpy(mesh->animNormals, mesh->normals, mesh->vertexCount*3*sizeof(float));
+
+            for (int v = 0; v < mesh->vertexCount; v++)
+            {
+                cgltf_uint vertexJoints[4] = { 0 };
+                float vertexWeights[4] = { 0 };
+
+                cgltf_accessor_read_uint(joints, v, vertexJoints, 4);
+                cgltf_accessor_read_float(weights, v, vertexWeights, 4);
+
+                // Sort the joints by decreasing weight, UpdateModelAnimation() only using the first one
+                for (int j = 1; j < 4; j++)
+                {
+                    for (int k = j; (k > 0) && (vertexWeights[k] > vertexWeights[k - 1]); k--)
+                    {
+                        cgltf_uint joint = vertexJoints[k]; vertexJoints[k] = vertexJoints[k - 1]; vertexJoints[k - 1] = joint;
+                        float weight = vertexWeights[k]; vertexWeights[k] = vertexWeights[k - 1]; vertexWeights[k - 1] = weight;
+                    }
+                }
+
+                for (int j = 0; j < 4; j++)
+                {
+                    mesh->boneIds[v*4 + j] = ((int)vertexJoints[j] < model->boneCount)? (int)vertexJoints[j] : 0;
+                    mesh->boneWeights[v*4 + j] = vertexWeights[j];
+                }
+            }
+        }
+    }
+
+    cgltf_free(data);
+
+    return true;
+}
+
+// Load the animations of the first skin of a glTF file
+ModelAnimation *LoadGLTFModelAnimations(const char *fileName, int *animCount)
+{
+    *animCount = 0;
+
+    cgltf_data *data = LoadGLTFData(fileName);
+
+    if (data == NULL) return NULL;
+
+    if ((data->skins_count == 0) || (data->animations_count == 0))
+    {
+        cgltf_free(data);
+        return NULL;
+    }
+
+    const cgltf_skin *skin = &data->skins[0];
+    ModelAnimation *animations = RL_MALLOC(data->animations_count*sizeof(ModelAnimation));
+    Transform *locals = RL_MALLOC(data->nodes_count*sizeof(Transform));
+
+    for (int a = 0; a < (int)data->animations_count; a++)
+    {
+        const cgltf_animation *animation = &data->animations[a];
+        float duration = 0.0f;
+
+        for (int c = 0; c < (int)animation->channels_count; c++)
+        {
+            const cgltf_accessor *input = animation->channels[c].sampler->input;
+            float time = 0.0f;
+
+            if (input->count > 0) cgltf_accessor_read_float(input, input->count - 1, &time, 1);
+            if (time > duration) duration = time;
+        }
+
+        animations[a].boneCount = (int)skin->joints_count;
+        animations[a].bones = RL_MALLOC(animations[a].boneCount*sizeof(BoneInfo));
+        animations[a].frameCount = (int)(duration*GLTF_ANIMATION_FRAME_RATE) + 1;
+        animations[a].framePoses = RL_MALLOC(animations[a].frameCount*sizeof(Transform *));
+
+        for (int i = 0; i < animations[a].boneCount; i++) animations[a].bones[i] = GetGLTFBoneInfo(skin, skin->joints[i]);
+
+        for (int frame = 0; frame < animations[a].frameCount; frame++)
+        {
+            float time = (float)frame/GLTF_ANIMATION_FRAME_RATE;
+
+            for (int i = 0; i < (int)data->nodes_count; i++) locals[i] = GetGLTFNodeTransform(&data->nodes[i]);
+
+            for (int c = 0; c < (int)animation->channels_count; c++)
+            {
+                const cgltf_animation_channel *channel = &animation->channels[c];
+
+                if (channel->target_node != NULL) ApplyGLTFAnimationChannel(channel, time, &locals[channel->target_node - data->nodes]);
+            }
+
+            animations[a].framePoses[frame] = RL_MALLOC(animations[a].boneCount*sizeof(Transform));
+
+            for (int i = 0; i < animations[a].boneCount; i++) animations[a].framePoses[frame][i] = GetGLTFGlobalTransform(data, locals, skin->joints[i]);
+        }
+    }
+
+    *animCount = (int)data->animations_count;
+
+    RL_FREE(locals);
+    cgltf_free(data);
+
+    return animations;
+}
+
+#endif
@@ -0,0 +1,56 @@
+// rayquaza patch: model loading reporting parse failures, LoadModel() substituting a cube mesh.
+//
+// Appended to models.c by build.rs when compiling raylib (the vendored sources stay untouched),
+// to reuse its file format loaders.
+
+// Load model from files (meshes and materials)
+// NOTE: Returns a model without meshes if the file can't be parsed
+Model LoadModelChecked(const char *fileName)
+{
+    Model model = { 0 };
+
+#if defined(SUPPORT_FILEFORMAT_OBJ)
+    if (IsFileExtension(fileName, ".obj")) model = LoadOBJ(fileName);
+#endif
+#if defined(SUPPORT_FILEFORMAT_IQM)
+    if (IsFileExtension(fileName, ".iqm")) model = LoadIQM(fileName);
+#endif
+#if defined(SUPPORT_FILEFORMAT_GLTF)
+    if (IsFileExtension(fileName, ".gltf") || IsFileExtension(fileName, ".glb")) model = LoadGLTF(fileName);
+#endif
+
+    if (model.meshCount == 0)
+    {
+        TRACELOG(LOG_WARNING, "MESH: [%s] Failed to load mesh data", fileName);
+
+        // Free the data loaded before the failure
+        for (int i = 0; i < model.materialCount; i++) UnloadMaterial(model.materials[i]);
+
+        RL_FREE(model.meshes);
+        RL_FREE(model.materials);
+        RL_FREE(model.meshMaterial);
+        RL_FREE(model.bones);
+        RL_FREE(model.bindPose);
+
+        return (Model){ 0 };
+    }
+
+    // Make sure model transform is set to identity matrix!
+    model.transform = MatrixIdentity();
+
+    // Upload vertex data to GPU (static mesh)
+    for (int i = 0; i < model.meshCount; i++) rlLoadMesh(&model.meshes[i], false);
+
+    if (model.materialCount == 0)
+    {
+        TRACELOG(LOG_WARNING, "MATERIAL: [%s] Failed to load material data, default to white material", fileName);
+
+        model.materialCount = 1;
+        model.materials = (Material *)RL_CALLOC(model.materialCount, sizeof(Material));
+        model.materials[0] = LoadMaterialDefault();
+
+        if (model.meshMaterial == NULL) model.meshMaterial = (int *)RL_CALLOC(model.meshCount, sizeof(int));
+    }
+
+    return model;
+}
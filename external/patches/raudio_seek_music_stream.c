@@ -0,0 +1,31 @@
+// rayquaza patch: music seeking, missing from raylib 3.0.
+//
+// Appended to raudio.c by build.rs when compiling raylib (the vendored sources stay untouched),
+// to access the decoders and the audio buffer internals.
+
+// Seek music to a position (in seconds)
+// NOTE: Seeking is not supported for module formats (XM, MOD)
+void SeekMusicStream(Music music, float position)
+{
+    if ((music.ctxType == MUSIC_MODULE_XM) || (music.ctxType == MUSIC_MODULE_MOD)) return;
+
+    if (position < 0.0f) position = 0.0f;
+
+    unsigned int positionInFrames = (unsigned int)(position*music.stream.sampleRate);
+
+    switch (music.ctxType)
+    {
+#if defined(SUPPORT_FILEFORMAT_OGG)
+        case MUSIC_AUDIO_OGG: stb_vorbis_seek((stb_vorbis *)music.ctxData, positionInFrames); break;
+#endif
+#if defined(SUPPORT_FILEFORMAT_FLAC)
+        case MUSIC_AUDIO_FLAC: drflac_seek_to_pcm_frame((drflac *)music.ctxData, positionInFrames); break;
+#endif
+#if defined(SUPPORT_FILEFORMAT_MP3)
+        case MUSIC_AUDIO_MP3: drmp3_seek_to_pcm_frame((drmp3 *)music.ctxData, positionInFrames); break;
+#endif
+        default: break;
+    }
+
+    if (music.stream.buffer != NULL) music.stream.buffer->totalFramesProcessed = positionInFrames;
+}
@@ -0,0 +1,398 @@
+//! Input action mapping.
+
+use crate::{
+    input::{Gamepad, GamepadAxis, GamepadButton, Key, MouseButton, KEY_COUNT},
+    math::clamp,
+    window::Window,
+};
+use std::collections::HashMap;
+
+/// Default dead-zone of gamepad axes.
+const DEFAULT_DEAD_ZONE: f32 = 0.25;
+
+/// Kinds of digital inputs.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Input {
+    Key(Key),
+    MouseButton(MouseButton),
+    GamepadButton(Gamepad, GamepadButton),
+    /// Gamepad axis pushed past the dead-zone, towards the positive values if `true`.
+    GamepadAxis(Gamepad, GamepadAxis, bool),
+}
+
+impl Input {
+    /// Detects if the input is being pressed.
+    fn is_down(self, window: &Window, dead_zone: f32) -> bool {
+        match self {
+            Input::Key(key) => window.is_key_down(key),
+            Input::MouseButton(button) => window.is_mouse_button_down(button),
+            Input::GamepadButton(gamepad, button) => window.is_gamepad_button_down(gamepad, button),
+            Input::GamepadAxis(gamepad, axis, positive) => {
+                let value = window.gamepad_axis_movement(gamepad, axis);
+                if positive {
+                    value > dead_zone
+                } else {
+                    value < -dead_zone
+                }
+            }
+        }
+    }
+}
+
+impl From<Key> for Input {
+    #[inline]
+    fn from(key: Key) -> Input {
+        Input::Key(key)
+    }
+}
+
+impl From<MouseButton> for Input {
+    #[inline]
+    fn from(button: MouseButton) -> Input {
+        Input::MouseButton(button)
+    }
+}
+
+impl From<(Gamepad, GamepadButton)> for Input {
+    #[inline]
+    fn from((gamepad, button): (Gamepad, GamepadButton)) -> Input {
+        Input::GamepadButton(gamepad, button)
+    }
+}
+
+/// Binding of an input to an action, with modifier keys which must be held down.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Binding {
+    pub input: Input,
+    pub modifiers: Vec<Key>,
+}
+
+impl Binding {
+    /// Creates a `Binding` without modifiers.
+    pub fn new(input: impl Into<Input>) -> Binding {
+        Binding {
+            input: input.into(),
+            modifiers: Vec::new(),
+        }
+    }
+
+    /// Adds a modifier key.
+    pub fn modifier(mut self, key: Key) -> Binding {
+        self.modifiers.push(key);
+        self
+    }
+
+    /// Detects if the binding is active.
+    fn is_down(&self, window: &Window, dead_zone: f32) -> bool {
+        self.input.is_down(window, dead_zone)
+            && self.modifiers.iter().all(|&key| window.is_key_down(key))
+    }
+}
+
+impl<I: Into<Input>> From<I> for Binding {
+    #[inline]
+    fn from(input: I) -> Binding {
+        Binding::new(input)
+    }
+}
+
+/// Binding of inputs to an axis.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AxisBinding {
+    /// Two bindings moving the axis to -1.0 and 1.0.
+    Digital {
+        negative: Binding,
+        positive: Binding,
+    },
+    /// Gamepad axis, ignoring values within the dead-zone.
+    Analog {
+        gamepad: Gamepad,
+        axis: GamepadAxis,
+        dead_zone: f32,
+        inverted: bool,
+    },
+}
+
+impl AxisBinding {
+    /// Creates a digital `AxisBinding`.
+    pub fn digital(negative: impl Into<Binding>, positive: impl Into<Binding>) -> AxisBinding {
+        AxisBinding::Digital {
+            negative: negative.into(),
+            positive: positive.into(),
+        }
+    }
+
+    /// Creates an analog `AxisBinding` with the default dead-zone.
+    pub fn analog(gamepad: Gamepad, axis: GamepadAxis) -> AxisBinding {
+        AxisBinding::Analog {
+            gamepad,
+            axis,
+            dead_zone: DEFAULT_DEAD_ZONE,
+            inverted: false,
+        }
+    }
+
+    /// Returns the axis value.
+    fn value(&self, window: &Window, dead_zone: f32) -> f32 {
+        match self {
+            AxisBinding::Digital { negative, positive } => {
+                let mut value = 0.0;
+                if negative.is_down(window, dead_zone) {
+                    value -= 1.0;
+                }
+                if positive.is_down(window, dead_zone) {
+                    value += 1.0;
+                }
+                value
+            }
+            AxisBinding::Analog {
+                gamepad,
+                axis,
+                dead_zone,
+                inverted,
+            } => {
+                let value =
+                    apply_dead_zone(window.gamepad_axis_movement(*gamepad, *axis), *dead_zone);
+                if *inverted {
+                    -value
+                } else {
+                    value
+                }
+            }
+        }
+    }
+}
+
+/// Rescales an axis value so that it starts from 0.0 at the edge of the dead-zone.
+fn apply_dead_zone(value: f32, dead_zone: f32) -> f32 {
+    if value.abs() <= dead_zone {
+        0.0
+    } else {
+        value.signum() * clamp((value.abs() - dead_zone) / (1.0 - dead_zone), 0.0, 1.0)
+    }
+}
+
+/// State of an action.
+#[derive(Default)]
+struct Action {
+    bindings: Vec<Binding>,
+    down: bool,
+    was_down: bool,
+}
+
+/// State of an axis.
+#[derive(Default)]
+struct Axis {
+    bindings: Vec<AxisBinding>,
+    value: f32,
+}
+
+/// Map of named actions and axes bound to inputs.
+///
+/// The state is computed by `update`, which must be called once per frame.
+pub struct ActionMap {
+    actions: HashMap<String, Action>,
+    axes: HashMap<String, Axis>,
+    dead_zone: f32,
+}
+
+impl ActionMap {
+    /// Creates an empty `ActionMap`.
+    pub fn new() -> ActionMap {
+        ActionMap {
+            actions: HashMap::new(),
+            axes: HashMap::new(),
+            dead_zone: DEFAULT_DEAD_ZONE,
+        }
+    }
+
+    /// Sets the dead-zone of gamepad axes bound to actions.
+    pub fn set_dead_zone(&mut self, dead_zone: f32) {
+        self.dead_zone = dead_zone;
+    }
+
+    /// Binds an input to an action, in addition to the existing bindings.
+    pub fn bind_action(&mut self, action: &str, binding: impl Into<Binding>) {
+        let binding = binding.into();
+        let bindings = &mut self.actions.entry(action.to_string()).or_default().bindings;
+        if !bindings.contains(&binding) {
+            bindings.push(binding);
+        }
+    }
+
+    /// Unbinds an input from an action.
+    pub fn unbind_action(&mut self, action: &str, binding: impl Into<Binding>) {
+        let binding = binding.into();
+        if let Some(action) = self.actions.get_mut(action) {
+            action.bindings.retain(|other| *other != binding);
+        }
+    }
+
+    /// Replaces all the bindings of an action.
+    pub fn set_action_bindings(&mut self, action: &str, bindings: Vec<Binding>) {
+        self.actions.entry(action.to_string()).or_default().bindings = bindings;
+    }
+
+    /// Returns the bindings of an action.
+    pub fn action_bindings(&self, action: &str) -> &[Binding] {
+        self.actions
+            .get(action)
+            .map_or(&[], |action| action.bindings.as_slice())
+    }
+
+    /// Binds inputs to an axis, in addition to the existing bindings.
+    pub fn bind_axis(&mut self, axis: &str, binding: AxisBinding) {
+        let bindings = &mut self.axes.entry(axis.to_string()).or_default().bindings;
+        if !bindings.contains(&binding) {
+            bindings.push(binding);
+        }
+    }
+
+    /// Replaces all the bindings of an axis.
+    pub fn set_axis_bindings(&mut self, axis: &str, bindings: Vec<AxisBinding>) {
+        self.axes.entry(axis.to_string()).or_default().bindings = bindings;
+    }
+
+    /// Returns the bindings of an axis.
+    pub fn axis_bindings(&self, axis: &str) -> &[AxisBinding] {
+        self.axes
+            .get(axis)
+            .map_or(&[], |axis| axis.bindings.as_slice())
+    }
+
+    /// Updates the state of the actions and axes from the current input.
+    pub fn update(&mut self, window: &Window) {
+        let dead_zone = self.dead_zone;
+        for action in self.actions.values_mut() {
+            action.was_down = action.down;
+            action.down = action
+                .bindings
+                .iter()
+                .any(|binding| binding.is_down(window, dead_zone));
+        }
+        for axis in self.axes.values_mut() {
+            let value = axis
+                .bindings
+                .iter()
+                .map(|binding| binding.value(window, dead_zone))
+                .sum();
+            axis.value = clamp(value, -1.0, 1.0);
+        }
+    }
+
+    /// Detects if an action has been pressed once.
+    pub fn is_action_pressed(&self, action: &str) -> bool {
+        matches!(self.actions.get(action), Some(action) if action.down && !action.was_down)
+    }
+
+    /// Detects if an action is being pressed.
+    pub fn is_action_down(&self, action: &str) -> bool {
+        matches!(self.actions.get(action), Some(action) if action.down)
+    }
+
+    /// Detects if an action has been released once.
+    pub fn is_action_released(&self, action: &str) -> bool {
+        matches!(self.actions.get(action), Some(action) if !action.down && action.was_down)
+    }
+
+    /// Returns the value of an axis, between -1.0 and 1.0.
+    pub fn axis_value(&self, axis: &str) -> f32 {
+        self.axes.get(axis).map_or(0.0, |axis| axis.value)
+    }
+
+    /// Returns the first input pressed during the current frame, useful to rebind an action.
+    pub fn detect_input(&self, window: &Window) -> Option<Input> {
+        let key = (0..KEY_COUNT)
+            .filter_map(Key::from_raw)
+            .find(|&key| window.is_key_pressed(key))
+            .map(Input::Key);
+        let mouse_button = || {
+            MouseButton::ALL
+                .iter()
+                .find(|&&button| window.is_mouse_button_pressed(button))
+                .map(|&button| Input::MouseButton(button))
+        };
+        let gamepad = || {
+            Gamepad::ALL
+                .iter()
+                .filter(|&&gamepad| window.is_gamepad_available(gamepad))
+                .find_map(|&gamepad| {
+                    GamepadButton::ALL
+                        .iter()
+                        .find(|&&button| window.is_gamepad_button_pressed(gamepad, button))
+                        .map(|&button| Input::GamepadButton(gamepad, button))
+                        .or_else(|| {
+                            GamepadAxis::ALL.iter().find_map(|&axis| {
+                                let value = window.gamepad_axis_movement(gamepad, axis);
+                                if value.abs() > 0.5 {
+                                    Some(Input::GamepadAxis(gamepad, axis, value > 0.0))
+                                } else {
+                                    None
+                                }
+                            })
+                        })
+                })
+        };
+        key.or_else(mouse_button).or_else(gamepad)
+    }
+}
+
+impl Default for ActionMap {
+    fn default() -> ActionMap {
+        ActionMap::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        action::{apply_dead_zone, ActionMap, AxisBinding, Binding, Input},
+        input::{Gamepad, GamepadAxis, GamepadButton, Key},
+    };
+
+    #[test]
+    fn test_apply_dead_zone() {
+        assert_eq!(apply_dead_zone(0.2, 0.25), 0.0);
+        assert_eq!(apply_dead_zone(-0.25, 0.25), 0.0);
+        assert_eq!(apply_dead_zone(1.0, 0.25), 1.0);
+        assert_eq!(apply_dead_zone(-0.625, 0.25), -0.5);
+    }
+
+    #[test]
+    fn test_binding_modifier() {
+        let binding = Binding::new(Key::S).modifier(Key::LeftControl);
+        assert_eq!(binding.input, Input::Key(Key::S));
+        assert_eq!(binding.modifiers, vec![Key::LeftControl]);
+    }
+
+    #[test]
+    fn test_action_map_bind_action() {
+        let mut actions = ActionMap::new();
+        actions.bind_action("jump", Key::Space);
+        actions.bind_action("jump", Key::Space);
+        actions.bind_action("jump", (Gamepad::One, GamepadButton::RightFaceDown));
+        assert_eq!(actions.action_bindings("jump").len(), 2);
+        actions.unbind_action("jump", Key::Space);
+        assert_eq!(
+            actions.action_bindings("jump"),
+            [Binding::new((Gamepad::One, GamepadButton::RightFaceDown))]
+        );
+        actions.set_action_bindings("jump", vec![Binding::new(Key::W)]);
+        assert_eq!(actions.action_bindings("jump"), [Binding::new(Key::W)]);
+        assert!(actions.action_bindings("fire").is_empty());
+        assert!(!actions.is_action_down("jump"));
+    }
+
+    #[test]
+    fn test_action_map_bind_axis() {
+        let mut actions = ActionMap::new();
+        actions.bind_axis("move_x", AxisBinding::digital(Key::A, Key::D));
+        actions.bind_axis(
+            "move_x",
+            AxisBinding::analog(Gamepad::One, GamepadAxis::LeftX),
+        );
+        assert_eq!(actions.axis_bindings("move_x").len(), 2);
+        assert_eq!(actions.axis_value("move_x"), 0.0);
+        assert_eq!(actions.axis_value("move_y"), 0.0);
+    }
+}
@@ -0,0 +1,1040 @@
+//! Asset sources (directories, ZIP archives and embedded data) mounted into a virtual file system,
+//! and the asset cache.
+
+use crate::{
+    ffi,
+    shader::{LoadShaderError, Shader},
+    text::{CharSet, Font, FontOptions, LoadFontError},
+    texture::{LoadTextureError, Texture},
+    window::Window,
+};
+use std::{
+    cell::{Ref, RefCell, RefMut},
+    collections::{HashMap, HashSet},
+    env,
+    error::Error,
+    fmt, fs, io,
+    os::raw::{c_char, c_int},
+    path::{Path, PathBuf},
+    process,
+    rc::{Rc, Weak},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::SystemTime,
+};
+
+/// Interval between checks of modified files for hot reloading, in seconds.
+const HOT_RELOAD_INTERVAL: f32 = 0.5;
+
+/// ZIP end of central directory signature.
+const END_OF_CENTRAL_DIRECTORY: u32 = 0x0605_4B50;
+
+/// ZIP central directory file header signature.
+const CENTRAL_DIRECTORY_HEADER: u32 = 0x0201_4B50;
+
+/// ZIP local file header signature.
+const LOCAL_FILE_HEADER: u32 = 0x0403_4B50;
+
+/// ZIP compression method without compression.
+const STORED: u16 = 0;
+
+/// ZIP compression method using DEFLATE.
+const DEFLATED: u16 = 8;
+
+/// Maximum compression ratio of DEFLATE, bounding the size claimed by deflated entries.
+const MAX_DEFLATE_RATIO: usize = 1032;
+
+/// Kinds of asset reading errors.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReadAssetError {
+    NotFound,
+    ReadFailed,
+}
+
+impl fmt::Display for ReadAssetError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{:?}", self)
+    }
+}
+
+impl Error for ReadAssetError {}
+
+/// Kinds of archive opening errors.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OpenArchiveError {
+    ReadFailed,
+    InvalidFormat,
+}
+
+impl fmt::Display for OpenArchiveError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{:?}", self)
+    }
+}
+
+impl Error for OpenArchiveError {}
+
+/// Source of assets, addressed by relative paths using `/` separators.
+pub trait AssetSource {
+    /// Reads the bytes of an asset.
+    fn read(&self, path: &str) -> Result<Vec<u8>, ReadAssetError>;
+
+    /// Checks if an asset exists.
+    fn contains(&self, path: &str) -> bool;
+
+    /// Returns the last modification time of an asset, if known (used by hot reloading).
+    fn modified(&self, _path: &str) -> Option<SystemTime> {
+        None
+    }
+}
+
+/// Assets stored in a directory.
+#[derive(Clone, Debug)]
+pub struct DirectorySource {
+    root: PathBuf,
+}
+
+impl DirectorySource {
+    /// Creates a `DirectorySource` rooted at a directory.
+    pub fn new<P: AsRef<Path>>(root: P) -> DirectorySource {
+        DirectorySource {
+            root: root.as_ref().to_path_buf(),
+        }
+    }
+}
+
+impl AssetSource for DirectorySource {
+    fn read(&self, path: &str) -> Result<Vec<u8>, ReadAssetError> {
+        fs::read(self.root.join(path)).map_err(|error| match error.kind() {
+            io::ErrorKind::NotFound => ReadAssetError::NotFound,
+            _ => ReadAssetError::ReadFailed,
+        })
+    }
+
+    fn contains(&self, path: &str) -> bool {
+        self.root.join(path).is_file()
+    }
+
+    fn modified(&self, path: &str) -> Option<SystemTime> {
+        fs::metadata(self.root.join(path)).ok()?.modified().ok()
+    }
+}
+
+/// Entry of a ZIP archive.
+#[derive(Clone, Copy, Debug)]
+struct ArchiveEntry {
+    offset: usize,
+    compressed_size: usize,
+    size: usize,
+    method: u16,
+}
+
+/// Assets stored in a ZIP archive (stored or deflated entries), kept in memory.
+pub struct ArchiveSource {
+    bytes: Vec<u8>,
+    entries: HashMap<String, ArchiveEntry>,
+}
+
+impl ArchiveSource {
+    /// Opens a ZIP archive file.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<ArchiveSource, OpenArchiveError> {
+        let bytes = fs::read(path).map_err(|_| OpenArchiveError::ReadFailed)?;
+        ArchiveSource::from_bytes(bytes)
+    }
+
+    /// Opens a ZIP archive from its bytes (e.g. `include_bytes!`).
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<ArchiveSource, OpenArchiveError> {
+        let entries = read_entries(&bytes).ok_or(OpenArchiveError::InvalidFormat)?;
+        Ok(ArchiveSource { bytes, entries })
+    }
+
+    /// Returns the paths of the archived files.
+    pub fn paths(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(String::as_str)
+    }
+}
+
+impl AssetSource for ArchiveSource {
+    fn read(&self, path: &str) -> Result<Vec<u8>, ReadAssetError> {
+        let entry = self.entries.get(path).ok_or(ReadAssetError::NotFound)?;
+        let data = &self.bytes[entry.offset..entry.offset + entry.compressed_size];
+        match entry.method {
+            STORED => Ok(data.to_vec()),
+            DEFLATED => {
+                if entry.size > entry.compressed_size.saturating_mul(MAX_DEFLATE_RATIO)
+                    || entry.size > c_int::MAX as usize
+                    || data.len() > c_int::MAX as usize
+                {
+                    return Err(ReadAssetError::ReadFailed);
+                }
+                let mut bytes = vec![0; entry.size];
+                let size = unsafe {
+                    ffi::stbi_zlib_decode_noheader_buffer(
+                        bytes.as_mut_ptr() as *mut c_char,
+                        bytes.len() as c_int,
+                        data.as_ptr() as *const c_char,
+                        data.len() as c_int,
+                    )
+                };
+                if size as usize == entry.size {
+                    Ok(bytes)
+                } else {
+                    Err(ReadAssetError::ReadFailed)
+                }
+            }
+            _ => Err(ReadAssetError::ReadFailed),
+        }
+    }
+
+    fn contains(&self, path: &str) -> bool {
+        self.entries.contains_key(path)
+    }
+}
+
+/// Reads a little-endian `u16` at an offset.
+fn read_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+    let bytes = bytes.get(offset..offset + 2)?;
+    Some(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+/// Reads a little-endian `u32` at an offset.
+fn read_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+    let bytes = bytes.get(offset..offset + 4)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// Reads the file entries of a ZIP archive from its central directory.
+fn read_entries(bytes: &[u8]) -> Option<HashMap<String, ArchiveEntry>> {
+    // The end of central directory record is followed by a comment of 65535 bytes at most
+    let end = (0..=bytes.len().checked_sub(22)?)
+        .rev()
+        .take(65536)
+        .find(|&offset| read_u32(bytes, offset) == Some(END_OF_CENTRAL_DIRECTORY))?;
+    let count = read_u16(bytes, end + 10)?;
+    let mut offset = read_u32(bytes, end + 16)? as usize;
+    let mut entries = HashMap::new();
+    for _ in 0..count {
+        if read_u32(bytes, offset)? != CENTRAL_DIRECTORY_HEADER {
+            return None;
+        }
+        let method = read_u16(bytes, offset + 10)?;
+        let compressed_size = read_u32(bytes, offset + 20)? as usize;
+        let size = read_u32(bytes, offset + 24)? as usize;
+        let name_length = read_u16(bytes, offset + 28)? as usize;
+        let extra_length = read_u16(bytes, offset + 30)? as usize;
+        let comment_length = read_u16(bytes, offset + 32)? as usize;
+        let header = read_u32(bytes, offset + 42)? as usize;
+        let name = bytes.get(offset + 46..offset + 46 + name_length)?;
+        let name = String::from_utf8_lossy(name);
+        offset += 46 + name_length + extra_length + comment_length;
+        // Directories have no data
+        if name.ends_with('/') {
+            continue;
+        }
+        if read_u32(bytes, header)? != LOCAL_FILE_HEADER {
+            return None;
+        }
+        let data = header
+            + 30
+            + read_u16(bytes, header + 26)? as usize
+            + read_u16(bytes, header + 28)? as usize;
+        bytes.get(data..data + compressed_size)?;
+        entries.insert(
+            normalize_path(&name),
+            ArchiveEntry {
+                offset: data,
+                compressed_size,
+                size,
+                method,
+            },
+        );
+    }
+    Some(entries)
+}
+
+/// Assets embedded into the executable (e.g. `include_bytes!`).
+#[derive(Clone, Debug, Default)]
+pub struct EmbeddedSource {
+    assets: HashMap<String, &'static [u8]>,
+}
+
+impl EmbeddedSource {
+    /// Creates an `EmbeddedSource` without assets.
+    pub fn new() -> EmbeddedSource {
+        EmbeddedSource::default()
+    }
+
+    /// Adds an asset.
+    pub fn add(mut self, path: &str, bytes: &'static [u8]) -> EmbeddedSource {
+        self.assets.insert(normalize_path(path), bytes);
+        self
+    }
+}
+
+impl AssetSource for EmbeddedSource {
+    fn read(&self, path: &str) -> Result<Vec<u8>, ReadAssetError> {
+        self.assets
+            .get(path)
+            .map(|bytes| bytes.to_vec())
+            .ok_or(ReadAssetError::NotFound)
+    }
+
+    fn contains(&self, path: &str) -> bool {
+        self.assets.contains_key(path)
+    }
+}
+
+/// Asset sources mounted at mount points, the last mounted ones overriding the others.
+#[derive(Default)]
+pub(crate) struct VirtualFileSystem {
+    mounts: Vec<(String, Box<dyn AssetSource>)>,
+}
+
+impl VirtualFileSystem {
+    /// Mounts a source.
+    pub(crate) fn mount(&mut self, mount_point: &str, source: Box<dyn AssetSource>) {
+        self.mounts.push((normalize_path(mount_point), source));
+    }
+
+    /// Unmounts the sources of a mount point.
+    pub(crate) fn unmount(&mut self, mount_point: &str) {
+        let mount_point = normalize_path(mount_point);
+        self.mounts.retain(|(point, _)| *point != mount_point);
+    }
+
+    /// Checks if no source is mounted.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.mounts.is_empty()
+    }
+
+    /// Returns the mounted sources able to contain a path, with the path relative to them.
+    fn resolve<'a>(
+        &'a self,
+        path: &'a str,
+    ) -> impl Iterator<Item = (&'a dyn AssetSource, &'a str)> {
+        self.mounts.iter().rev().filter_map(move |(point, source)| {
+            let relative = if point.is_empty() {
+                path
+            } else if path.starts_with(point.as_str()) && path[point.len()..].starts_with('/') {
+                &path[point.len() + 1..]
+            } else {
+                return None;
+            };
+            Some((source.as_ref(), relative))
+        })
+    }
+
+    /// Reads an asset from the last mounted source containing it.
+    pub(crate) fn read(&self, path: &str) -> Result<Vec<u8>, ReadAssetError> {
+        let path = normalize_path(path);
+        for (source, relative) in self.resolve(&path) {
+            match source.read(relative) {
+                Err(ReadAssetError::NotFound) => continue,
+                result => return result,
+            }
+        }
+        Err(ReadAssetError::NotFound)
+    }
+
+    /// Returns the last modification time of an asset, from the last mounted source containing it.
+    pub(crate) fn modified(&self, path: &str) -> Option<SystemTime> {
+        let path = normalize_path(path);
+        let modified = self
+            .resolve(&path)
+            .find(|(source, relative)| source.contains(relative))
+            .and_then(|(source, relative)| source.modified(relative));
+        modified
+    }
+
+    /// Checks if a mounted source contains an asset.
+    pub(crate) fn contains(&self, path: &str) -> bool {
+        let path = normalize_path(path);
+        let found = self
+            .resolve(&path)
+            .any(|(source, relative)| source.contains(relative));
+        found
+    }
+
+    /// Reads an asset from the mounted sources, if any contains it.
+    pub(crate) fn read_mounted(&self, path: &Path) -> Result<Option<Vec<u8>>, ReadAssetError> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+        match self.read(&path.to_string_lossy()) {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(ReadAssetError::NotFound) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Copies an asset and its dependencies (OBJ materials, glTF buffers and images...) from the
+    /// mounted sources to a temporary directory, for the raylib loaders reading files only.
+    pub(crate) fn extract(&self, path: &Path) -> Result<Option<ExtractedAsset>, ReadAssetError> {
+        let bytes = match self.read_mounted(path)? {
+            Some(bytes) => bytes,
+            None => return Ok(None),
+        };
+        static EXTRACTIONS: AtomicUsize = AtomicUsize::new(0);
+        let directory = env::temp_dir().join(format!(
+            "rayquaza-{}-{}",
+            process::id(),
+            EXTRACTIONS.fetch_add(1, Ordering::Relaxed)
+        ));
+        let path = normalize_path(&path.to_string_lossy());
+        let extracted = ExtractedAsset {
+            path: directory.join(&path),
+            directory,
+        };
+        let mut visited = HashSet::new();
+        visited.insert(path.clone());
+        let mut pending = vec![(path, bytes)];
+        while let Some((path, bytes)) = pending.pop() {
+            for dependency in dependencies(&path, &bytes) {
+                if visited.insert(dependency.clone()) {
+                    match self.read(&dependency) {
+                        Ok(bytes) => pending.push((dependency, bytes)),
+                        // Left to the loader, reporting it as any missing file
+                        Err(ReadAssetError::NotFound) => {}
+                        Err(error) => return Err(error),
+                    }
+                }
+            }
+            let destination = extracted.directory.join(&path);
+            destination
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|_| fs::write(&destination, bytes))
+                .map_err(|_| ReadAssetError::ReadFailed)?;
+        }
+        Ok(Some(extracted))
+    }
+}
+
+/// Asset copied to a temporary directory with its dependencies, removed when dropped.
+pub(crate) struct ExtractedAsset {
+    directory: PathBuf,
+    path: PathBuf,
+}
+
+impl ExtractedAsset {
+    /// Returns the path of the copied asset.
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for ExtractedAsset {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.directory);
+    }
+}
+
+/// Returns the normalized paths of the files referenced by an asset, relative to its directory:
+/// material libraries of OBJ models, textures of MTL materials, buffers and images of glTF models.
+fn dependencies(path: &str, bytes: &[u8]) -> Vec<String> {
+    let text = String::from_utf8_lossy(bytes);
+    let names: Vec<&str> = match file_type(Path::new(path)).to_lowercase().as_str() {
+        "obj" => text
+            .lines()
+            .filter_map(|line| line.trim_start().strip_prefix("mtllib "))
+            .flat_map(str::split_whitespace)
+            .collect(),
+        "mtl" => text
+            .lines()
+            .filter_map(|line| {
+                let mut tokens = line.split_whitespace();
+                let keyword = tokens.next()?;
+                if keyword.starts_with("map_") || matches!(keyword, "bump" | "disp" | "decal") {
+                    // Texture options precede the file name
+                    tokens.last()
+                } else {
+                    None
+                }
+            })
+            .collect(),
+        "gltf" | "glb" => text
+            .split("\"uri\"")
+            .skip(1)
+            .filter_map(|rest| {
+                let rest = rest.trim_start().strip_prefix(':')?;
+                let rest = rest.trim_start().strip_prefix('"')?;
+                let uri = &rest[..rest.find('"')?];
+                if uri.starts_with("data:") {
+                    None
+                } else {
+                    Some(uri)
+                }
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+    let directory = path.rfind('/').map_or("", |index| &path[..index]);
+    names
+        .into_iter()
+        .map(|name| normalize_path(&format!("{}/{}", directory, name)))
+        .collect()
+}
+
+/// Returns a path using `/` separators, without empty, `.` and `..` components.
+fn normalize_path(path: &str) -> String {
+    let mut components = Vec::new();
+    for component in path.split(&['/', '\\'][..]) {
+        match component {
+            "" | "." => {}
+            ".." => {
+                components.pop();
+            }
+            component => components.push(component),
+        }
+    }
+    components.join("/")
+}
+
+/// Returns the file type of a path (its extension).
+pub(crate) fn file_type(path: &Path) -> &str {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default()
+}
+
+/// Shared handle to an asset, cached or converted from a loaded one, unloaded when the last handle
+/// is dropped.
+pub struct AssetHandle<T> {
+    asset: Rc<RefCell<T>>,
+}
+
+impl<T> AssetHandle<T> {
+    /// Borrows the asset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the asset is being reloaded.
+    pub fn borrow(&self) -> Ref<'_, T> {
+        self.asset.borrow()
+    }
+
+    /// Mutably borrows the asset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the asset is already borrowed.
+    pub fn borrow_mut(&self) -> RefMut<'_, T> {
+        self.asset.borrow_mut()
+    }
+
+    /// Checks if two handles refer to the same asset.
+    pub fn ptr_eq(&self, other: &AssetHandle<T>) -> bool {
+        Rc::ptr_eq(&self.asset, &other.asset)
+    }
+}
+
+impl<T> From<T> for AssetHandle<T> {
+    fn from(asset: T) -> AssetHandle<T> {
+        AssetHandle {
+            asset: Rc::new(RefCell::new(asset)),
+        }
+    }
+}
+
+impl<T> Clone for AssetHandle<T> {
+    fn clone(&self) -> AssetHandle<T> {
+        AssetHandle {
+            asset: self.asset.clone(),
+        }
+    }
+}
+
+/// Statistics of the assets in use.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct AssetStats {
+    pub textures: usize,
+    pub fonts: usize,
+    pub shaders: usize,
+    /// Estimated GPU memory (VRAM) used by textures and font atlases, in bytes.
+    pub video_memory: usize,
+}
+
+/// Kinds of asset hot reloading events.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AssetEvent {
+    Reloaded(PathBuf),
+    TextureFailed {
+        path: PathBuf,
+        error: LoadTextureError,
+    },
+    FontFailed {
+        path: PathBuf,
+        error: LoadFontError,
+    },
+    ShaderFailed {
+        path: PathBuf,
+        error: LoadShaderError,
+    },
+}
+
+/// Cached asset, with the files it was loaded from.
+struct CachedAsset<T> {
+    asset: Weak<RefCell<T>>,
+    paths: Vec<PathBuf>,
+    modified: Vec<Option<SystemTime>>,
+}
+
+impl<T> CachedAsset<T> {
+    /// Creates a `CachedAsset` and returns its handle.
+    fn new(window: &Window, paths: Vec<PathBuf>, asset: T) -> (CachedAsset<T>, AssetHandle<T>) {
+        let asset = Rc::new(RefCell::new(asset));
+        let modified = paths
+            .iter()
+            .map(|path| window.asset_modified(path))
+            .collect();
+        let cached = CachedAsset {
+            asset: Rc::downgrade(&asset),
+            paths,
+            modified,
+        };
+        (cached, AssetHandle { asset })
+    }
+
+    /// Returns a handle to the asset, if still in use.
+    fn handle(&self) -> Option<AssetHandle<T>> {
+        self.asset.upgrade().map(|asset| AssetHandle { asset })
+    }
+}
+
+/// Cache of assets keyed by path as given, loading each file once while handles to it are alive.
+///
+/// With hot reloading enabled, assets whose files are modified are reloaded into the same handles
+/// by `update`.
+#[derive(Default)]
+pub struct Assets {
+    textures: HashMap<PathBuf, CachedAsset<Texture>>,
+    fonts: HashMap<(PathBuf, FontOptions), CachedAsset<Font>>,
+    shaders: HashMap<(Option<PathBuf>, PathBuf), CachedAsset<Shader>>,
+    hot_reload: bool,
+    elapsed: f32,
+}
+
+impl Assets {
+    /// Creates an empty `Assets` cache.
+    pub fn new() -> Assets {
+        Assets::default()
+    }
+
+    /// Returns a handle to a texture, loading it if not in use (see `Window::load_texture`).
+    pub fn texture<P: AsRef<Path>>(
+        &mut self,
+        window: &Window,
+        path: P,
+    ) -> Result<AssetHandle<Texture>, LoadTextureError> {
+        let path = path.as_ref();
+        let key = path.to_path_buf();
+        if let Some(handle) = self.textures.get(&key).and_then(CachedAsset::handle) {
+            return Ok(handle);
+        }
+        let texture = window.load_texture(path)?;
+        let (cached, handle) = CachedAsset::new(window, vec![path.to_path_buf()], texture);
+        self.textures
+            .retain(|_, texture| texture.handle().is_some());
+        self.textures.insert(key, cached);
+        Ok(handle)
+    }
+
+    /// Returns a handle to a font of a given size and characters, loading it if not in use (see
+    /// `Window::load_font_ex`).
+    pub fn font<P: AsRef<Path>>(
+        &mut self,
+        window: &Window,
+        path: P,
+        size: i32,
+        chars: &CharSet,
+    ) -> Result<AssetHandle<Font>, LoadFontError> {
+        let options = FontOptions::new().size(size).chars(chars.clone());
+        self.font_with(window, path, &options)
+    }
+
+    /// Returns a handle to a font loaded with options, loading it if not in use (see
+    /// `Window::load_font_with`).
+    pub fn font_with<P: AsRef<Path>>(
+        &mut self,
+        window: &Window,
+        path: P,
+        options: &FontOptions,
+    ) -> Result<AssetHandle<Font>, LoadFontError> {
+        let path = path.as_ref();
+        let key = (path.to_path_buf(), options.clone());
+        if let Some(handle) = self.fonts.get(&key).and_then(CachedAsset::handle) {
+            return Ok(handle);
+        }
+        let font = window.load_font_with(path, options)?;
+        let (cached, handle) = CachedAsset::new(window, vec![path.to_path_buf()], font);
+        self.fonts.retain(|_, font| font.handle().is_some());
+        self.fonts.insert(key, cached);
+        Ok(handle)
+    }
+
+    /// Returns a handle to a shader, loading it if not in use (see `Window::load_shader` and
+    /// `Window::load_fragment_shader`).
+    ///
+    /// Uniform locations must be queried again after the shader is reloaded.
+    pub fn shader<P: AsRef<Path>>(
+        &mut self,
+        window: &Window,
+        vertex: Option<P>,
+        fragment: P,
+    ) -> Result<AssetHandle<Shader>, LoadShaderError> {
+        let vertex = vertex.as_ref().map(|vertex| vertex.as_ref());
+        let fragment = fragment.as_ref();
+        let key = (vertex.map(Path::to_path_buf), fragment.to_path_buf());
+        if let Some(handle) = self.shaders.get(&key).and_then(CachedAsset::handle) {
+            return Ok(handle);
+        }
+        let paths: Vec<PathBuf> = vertex
+            .into_iter()
+            .chain(Some(fragment))
+            .map(Path::to_path_buf)
+            .collect();
+        let shader = load_shader(window, &paths)?;
+        let (cached, handle) = CachedAsset::new(window, paths, shader);
+        self.shaders.retain(|_, shader| shader.handle().is_some());
+        self.shaders.insert(key, cached);
+        Ok(handle)
+    }
+
+    /// Enables or disables hot reloading.
+    pub fn set_hot_reload(&mut self, enabled: bool) {
+        self.hot_reload = enabled;
+        self.elapsed = 0.0;
+    }
+
+    /// Checks if hot reloading is enabled.
+    pub fn is_hot_reload_enabled(&self) -> bool {
+        self.hot_reload
+    }
+
+    /// Reloads the assets whose files were modified, if hot reloading is enabled, and returns the
+    /// reloading events.
+    ///
+    /// Must be called once per frame, files are checked every half second. Assets failing to
+    /// reload keep their previous version, assets borrowed during the call are reloaded later.
+    pub fn update(&mut self, window: &Window) -> Vec<AssetEvent> {
+        let mut events = Vec::new();
+        if !self.hot_reload {
+            return events;
+        }
+        self.elapsed += window.frame_time();
+        if self.elapsed < HOT_RELOAD_INTERVAL {
+            return events;
+        }
+        self.elapsed = 0.0;
+        reload_modified(
+            &mut self.textures,
+            window,
+            &mut events,
+            |window, _, paths| window.load_texture(&paths[0]),
+            |path, error| AssetEvent::TextureFailed { path, error },
+        );
+        reload_modified(
+            &mut self.fonts,
+            window,
+            &mut events,
+            |window, (_, options), paths| window.load_font_with(&paths[0], options),
+            |path, error| AssetEvent::FontFailed { path, error },
+        );
+        reload_modified(
+            &mut self.shaders,
+            window,
+            &mut events,
+            |window, _, paths| load_shader(window, paths),
+            |path, error| AssetEvent::ShaderFailed { path, error },
+        );
+        events
+    }
+
+    /// Returns statistics of the assets in use.
+    pub fn stats(&self) -> AssetStats {
+        let mut stats = AssetStats::default();
+        for texture in self.textures.values().filter_map(CachedAsset::handle) {
+            stats.textures += 1;
+            stats.video_memory += video_memory(texture.borrow().raw);
+        }
+        for font in self.fonts.values().filter_map(CachedAsset::handle) {
+            stats.fonts += 1;
+            stats.video_memory += video_memory(font.borrow().raw.texture);
+        }
+        stats.shaders = self
+            .shaders
+            .values()
+            .filter(|shader| shader.handle().is_some())
+            .count();
+        stats
+    }
+}
+
+/// Reloads the cached assets whose files were modified.
+fn reload_modified<K, T, E>(
+    cache: &mut HashMap<K, CachedAsset<T>>,
+    window: &Window,
+    events: &mut Vec<AssetEvent>,
+    load: impl Fn(&Window, &K, &[PathBuf]) -> Result<T, E>,
+    failed: impl Fn(PathBuf, E) -> AssetEvent,
+) {
+    for (key, cached) in cache.iter_mut() {
+        let asset = match cached.asset.upgrade() {
+            Some(asset) => asset,
+            None => continue,
+        };
+        let modified: Vec<Option<SystemTime>> = cached
+            .paths
+            .iter()
+            .map(|path| window.asset_modified(path))
+            .collect();
+        if modified == cached.modified {
+            continue;
+        }
+        let mut asset = match asset.try_borrow_mut() {
+            Ok(asset) => asset,
+            Err(_) => continue,
+        };
+        let path = cached
+            .paths
+            .iter()
+            .zip(&cached.modified)
+            .zip(&modified)
+            .find(|((_, previous), current)| previous != current)
+            .map(|((path, _), _)| path.clone())
+            .unwrap_or_default();
+        cached.modified = modified;
+        match load(window, key, &cached.paths) {
+            Ok(reloaded) => {
+                *asset = reloaded;
+                events.push(AssetEvent::Reloaded(path));
+            }
+            Err(error) => events.push(failed(path, error)),
+        }
+    }
+}
+
+/// Loads a shader from an optional vertex shader path followed by a fragment shader path.
+fn load_shader(window: &Window, paths: &[PathBuf]) -> Result<Shader, LoadShaderError> {
+    match paths {
+        [vertex, fragment] => window.load_shader(vertex, fragment),
+        [fragment] => window.load_fragment_shader(fragment),
+        _ => unreachable!(),
+    }
+}
+
+/// Returns the GPU memory (VRAM) used by a texture and its mipmaps, in bytes.
+fn video_memory(texture: ffi::Texture2D) -> usize {
+    let (mut width, mut height) = (texture.width, texture.height);
+    let mut size = 0;
+    for _ in 0..texture.mipmaps {
+        size += unsafe { ffi::GetPixelDataSize(width, height, texture.format) } as usize;
+        width = (width / 2).max(1);
+        height = (height / 2).max(1);
+    }
+    size
+}
+
+/// Assets.
+impl Window {
+    /// Mounts an asset source at a mount point (e.g. `""` for the root, or `textures`).
+    ///
+    /// Assets are resolved from the last mounted source containing them, so mods can override
+    /// base assets by being mounted afterwards. Loaders such as `load_texture`, `load_font` and
+    /// `load_model` resolve their paths through mounted sources first, then through the file
+    /// system (see `AudioDevice::with_assets` for sounds and music).
+    pub fn mount_assets(&mut self, mount_point: &str, source: impl AssetSource + 'static) {
+        self.assets
+            .borrow_mut()
+            .mount(mount_point, Box::new(source));
+    }
+
+    /// Unmounts the asset sources of a mount point.
+    pub fn unmount_assets(&mut self, mount_point: &str) {
+        self.assets.borrow_mut().unmount(mount_point);
+    }
+
+    /// Reads the bytes of an asset from the mounted sources.
+    pub fn read_asset(&self, path: &str) -> Result<Vec<u8>, ReadAssetError> {
+        self.assets.borrow().read(path)
+    }
+
+    /// Checks if an asset exists in the mounted sources.
+    pub fn contains_asset(&self, path: &str) -> bool {
+        self.assets.borrow().contains(path)
+    }
+
+    /// Reads an asset from the mounted sources, if any contains it.
+    pub(crate) fn read_mounted_asset(
+        &self,
+        path: &Path,
+    ) -> Result<Option<Vec<u8>>, ReadAssetError> {
+        self.assets.borrow().read_mounted(path)
+    }
+
+    /// Copies an asset and its dependencies from the mounted sources to a temporary directory, if
+    /// any contains it.
+    pub(crate) fn extract_asset(
+        &self,
+        path: &Path,
+    ) -> Result<Option<ExtractedAsset>, ReadAssetError> {
+        self.assets.borrow().extract(path)
+    }
+
+    /// Reads an asset from the mounted sources, or else from the file system.
+    pub(crate) fn read_asset_or_file(&self, path: &Path) -> Option<Vec<u8>> {
+        match self.read_mounted_asset(path) {
+            Ok(Some(bytes)) => Some(bytes),
+            Ok(None) => fs::read(path).ok(),
+            Err(_) => None,
+        }
+    }
+
+    /// Returns the last modification time of an asset from the mounted sources, or else from the
+    /// file system.
+    pub(crate) fn asset_modified(&self, path: &Path) -> Option<SystemTime> {
+        let path_string = path.to_string_lossy();
+        let assets = self.assets.borrow();
+        if assets.contains(&path_string) {
+            assets.modified(&path_string)
+        } else {
+            fs::metadata(path).ok()?.modified().ok()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::asset::{
+        dependencies, ArchiveSource, AssetSource, EmbeddedSource, ReadAssetError,
+        VirtualFileSystem, DEFLATED, STORED,
+    };
+    use std::{fs, path::Path};
+
+    /// Returns a ZIP archive of stored files.
+    fn archive(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let files: Vec<_> = files
+            .iter()
+            .map(|&(name, data)| (name, STORED, data, data.len()))
+            .collect();
+        archive_with_methods(&files)
+    }
+
+    /// Returns a ZIP archive of files compressed with a method, with their uncompressed size.
+    fn archive_with_methods(files: &[(&str, u16, &[u8], usize)]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let mut directory = Vec::new();
+        for &(name, method, data, size) in files {
+            let header = bytes.len() as u32;
+            bytes.extend_from_slice(&0x0403_4B50u32.to_le_bytes());
+            bytes.extend_from_slice(&[0; 4]);
+            bytes.extend_from_slice(&method.to_le_bytes());
+            bytes.extend_from_slice(&[0; 8]);
+            bytes.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(&(size as u32).to_le_bytes());
+            bytes.extend_from_slice(&(name.len() as u16).to_le_bytes());
+            bytes.extend_from_slice(&[0; 2]);
+            bytes.extend_from_slice(name.as_bytes());
+            bytes.extend_from_slice(data);
+            directory.extend_from_slice(&0x0201_4B50u32.to_le_bytes());
+            directory.extend_from_slice(&[0; 6]);
+            directory.extend_from_slice(&method.to_le_bytes());
+            directory.extend_from_slice(&[0; 8]);
+            directory.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            directory.extend_from_slice(&(size as u32).to_le_bytes());
+            directory.extend_from_slice(&(name.len() as u16).to_le_bytes());
+            directory.extend_from_slice(&[0; 12]);
+            directory.extend_from_slice(&header.to_le_bytes());
+            directory.extend_from_slice(name.as_bytes());
+        }
+        let offset = bytes.len() as u32;
+        bytes.extend_from_slice(&directory);
+        bytes.extend_from_slice(&0x0605_4B50u32.to_le_bytes());
+        bytes.extend_from_slice(&[0; 6]);
+        bytes.extend_from_slice(&(files.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(&(directory.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&offset.to_le_bytes());
+        bytes.extend_from_slice(&[0; 2]);
+        bytes
+    }
+
+    #[test]
+    fn test_archive_source() {
+        let bytes = archive(&[("image/logo.png", b"logo"), ("readme.txt", b"hello")]);
+        let source = ArchiveSource::from_bytes(bytes).unwrap();
+        assert_eq!(source.read("image/logo.png"), Ok(b"logo".to_vec()));
+        assert_eq!(source.read("readme.txt"), Ok(b"hello".to_vec()));
+        assert!(!source.contains("missing.png"));
+        assert!(ArchiveSource::from_bytes(b"not an archive".to_vec()).is_err());
+    }
+
+    #[test]
+    fn test_archive_source_deflated() {
+        let text = b"hello hello hello hello";
+        let deflated = [203, 72, 205, 201, 201, 87, 200, 64, 39, 1];
+        let bytes = archive_with_methods(&[
+            ("text.txt", DEFLATED, &deflated, text.len()),
+            ("truncated.txt", DEFLATED, &deflated, text.len() + 1),
+            ("bomb.txt", DEFLATED, &deflated, u32::MAX as usize),
+        ]);
+        let source = ArchiveSource::from_bytes(bytes).unwrap();
+        assert_eq!(source.read("text.txt"), Ok(text.to_vec()));
+        assert_eq!(
+            source.read("truncated.txt"),
+            Err(ReadAssetError::ReadFailed)
+        );
+        assert_eq!(source.read("bomb.txt"), Err(ReadAssetError::ReadFailed));
+    }
+
+    #[test]
+    fn test_virtual_file_system() {
+        let mut assets = VirtualFileSystem::default();
+        let base = EmbeddedSource::new()
+            .add("image/logo.png", b"base logo")
+            .add("image/player.png", b"base player");
+        let mod_ = EmbeddedSource::new().add("logo.png", b"mod logo");
+        assets.mount("", Box::new(base));
+        assets.mount("image", Box::new(mod_));
+        assert_eq!(assets.read("./image/logo.png"), Ok(b"mod logo".to_vec()));
+        assert_eq!(
+            assets.read("image\\player.png"),
+            Ok(b"base player".to_vec())
+        );
+        assert!(assets.read("image/missing.png").is_err());
+        assets.unmount("image/");
+        assert_eq!(assets.read("image/logo.png"), Ok(b"base logo".to_vec()));
+    }
+
+    #[test]
+    fn test_dependencies() {
+        let obj = b"mtllib scene.mtl\nv 0 0 0\n";
+        assert_eq!(dependencies("models/scene.obj", obj), ["models/scene.mtl"]);
+        let mtl = b"newmtl wood\nmap_Kd -s 2 2 ../textures/wood.png\nbump wood_n.png\n";
+        assert_eq!(
+            dependencies("models/scene.mtl", mtl),
+            ["textures/wood.png", "models/wood_n.png"]
+        );
+        let gltf = br#"{"buffers": [{"uri": "robot.bin"}, {"uri": "data:,"}]}"#;
+        assert_eq!(dependencies("robot.GLTF", gltf), ["robot.bin"]);
+        assert!(dependencies("logo.png", b"\"uri\": \"a\"").is_empty());
+    }
+
+    #[test]
+    fn test_extract() {
+        let mut assets = VirtualFileSystem::default();
+        let source = EmbeddedSource::new()
+            .add("models/scene.obj", b"mtllib scene.mtl\n")
+            .add("models/scene.mtl", b"map_Kd wood.png\n")
+            .add("models/wood.png", b"wood");
+        assets.mount("", Box::new(source));
+        assert!(assets.extract(Path::new("missing.obj")).unwrap().is_none());
+        let extracted = assets
+            .extract(Path::new("models/scene.obj"))
+            .unwrap()
+            .unwrap();
+        let directory = extracted.path().parent().unwrap().to_path_buf();
+        assert_eq!(fs::read(extracted.path()).unwrap(), b"mtllib scene.mtl\n");
+        assert_eq!(fs::read(directory.join("wood.png")).unwrap(), b"wood");
+        drop(extracted);
+        assert!(!directory.exists());
+    }
+}
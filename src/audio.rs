@@ -0,0 +1,575 @@
+//! Audio management.
+//!
+//! Supported file formats are selected with Cargo features: `wav`, `ogg`, `mp3`, `flac`, `xm`
+//! and `mod`.
+
+use crate::{
+    asset::{ExtractedAsset, VirtualFileSystem},
+    ffi,
+    window::Window,
+};
+use std::{
+    cell::RefCell,
+    error::Error,
+    ffi::CString,
+    fmt,
+    marker::PhantomData,
+    path::Path,
+    rc::Rc,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// Holder of the audio device.
+pub(crate) struct AudioHandle;
+
+impl Drop for AudioHandle {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::CloseAudioDevice();
+        }
+    }
+}
+
+/// Kinds of audio device creation errors.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CreateAudioDeviceError {
+    InitializationFailed,
+    AlreadyCreated,
+}
+
+impl fmt::Display for CreateAudioDeviceError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{:?}", self)
+    }
+}
+
+impl Error for CreateAudioDeviceError {}
+
+/// Kinds of audio loading errors.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LoadAudioError {
+    ReadFailed,
+    CreationFailed,
+}
+
+impl fmt::Display for LoadAudioError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{:?}", self)
+    }
+}
+
+impl Error for LoadAudioError {}
+
+/// Audio device.
+pub struct AudioDevice {
+    pub(crate) handle: Rc<AudioHandle>,
+    assets: Rc<RefCell<VirtualFileSystem>>,
+}
+
+impl AudioDevice {
+    /// Initializes the audio device and context.
+    pub fn init() -> Result<AudioDevice, CreateAudioDeviceError> {
+        unsafe {
+            static INITIALIZED: AtomicBool = AtomicBool::new(false);
+            if INITIALIZED.load(Ordering::Relaxed) {
+                Err(CreateAudioDeviceError::AlreadyCreated)
+            } else {
+                ffi::InitAudioDevice();
+                if ffi::IsAudioDeviceReady() {
+                    INITIALIZED.store(true, Ordering::Relaxed);
+                    Ok(AudioDevice {
+                        handle: Rc::new(AudioHandle),
+                        assets: Default::default(),
+                    })
+                } else {
+                    Err(CreateAudioDeviceError::InitializationFailed)
+                }
+            }
+        }
+    }
+
+    /// Resolves the paths of the sound and music loaders through the asset sources mounted into a
+    /// window first.
+    pub fn with_assets(mut self, window: &Window) -> AudioDevice {
+        self.assets = window.assets.clone();
+        self
+    }
+
+    /// Sets master volume (listener).
+    #[inline]
+    pub fn set_master_volume(&mut self, volume: f32) {
+        unsafe {
+            ffi::SetMasterVolume(volume);
+        }
+    }
+
+    /// Stops any sound playing (using multichannel buffer pool).
+    #[inline]
+    pub fn stop_sound_multi(&mut self) {
+        unsafe {
+            ffi::StopSoundMulti();
+        }
+    }
+
+    /// Loads sound from file.
+    ///
+    /// The path is resolved through the asset sources of the window given to `with_assets` first.
+    pub fn load_sound<P: AsRef<Path>>(&self, path: P) -> Result<Sound, LoadAudioError> {
+        let extracted = self.extract_asset(path.as_ref())?;
+        let path = extracted
+            .as_ref()
+            .map_or(path.as_ref(), ExtractedAsset::path);
+        unsafe {
+            let path = CString::new(path.display().to_string()).unwrap();
+            let raw = ffi::LoadSound(path.as_ptr());
+            if !raw.stream.buffer.is_null() {
+                Ok(Sound {
+                    _handle: self.handle.clone(),
+                    raw,
+                })
+            } else {
+                Err(LoadAudioError::ReadFailed)
+            }
+        }
+    }
+
+    /// Loads sound from wave data.
+    pub fn load_sound_from_wave(&self, wave: &Wave) -> Result<Sound, LoadAudioError> {
+        unsafe {
+            let raw = ffi::LoadSoundFromWave(wave.raw);
+            if !raw.stream.buffer.is_null() {
+                Ok(Sound {
+                    _handle: self.handle.clone(),
+                    raw,
+                })
+            } else {
+                Err(LoadAudioError::CreationFailed)
+            }
+        }
+    }
+
+    /// Loads music stream from file.
+    ///
+    /// The path is resolved through the asset sources of the window given to `with_assets` first.
+    pub fn load_music<P: AsRef<Path>>(&self, path: P) -> Result<Music, LoadAudioError> {
+        let extracted = self.extract_asset(path.as_ref())?;
+        let path = extracted
+            .as_ref()
+            .map_or(path.as_ref(), ExtractedAsset::path);
+        unsafe {
+            let path = CString::new(path.display().to_string()).unwrap();
+            let raw = ffi::LoadMusicStream(path.as_ptr());
+            if !raw.stream.buffer.is_null() {
+                Ok(Music {
+                    _handle: self.handle.clone(),
+                    _extracted: extracted,
+                    raw,
+                })
+            } else {
+                Err(LoadAudioError::ReadFailed)
+            }
+        }
+    }
+
+    /// Creates an audio stream to play raw samples of type `S`.
+    pub fn load_audio_stream<S: Sample>(
+        &self,
+        sample_rate: u32,
+        channels: u32,
+    ) -> Result<AudioStream<S>, LoadAudioError> {
+        unsafe {
+            let raw = ffi::InitAudioStream(sample_rate, S::SIZE, channels);
+            if !raw.buffer.is_null() {
+                Ok(AudioStream {
+                    _handle: self.handle.clone(),
+                    _sample: PhantomData,
+                    raw,
+                })
+            } else {
+                Err(LoadAudioError::CreationFailed)
+            }
+        }
+    }
+
+    /// Copies an asset from the mounted sources to a temporary directory, if any contains it.
+    fn extract_asset(&self, path: &Path) -> Result<Option<ExtractedAsset>, LoadAudioError> {
+        self.assets
+            .borrow()
+            .extract(path)
+            .map_err(|_| LoadAudioError::ReadFailed)
+    }
+}
+
+/// Wave data stored in CPU memory (RAM).
+pub struct Wave {
+    pub(crate) raw: ffi::Wave,
+}
+
+impl Wave {
+    /// Loads wave data from file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Wave, LoadAudioError> {
+        unsafe {
+            let path = CString::new(path.as_ref().display().to_string()).unwrap();
+            let raw = ffi::LoadWave(path.as_ptr());
+            if !raw.data.is_null() {
+                Ok(Wave { raw })
+            } else {
+                Err(LoadAudioError::ReadFailed)
+            }
+        }
+    }
+
+    /// Returns the total number of samples.
+    pub fn sample_count(&self) -> u32 {
+        self.raw.sampleCount
+    }
+
+    /// Returns the frequency (samples per second).
+    pub fn sample_rate(&self) -> u32 {
+        self.raw.sampleRate
+    }
+
+    /// Returns the bit depth (bits per sample): 8, 16 or 32.
+    pub fn sample_size(&self) -> u32 {
+        self.raw.sampleSize
+    }
+
+    /// Returns the number of channels (1 for mono, 2 for stereo).
+    pub fn channels(&self) -> u32 {
+        self.raw.channels
+    }
+
+    /// Converts wave data to the desired format.
+    pub fn set_format(&mut self, sample_rate: i32, sample_size: i32, channels: i32) {
+        unsafe {
+            ffi::WaveFormat(&mut self.raw, sample_rate, sample_size, channels);
+        }
+    }
+
+    /// Crops the wave to a defined samples range.
+    pub fn crop(&mut self, start: i32, end: i32) {
+        unsafe {
+            ffi::WaveCrop(&mut self.raw, start, end);
+        }
+    }
+}
+
+impl Clone for Wave {
+    fn clone(&self) -> Wave {
+        unsafe {
+            Wave {
+                raw: ffi::WaveCopy(self.raw),
+            }
+        }
+    }
+}
+
+impl Drop for Wave {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::UnloadWave(self.raw);
+        }
+    }
+}
+
+/// Sound fully loaded into memory.
+pub struct Sound {
+    pub(crate) _handle: Rc<AudioHandle>,
+    pub(crate) raw: ffi::Sound,
+}
+
+impl Sound {
+    /// Plays the sound.
+    #[inline]
+    pub fn play(&mut self) {
+        unsafe {
+            ffi::PlaySound(self.raw);
+        }
+    }
+
+    /// Plays the sound using the multichannel buffer pool.
+    #[inline]
+    pub fn play_multi(&mut self) {
+        unsafe {
+            ffi::PlaySoundMulti(self.raw);
+        }
+    }
+
+    /// Stops playing the sound.
+    #[inline]
+    pub fn stop(&mut self) {
+        unsafe {
+            ffi::StopSound(self.raw);
+        }
+    }
+
+    /// Pauses the sound.
+    #[inline]
+    pub fn pause(&mut self) {
+        unsafe {
+            ffi::PauseSound(self.raw);
+        }
+    }
+
+    /// Resumes a paused sound.
+    #[inline]
+    pub fn resume(&mut self) {
+        unsafe {
+            ffi::ResumeSound(self.raw);
+        }
+    }
+
+    /// Checks if the sound is currently playing.
+    #[inline]
+    pub fn is_playing(&self) -> bool {
+        unsafe { ffi::IsSoundPlaying(self.raw) }
+    }
+
+    /// Sets volume for the sound (1.0 is max level).
+    #[inline]
+    pub fn set_volume(&mut self, volume: f32) {
+        unsafe {
+            ffi::SetSoundVolume(self.raw, volume);
+        }
+    }
+
+    /// Sets pitch for the sound (1.0 is base level).
+    #[inline]
+    pub fn set_pitch(&mut self, pitch: f32) {
+        unsafe {
+            ffi::SetSoundPitch(self.raw, pitch);
+        }
+    }
+}
+
+impl Drop for Sound {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::UnloadSound(self.raw);
+        }
+    }
+}
+
+/// Music streamed from file.
+pub struct Music {
+    pub(crate) _handle: Rc<AudioHandle>,
+    // Streamed copy of a mounted asset, removed once the music is unloaded
+    pub(crate) _extracted: Option<ExtractedAsset>,
+    pub(crate) raw: ffi::Music,
+}
+
+impl Music {
+    /// Starts playing the music.
+    #[inline]
+    pub fn play(&mut self) {
+        unsafe {
+            ffi::PlayMusicStream(self.raw);
+        }
+    }
+
+    /// Updates buffers for the music streaming (must be called every frame).
+    #[inline]
+    pub fn update(&mut self) {
+        unsafe {
+            ffi::UpdateMusicStream(self.raw);
+        }
+    }
+
+    /// Stops playing the music.
+    #[inline]
+    pub fn stop(&mut self) {
+        unsafe {
+            ffi::StopMusicStream(self.raw);
+        }
+    }
+
+    /// Pauses the music.
+    #[inline]
+    pub fn pause(&mut self) {
+        unsafe {
+            ffi::PauseMusicStream(self.raw);
+        }
+    }
+
+    /// Resumes a paused music.
+    #[inline]
+    pub fn resume(&mut self) {
+        unsafe {
+            ffi::ResumeMusicStream(self.raw);
+        }
+    }
+
+    /// Seeks the music to a position in seconds (not supported by XM and MOD formats).
+    ///
+    /// The position is clamped between the start and the end of the music.
+    #[inline]
+    pub fn seek(&mut self, position: f32) {
+        // Converted to an unsigned frame index by raylib
+        let position = position.max(0.0).min(self.length());
+        unsafe {
+            ffi::SeekMusicStream(self.raw, position);
+        }
+    }
+
+    /// Checks if the music is playing.
+    #[inline]
+    pub fn is_playing(&self) -> bool {
+        unsafe { ffi::IsMusicPlaying(self.raw) }
+    }
+
+    /// Sets volume for the music (1.0 is max level).
+    #[inline]
+    pub fn set_volume(&mut self, volume: f32) {
+        unsafe {
+            ffi::SetMusicVolume(self.raw, volume);
+        }
+    }
+
+    /// Sets pitch for the music (1.0 is base level).
+    #[inline]
+    pub fn set_pitch(&mut self, pitch: f32) {
+        unsafe {
+            ffi::SetMusicPitch(self.raw, pitch);
+        }
+    }
+
+    /// Sets the number of times the music will play (0 means infinite loop).
+    #[inline]
+    pub fn set_loop_count(&mut self, count: i32) {
+        unsafe {
+            ffi::SetMusicLoopCount(self.raw, count);
+        }
+    }
+
+    /// Returns the music length in seconds.
+    #[inline]
+    pub fn length(&self) -> f32 {
+        unsafe { ffi::GetMusicTimeLength(self.raw) }
+    }
+
+    /// Returns the time played in seconds.
+    #[inline]
+    pub fn time_played(&self) -> f32 {
+        unsafe { ffi::GetMusicTimePlayed(self.raw) }
+    }
+}
+
+impl Drop for Music {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::UnloadMusicStream(self.raw);
+        }
+    }
+}
+
+/// Audio sample type, defining the bit depth of an `AudioStream`.
+pub trait Sample: Copy {
+    /// Size of the sample in bits.
+    const SIZE: u32;
+}
+
+impl Sample for u8 {
+    const SIZE: u32 = 8;
+}
+
+impl Sample for i16 {
+    const SIZE: u32 = 16;
+}
+
+impl Sample for f32 {
+    const SIZE: u32 = 32;
+}
+
+/// Audio stream of raw samples (interleaved by channel).
+pub struct AudioStream<S: Sample> {
+    pub(crate) _handle: Rc<AudioHandle>,
+    pub(crate) _sample: PhantomData<S>,
+    pub(crate) raw: ffi::AudioStream,
+}
+
+impl<S: Sample> AudioStream<S> {
+    /// Returns the frequency (samples per second).
+    pub fn sample_rate(&self) -> u32 {
+        self.raw.sampleRate
+    }
+
+    /// Returns the number of channels (1 for mono, 2 for stereo).
+    pub fn channels(&self) -> u32 {
+        self.raw.channels
+    }
+
+    /// Checks if any audio stream buffer requires to be refilled.
+    #[inline]
+    pub fn is_processed(&self) -> bool {
+        unsafe { ffi::IsAudioStreamProcessed(self.raw) }
+    }
+
+    /// Fills the next processed buffer with samples.
+    pub fn update(&mut self, samples: &[S]) {
+        unsafe {
+            ffi::UpdateAudioStream(self.raw, samples.as_ptr() as *const _, samples.len() as i32);
+        }
+    }
+
+    /// Plays the audio stream.
+    #[inline]
+    pub fn play(&mut self) {
+        unsafe {
+            ffi::PlayAudioStream(self.raw);
+        }
+    }
+
+    /// Stops the audio stream.
+    #[inline]
+    pub fn stop(&mut self) {
+        unsafe {
+            ffi::StopAudioStream(self.raw);
+        }
+    }
+
+    /// Pauses the audio stream.
+    #[inline]
+    pub fn pause(&mut self) {
+        unsafe {
+            ffi::PauseAudioStream(self.raw);
+        }
+    }
+
+    /// Resumes the audio stream.
+    #[inline]
+    pub fn resume(&mut self) {
+        unsafe {
+            ffi::ResumeAudioStream(self.raw);
+        }
+    }
+
+    /// Checks if the audio stream is playing.
+    #[inline]
+    pub fn is_playing(&self) -> bool {
+        unsafe { ffi::IsAudioStreamPlaying(self.raw) }
+    }
+
+    /// Sets volume for the audio stream (1.0 is max level).
+    #[inline]
+    pub fn set_volume(&mut self, volume: f32) {
+        unsafe {
+            ffi::SetAudioStreamVolume(self.raw, volume);
+        }
+    }
+
+    /// Sets pitch for the audio stream (1.0 is base level).
+    #[inline]
+    pub fn set_pitch(&mut self, pitch: f32) {
+        unsafe {
+            ffi::SetAudioStreamPitch(self.raw, pitch);
+        }
+    }
+}
+
+impl<S: Sample> Drop for AudioStream<S> {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::CloseAudioStream(self.raw);
+        }
+    }
+}
@@ -0,0 +1,383 @@
+//! AngelCode BMFont descriptor parsing (text and binary formats).
+
+use std::{convert::TryInto, str};
+
+/// Signature of binary descriptors.
+const BINARY_SIGNATURE: &[u8] = b"BMF";
+
+/// Supported version of binary descriptors.
+const BINARY_VERSION: u8 = 3;
+
+/// Size of character records in binary descriptors.
+const BINARY_CHAR_SIZE: usize = 20;
+
+/// Size of kerning records in binary descriptors.
+const BINARY_KERNING_SIZE: usize = 10;
+
+/// Font described by a BMFont descriptor.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct BmFont {
+    /// Distance between lines, in pixels.
+    pub(crate) line_height: i32,
+    /// Size of the atlas pages, in pixels.
+    pub(crate) scale_width: i32,
+    pub(crate) scale_height: i32,
+    /// File names of the atlas pages, relative to the descriptor.
+    pub(crate) pages: Vec<String>,
+    pub(crate) chars: Vec<BmChar>,
+    pub(crate) kernings: Vec<BmKerning>,
+}
+
+/// Character of a BMFont.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct BmChar {
+    pub(crate) id: u32,
+    pub(crate) x: i32,
+    pub(crate) y: i32,
+    pub(crate) width: i32,
+    pub(crate) height: i32,
+    pub(crate) x_offset: i32,
+    pub(crate) y_offset: i32,
+    pub(crate) x_advance: i32,
+    pub(crate) page: usize,
+}
+
+impl BmChar {
+    /// Checks if the character rectangle is non-negative and lies inside a page of a given size.
+    pub(crate) fn is_inside(&self, page_width: i32, page_height: i32) -> bool {
+        let fits = |start: i32, size: i32, page_size: i32| {
+            start >= 0
+                && size >= 0
+                && matches!(start.checked_add(size), Some(end) if end <= page_size)
+        };
+        fits(self.x, self.width, page_width) && fits(self.y, self.height, page_height)
+    }
+}
+
+/// Kerning pair of a BMFont.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct BmKerning {
+    pub(crate) first: u32,
+    pub(crate) second: u32,
+    pub(crate) amount: i32,
+}
+
+/// Parses a text or binary BMFont descriptor, returning `None` if invalid.
+pub(crate) fn parse(bytes: &[u8]) -> Option<BmFont> {
+    if bytes.starts_with(BINARY_SIGNATURE) {
+        parse_binary(bytes)
+    } else {
+        parse_text(str::from_utf8(bytes).ok()?)
+    }
+}
+
+/// Parses a text descriptor, made of lines of tags followed by `key=value` attributes.
+fn parse_text(text: &str) -> Option<BmFont> {
+    let mut font = BmFont::default();
+    let mut pages = Vec::new();
+    let mut has_common = false;
+    for line in text.lines() {
+        let mut tokens = tokenize(line).into_iter();
+        let tag = match tokens.next() {
+            Some((tag, None)) => tag,
+            _ => continue,
+        };
+        let attributes: Vec<(String, String)> = tokens
+            .filter_map(|(key, value)| value.map(|value| (key, value)))
+            .collect();
+        let get = |key: &str| -> Option<i32> {
+            attributes
+                .iter()
+                .find(|(name, _)| name == key)
+                .and_then(|(_, value)| value.parse().ok())
+        };
+        match tag.as_str() {
+            "common" => {
+                font.line_height = get("lineHeight")?;
+                font.scale_width = get("scaleW").unwrap_or(0);
+                font.scale_height = get("scaleH").unwrap_or(0);
+                has_common = true;
+            }
+            "page" => {
+                let file = attributes.iter().find(|(name, _)| name == "file")?;
+                pages.push((get("id")?, file.1.clone()));
+            }
+            "char" => font.chars.push(BmChar {
+                id: get("id")? as u32,
+                x: get("x")?,
+                y: get("y")?,
+                width: get("width")?,
+                height: get("height")?,
+                x_offset: get("xoffset").unwrap_or(0),
+                y_offset: get("yoffset").unwrap_or(0),
+                x_advance: get("xadvance")?,
+                page: get("page").unwrap_or(0) as usize,
+            }),
+            "kerning" => font.kernings.push(BmKerning {
+                first: get("first")? as u32,
+                second: get("second")? as u32,
+                amount: get("amount")?,
+            }),
+            _ => {}
+        }
+    }
+    pages.sort();
+    font.pages = pages.into_iter().map(|(_, file)| file).collect();
+    if has_common {
+        Some(font)
+    } else {
+        None
+    }
+}
+
+/// Writes a text descriptor.
+pub(crate) fn to_text(font: &BmFont) -> String {
+    let mut text = format!("info face=\"\" size={}\n", font.line_height);
+    text += &format!(
+        "common lineHeight={} base={} scaleW={} scaleH={} pages={} packed=0\n",
+        font.line_height,
+        font.line_height,
+        font.scale_width,
+        font.scale_height,
+        font.pages.len()
+    );
+    for (id, page) in font.pages.iter().enumerate() {
+        text += &format!("page id={} file=\"{}\"\n", id, page);
+    }
+    text += &format!("chars count={}\n", font.chars.len());
+    for char in &font.chars {
+        text += &format!(
+            "char id={} x={} y={} width={} height={} xoffset={} yoffset={} xadvance={} page={} chnl=15\n",
+            char.id,
+            char.x,
+            char.y,
+            char.width,
+            char.height,
+            char.x_offset,
+            char.y_offset,
+            char.x_advance,
+            char.page
+        );
+    }
+    if !font.kernings.is_empty() {
+        text += &format!("kernings count={}\n", font.kernings.len());
+        for kerning in &font.kernings {
+            text += &format!(
+                "kerning first={} second={} amount={}\n",
+                kerning.first, kerning.second, kerning.amount
+            );
+        }
+    }
+    text
+}
+
+/// Splits a descriptor line into words and `key=value` pairs, values being possibly quoted.
+fn tokenize(line: &str) -> Vec<(String, Option<String>)> {
+    let mut tokens = Vec::new();
+    let mut chars = line.chars().peekable();
+    loop {
+        match chars.peek() {
+            Some(char) if char.is_whitespace() => {
+                chars.next();
+                continue;
+            }
+            Some(_) => {}
+            None => return tokens,
+        }
+        let mut key = String::new();
+        while let Some(&char) = chars.peek() {
+            if char.is_whitespace() || char == '=' {
+                break;
+            }
+            key.push(char);
+            chars.next();
+        }
+        if chars.peek() != Some(&'=') {
+            tokens.push((key, None));
+            continue;
+        }
+        chars.next();
+        let mut value = String::new();
+        if chars.peek() == Some(&'"') {
+            chars.next();
+            for char in &mut chars {
+                if char == '"' {
+                    break;
+                }
+                value.push(char);
+            }
+        } else {
+            while let Some(&char) = chars.peek() {
+                if char.is_whitespace() {
+                    break;
+                }
+                value.push(char);
+                chars.next();
+            }
+        }
+        tokens.push((key, Some(value)));
+    }
+}
+
+/// Parses a binary descriptor, made of typed blocks after the signature and version.
+fn parse_binary(bytes: &[u8]) -> Option<BmFont> {
+    if bytes.get(BINARY_SIGNATURE.len()) != Some(&BINARY_VERSION) {
+        return None;
+    }
+    let mut font = BmFont::default();
+    let mut has_common = false;
+    let mut offset = BINARY_SIGNATURE.len() + 1;
+    while offset < bytes.len() {
+        let kind = bytes[offset];
+        let size = read_u32(bytes, offset + 1)? as usize;
+        let block = bytes.get(offset + 5..offset + 5 + size)?;
+        match kind {
+            // Common
+            2 => {
+                font.line_height = read_u16(block, 0)? as i32;
+                font.scale_width = read_u16(block, 4)? as i32;
+                font.scale_height = read_u16(block, 6)? as i32;
+                has_common = true;
+            }
+            // Pages, as null-terminated strings
+            3 => {
+                font.pages = block
+                    .split(|&byte| byte == 0)
+                    .filter(|name| !name.is_empty())
+                    .map(|name| String::from_utf8_lossy(name).into_owned())
+                    .collect();
+            }
+            // Characters
+            4 => {
+                for record in block.chunks_exact(BINARY_CHAR_SIZE) {
+                    font.chars.push(BmChar {
+                        id: read_u32(record, 0)?,
+                        x: read_u16(record, 4)? as i32,
+                        y: read_u16(record, 6)? as i32,
+                        width: read_u16(record, 8)? as i32,
+                        height: read_u16(record, 10)? as i32,
+                        x_offset: read_u16(record, 12)? as i16 as i32,
+                        y_offset: read_u16(record, 14)? as i16 as i32,
+                        x_advance: read_u16(record, 16)? as i16 as i32,
+                        page: record[18] as usize,
+                    });
+                }
+            }
+            // Kerning pairs
+            5 => {
+                for record in block.chunks_exact(BINARY_KERNING_SIZE) {
+                    font.kernings.push(BmKerning {
+                        first: read_u32(record, 0)?,
+                        second: read_u32(record, 4)?,
+                        amount: read_u16(record, 8)? as i16 as i32,
+                    });
+                }
+            }
+            _ => {}
+        }
+        offset += 5 + size;
+    }
+    if has_common {
+        Some(font)
+    } else {
+        None
+    }
+}
+
+/// Reads a little-endian `u16`.
+fn read_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(
+        bytes.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+/// Reads a little-endian `u32`.
+fn read_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(
+        bytes.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bmfont::{parse, to_text, BmChar, BmKerning};
+
+    #[test]
+    fn test_parse_text() {
+        let text = "info face=\"Pixel Font\" size=16 bold=0\n\
+                    common lineHeight=18 base=14 scaleW=128 scaleH=64 pages=2 packed=0\n\
+                    page id=1 file=\"pixel font_1.png\"\n\
+                    page id=0 file=\"pixel font_0.png\"\n\
+                    chars count=1\n\
+                    char id=65 x=1 y=2 width=7 height=9 xoffset=-1 yoffset=3 xadvance=8 page=1 chnl=15\n\
+                    kernings count=1\n\
+                    kerning first=65 second=86 amount=-2\n";
+        let font = parse(text.as_bytes()).unwrap();
+        assert_eq!(font.line_height, 18);
+        assert_eq!(font.pages, vec!["pixel font_0.png", "pixel font_1.png"]);
+        assert_eq!(
+            font.chars,
+            vec![BmChar {
+                id: 65,
+                x: 1,
+                y: 2,
+                width: 7,
+                height: 9,
+                x_offset: -1,
+                y_offset: 3,
+                x_advance: 8,
+                page: 1,
+            }]
+        );
+        assert_eq!(
+            font.kernings,
+            vec![BmKerning {
+                first: 65,
+                second: 86,
+                amount: -2,
+            }]
+        );
+        assert_eq!(font.scale_width, 128);
+        assert!(font.chars[0].is_inside(8, 11));
+        assert!(!font.chars[0].is_inside(7, 11));
+        assert!(!font.chars[0].is_inside(8, 10));
+        let char = BmChar {
+            width: -1,
+            ..font.chars[0]
+        };
+        assert!(!char.is_inside(128, 64));
+        assert_eq!(parse(to_text(&font).as_bytes()), Some(font));
+        assert_eq!(parse(b"info face=\"Pixel Font\"\n"), None);
+    }
+
+    #[test]
+    fn test_parse_binary() {
+        let mut bytes = b"BMF\x03".to_vec();
+        let mut block = |kind: u8, data: &[u8]| {
+            bytes.push(kind);
+            bytes.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(data);
+        };
+        block(2, &[18, 0, 14, 0, 128, 0, 64, 0, 1, 0, 0, 0, 0, 0, 0]);
+        block(3, b"font_0.png\0");
+        let mut char = Vec::new();
+        char.extend_from_slice(&65u32.to_le_bytes());
+        for value in &[1i16, 2, 7, 9, -1, 3, 8] {
+            char.extend_from_slice(&value.to_le_bytes());
+        }
+        char.extend_from_slice(&[0, 15]);
+        block(4, &char);
+        let mut kerning = Vec::new();
+        kerning.extend_from_slice(&65u32.to_le_bytes());
+        kerning.extend_from_slice(&86u32.to_le_bytes());
+        kerning.extend_from_slice(&(-2i16).to_le_bytes());
+        block(5, &kerning);
+        let font = parse(&bytes).unwrap();
+        assert_eq!(font.line_height, 18);
+        assert_eq!(font.pages, vec!["font_0.png"]);
+        assert_eq!(font.chars[0].x_offset, -1);
+        assert_eq!(font.chars[0].x_advance, 8);
+        assert_eq!(font.kernings[0].amount, -2);
+        assert_eq!(parse(&bytes[..bytes.len() - 1]), None);
+    }
+}
@@ -1,6 +1,9 @@
 //! Camera utilities.
 
-use crate::{ffi, math::Vector2};
+use crate::{
+    ffi,
+    math::{Vector2, Vector3},
+};
 use std::mem;
 
 /// 2D camera.
@@ -46,3 +49,64 @@ impl Into<ffi::Camera2D> for Camera2D {
         unsafe { mem::transmute(self) }
     }
 }
+
+/// Kinds of camera projections.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Projection {
+    Perspective = ffi::CAMERA_PERSPECTIVE,
+    Orthographic = ffi::CAMERA_ORTHOGRAPHIC,
+}
+
+/// 3D camera.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct Camera3D {
+    pub position: Vector3,
+    pub target: Vector3,
+    pub up: Vector3,
+    /// Field of view in Y (degrees) in perspective, near plane height in orthographic.
+    pub fovy: f32,
+    pub projection: Projection,
+}
+
+impl Camera3D {
+    /// Creates a `Camera3D`.
+    #[inline]
+    pub const fn new(
+        position: Vector3,
+        target: Vector3,
+        up: Vector3,
+        fovy: f32,
+        projection: Projection,
+    ) -> Camera3D {
+        Camera3D {
+            position,
+            target,
+            up,
+            fovy,
+            projection,
+        }
+    }
+}
+
+impl From<&Camera3D> for Camera3D {
+    #[inline]
+    fn from(camera: &Camera3D) -> Camera3D {
+        *camera
+    }
+}
+
+impl From<ffi::Camera3D> for Camera3D {
+    #[inline]
+    fn from(camera: ffi::Camera3D) -> Camera3D {
+        unsafe { mem::transmute(camera) }
+    }
+}
+
+impl Into<ffi::Camera3D> for Camera3D {
+    #[inline]
+    fn into(self) -> ffi::Camera3D {
+        unsafe { mem::transmute(self) }
+    }
+}
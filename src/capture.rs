@@ -0,0 +1,203 @@
+//! Window recording into animated GIF files or PNG sequences.
+
+use crate::{gif::GifEncoder, window::Window};
+use std::{
+    error::Error,
+    fmt,
+    fs::File,
+    io::BufWriter,
+    path::{Path, PathBuf},
+};
+
+/// Kinds of window recording starting errors.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StartRecordingError {
+    AlreadyRecording,
+    UnsupportedFormat,
+    WriteFailed,
+}
+
+impl fmt::Display for StartRecordingError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{:?}", self)
+    }
+}
+
+impl Error for StartRecordingError {}
+
+/// Kinds of window recording stopping errors.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StopRecordingError {
+    NotRecording,
+    WriteFailed,
+}
+
+impl fmt::Display for StopRecordingError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{:?}", self)
+    }
+}
+
+impl Error for StopRecordingError {}
+
+/// Window recording options.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RecordingOptions {
+    frame_skip: u32,
+    scale: f32,
+}
+
+impl RecordingOptions {
+    /// Creates new `RecordingOptions`, recording every frame at full size.
+    pub fn new() -> RecordingOptions {
+        RecordingOptions::default()
+    }
+
+    /// Sets the number of frames skipped after each recorded frame.
+    pub fn frame_skip(mut self, frame_skip: u32) -> RecordingOptions {
+        self.frame_skip = frame_skip;
+        self
+    }
+
+    /// Sets the scale applied to recorded frames.
+    pub fn scale(mut self, scale: f32) -> RecordingOptions {
+        self.scale = scale;
+        self
+    }
+}
+
+impl Default for RecordingOptions {
+    fn default() -> RecordingOptions {
+        RecordingOptions {
+            frame_skip: 0,
+            scale: 1.0,
+        }
+    }
+}
+
+/// Destination of recorded frames.
+enum Output {
+    Gif(GifEncoder<BufWriter<File>>),
+    PngSequence(PathBuf),
+}
+
+/// Window recording in progress.
+pub(crate) struct ScreenRecording {
+    output: Output,
+    options: RecordingOptions,
+    width: i32,
+    height: i32,
+    frame: u32,
+    skipped: u32,
+    delay: f32,
+    failed: bool,
+}
+
+/// Window recording.
+impl Window {
+    /// Starts recording the frames drawn by `Window::draw`.
+    ///
+    /// The format is deduced from the file extension: `gif` for an animated GIF file, `png` for a
+    /// sequence of PNG files numbered after the file name (e.g. `clip0000.png`, `clip0001.png`).
+    /// GIF frames with more than 256 colors are quantized to a fixed palette.
+    pub fn start_recording<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        options: RecordingOptions,
+    ) -> Result<(), StartRecordingError> {
+        if self.is_recording() {
+            return Err(StartRecordingError::AlreadyRecording);
+        }
+        let path = path.as_ref();
+        let width = ((self.width() as f32 * options.scale) as i32).max(1);
+        let height = ((self.height() as f32 * options.scale) as i32).max(1);
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| extension.to_lowercase());
+        let output = match extension.as_deref() {
+            Some("gif") => {
+                let file = File::create(path).map_err(|_| StartRecordingError::WriteFailed)?;
+                let encoder = GifEncoder::new(BufWriter::new(file), width as u16, height as u16)
+                    .map_err(|_| StartRecordingError::WriteFailed)?;
+                Output::Gif(encoder)
+            }
+            Some("png") => Output::PngSequence(path.to_path_buf()),
+            _ => return Err(StartRecordingError::UnsupportedFormat),
+        };
+        *self.capture.borrow_mut() = Some(ScreenRecording {
+            output,
+            options,
+            width,
+            height,
+            frame: 0,
+            skipped: 0,
+            delay: 0.0,
+            failed: false,
+        });
+        Ok(())
+    }
+
+    /// Checks if the window is being recorded.
+    pub fn is_recording(&self) -> bool {
+        self.capture.borrow().is_some()
+    }
+
+    /// Stops recording the window, finishing the GIF file.
+    pub fn stop_recording(&mut self) -> Result<(), StopRecordingError> {
+        let recording = self
+            .capture
+            .borrow_mut()
+            .take()
+            .ok_or(StopRecordingError::NotRecording)?;
+        let failed = match recording.output {
+            Output::Gif(encoder) => recording.failed || encoder.finish().is_err(),
+            Output::PngSequence(_) => recording.failed,
+        };
+        if failed {
+            Err(StopRecordingError::WriteFailed)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Records the frame being drawn, called before swapping buffers.
+    pub(crate) fn record_frame(&self) {
+        if !self.is_recording() {
+            return;
+        }
+        let frame_time = self.frame_time();
+        let mut capture = self.capture.borrow_mut();
+        let recording = capture.as_mut().unwrap();
+        if recording.skipped > 0 || recording.failed {
+            recording.skipped = recording.skipped.saturating_sub(1);
+            return;
+        }
+        recording.skipped = recording.options.frame_skip;
+        let index = recording.frame;
+        recording.frame += 1;
+        let mut image = self.screenshot();
+        if image.width() != recording.width || image.height() != recording.height {
+            image.resize(recording.width, recording.height);
+        }
+        recording.failed = match &mut recording.output {
+            Output::Gif(encoder) => {
+                // Delays are in hundredths of a second, the remainder is carried over (not the
+                // excess of the 2 hundredths minimum, which would be owed forever at high rates)
+                recording.delay += frame_time * (recording.options.frame_skip + 1) as f32 * 100.0;
+                let delay = recording.delay.round().max(2.0);
+                recording.delay -= delay;
+                recording.delay = recording.delay.max(0.0);
+                match image.pixels() {
+                    Some(pixels) => encoder.write_frame(pixels, delay as u16).is_err(),
+                    None => true,
+                }
+            }
+            Output::PngSequence(path) => {
+                let mut name = path.file_stem().unwrap_or_default().to_os_string();
+                name.push(format!("{:04}.png", index));
+                image.export_png(path.with_file_name(name)).is_err()
+            }
+        };
+    }
+}
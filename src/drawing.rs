@@ -1,10 +1,21 @@
 //! Drawing utilities.
 
-use crate::{camera::Camera2D, color::Color, ffi, window::Window};
+use crate::{
+    camera::{Camera2D, Camera3D},
+    color::Color,
+    ffi,
+    shader::Shader,
+    texture::RenderTexture,
+    window::Window,
+};
 use std::marker::PhantomData;
 
 /// Container that holds various drawing elements.
-pub struct Canvas(PhantomData<*const ()>);
+pub struct Canvas {
+    // Shader of the innermost shader mode, restored after drawing SDF text
+    shader: Option<ffi::Shader>,
+    _marker: PhantomData<*const ()>,
+}
 
 impl Canvas {
     /// Sets background color.
@@ -24,6 +35,55 @@ impl Canvas {
             ffi::EndMode2D();
         }
     }
+
+    /// Draws in 3D mode with custom camera.
+    pub fn mode_3d<F>(&mut self, camera: Camera3D, function: F)
+    where
+        F: FnOnce(&mut Canvas),
+    {
+        unsafe {
+            ffi::BeginMode3D(camera.into());
+            function(self);
+            ffi::EndMode3D();
+        }
+    }
+
+    /// Draws with a custom shader.
+    pub fn shader_mode<F>(&mut self, shader: &Shader, function: F)
+    where
+        F: FnOnce(&mut Canvas),
+    {
+        let previous = self.shader.replace(shader.raw);
+        unsafe {
+            ffi::BeginShaderMode(shader.raw);
+            shader.bind_samplers();
+        }
+        function(self);
+        self.shader = previous;
+        self.restore_shader_mode();
+    }
+
+    /// Draws with the shader of the current shader mode, or with the default one.
+    pub(crate) fn restore_shader_mode(&mut self) {
+        unsafe {
+            match self.shader {
+                Some(shader) => ffi::BeginShaderMode(shader),
+                None => ffi::EndShaderMode(),
+            }
+        }
+    }
+
+    /// Draws into a render texture instead of the screen.
+    pub fn render_to<F>(&mut self, target: &mut RenderTexture, function: F)
+    where
+        F: FnOnce(&mut Canvas),
+    {
+        unsafe {
+            ffi::BeginTextureMode(target.raw);
+            function(self);
+            ffi::EndTextureMode();
+        }
+    }
 }
 
 /// Drawing.
@@ -35,8 +95,15 @@ impl Window {
     {
         unsafe {
             ffi::BeginDrawing();
-            function(&mut Canvas(PhantomData));
+            function(&mut Canvas {
+                shader: None,
+                _marker: PhantomData,
+            });
+        }
+        self.record_frame();
+        unsafe {
             ffi::EndDrawing();
         }
+        self.next_input_frame();
     }
 }
@@ -1,14 +1,38 @@
 //! The generic `Error` type.
 
-use crate::{text::LoadFontError, texture::LoadTextureError, window::CreateWindowError};
+use crate::{
+    asset::{OpenArchiveError, ReadAssetError},
+    audio::{CreateAudioDeviceError, LoadAudioError},
+    capture::{StartRecordingError, StopRecordingError},
+    image::{ExportImageError, LoadImageError},
+    model::LoadModelError,
+    recording::{LoadInputRecordingError, SaveInputRecordingError},
+    shader::LoadShaderError,
+    text::{ExportFontError, LoadFontError},
+    texture::LoadTextureError,
+    window::CreateWindowError,
+};
 use std::fmt::{Display, Formatter, Result};
 
 /// Generic error type.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Error {
+    CreateAudioDeviceError(CreateAudioDeviceError),
     CreateWindowError(CreateWindowError),
+    ExportFontError(ExportFontError),
+    ExportImageError(ExportImageError),
+    LoadAudioError(LoadAudioError),
     LoadFontError(LoadFontError),
+    LoadImageError(LoadImageError),
+    LoadInputRecordingError(LoadInputRecordingError),
+    LoadModelError(LoadModelError),
+    LoadShaderError(LoadShaderError),
     LoadTextureError(LoadTextureError),
+    OpenArchiveError(OpenArchiveError),
+    ReadAssetError(ReadAssetError),
+    SaveInputRecordingError(SaveInputRecordingError),
+    StartRecordingError(StartRecordingError),
+    StopRecordingError(StopRecordingError),
 }
 
 impl Display for Error {
@@ -36,3 +60,81 @@ impl From<LoadFontError> for Error {
         Error::LoadFontError(error)
     }
 }
+
+impl From<ExportFontError> for Error {
+    fn from(error: ExportFontError) -> Error {
+        Error::ExportFontError(error)
+    }
+}
+
+impl From<LoadImageError> for Error {
+    fn from(error: LoadImageError) -> Error {
+        Error::LoadImageError(error)
+    }
+}
+
+impl From<ExportImageError> for Error {
+    fn from(error: ExportImageError) -> Error {
+        Error::ExportImageError(error)
+    }
+}
+
+impl From<CreateAudioDeviceError> for Error {
+    fn from(error: CreateAudioDeviceError) -> Error {
+        Error::CreateAudioDeviceError(error)
+    }
+}
+
+impl From<LoadAudioError> for Error {
+    fn from(error: LoadAudioError) -> Error {
+        Error::LoadAudioError(error)
+    }
+}
+
+impl From<LoadShaderError> for Error {
+    fn from(error: LoadShaderError) -> Error {
+        Error::LoadShaderError(error)
+    }
+}
+
+impl From<LoadModelError> for Error {
+    fn from(error: LoadModelError) -> Error {
+        Error::LoadModelError(error)
+    }
+}
+
+impl From<LoadInputRecordingError> for Error {
+    fn from(error: LoadInputRecordingError) -> Error {
+        Error::LoadInputRecordingError(error)
+    }
+}
+
+impl From<SaveInputRecordingError> for Error {
+    fn from(error: SaveInputRecordingError) -> Error {
+        Error::SaveInputRecordingError(error)
+    }
+}
+
+impl From<StartRecordingError> for Error {
+    fn from(error: StartRecordingError) -> Error {
+        Error::StartRecordingError(error)
+    }
+}
+
+impl From<StopRecordingError> for Error {
+    fn from(error: StopRecordingError) -> Error {
+        Error::StopRecordingError(error)
+    }
+}
+
+impl From<OpenArchiveError> for Error {
+    fn from(error: OpenArchiveError) -> Error {
+        Error::OpenArchiveError(error)
+    }
+}
+
+impl From<ReadAssetError> for Error {
+    fn from(error: ReadAssetError) -> Error {
+        Error::ReadAssetError(error)
+    }
+}
@@ -1,8 +1,8 @@
 //! Utilities related to FFI bindings.
 
-#![allow(non_snake_case)]
+#![allow(non_camel_case_types, non_snake_case)]
 
-use std::os::raw::{c_char, c_double, c_float, c_int, c_uchar, c_uint, c_void};
+use std::os::raw::{c_char, c_double, c_float, c_int, c_uchar, c_uint, c_ushort, c_void};
 
 // Constants
 // Alphanumeric keys
@@ -145,7 +145,111 @@ pub const FLAG_WINDOW_ALWAYS_RUN: u32 = 256;
 pub const FLAG_MSAA_4X_HINT: u32 = 32;
 pub const FLAG_VSYNC_HINT: u32 = 64;
 
+// Pixel formats
+pub const UNCOMPRESSED_GRAYSCALE: i32 = 1;
+pub const UNCOMPRESSED_GRAY_ALPHA: i32 = 2;
+pub const UNCOMPRESSED_R5G6B5: i32 = 3;
+pub const UNCOMPRESSED_R8G8B8: i32 = 4;
+pub const UNCOMPRESSED_R5G5B5A1: i32 = 5;
+pub const UNCOMPRESSED_R4G4B4A4: i32 = 6;
+pub const UNCOMPRESSED_R8G8B8A8: i32 = 7;
+pub const UNCOMPRESSED_R32: i32 = 8;
+pub const UNCOMPRESSED_R32G32B32: i32 = 9;
+pub const UNCOMPRESSED_R32G32B32A32: i32 = 10;
+pub const COMPRESSED_DXT1_RGB: i32 = 11;
+pub const COMPRESSED_DXT1_RGBA: i32 = 12;
+pub const COMPRESSED_DXT3_RGBA: i32 = 13;
+pub const COMPRESSED_DXT5_RGBA: i32 = 14;
+pub const COMPRESSED_ETC1_RGB: i32 = 15;
+pub const COMPRESSED_ETC2_RGB: i32 = 16;
+pub const COMPRESSED_ETC2_EAC_RGBA: i32 = 17;
+pub const COMPRESSED_PVRT_RGB: i32 = 18;
+pub const COMPRESSED_PVRT_RGBA: i32 = 19;
+pub const COMPRESSED_ASTC_4X4_RGBA: i32 = 20;
+pub const COMPRESSED_ASTC_8X8_RGBA: i32 = 21;
+
+// Camera projection types
+pub const CAMERA_PERSPECTIVE: i32 = 0;
+pub const CAMERA_ORTHOGRAPHIC: i32 = 1;
+
+// Material map types
+pub const MAP_ALBEDO: i32 = 0;
+pub const MAP_METALNESS: i32 = 1;
+pub const MAP_NORMAL: i32 = 2;
+pub const MAP_ROUGHNESS: i32 = 3;
+pub const MAP_OCCLUSION: i32 = 4;
+pub const MAP_EMISSION: i32 = 5;
+pub const MAP_HEIGHT: i32 = 6;
+pub const MAP_CUBEMAP: i32 = 7;
+pub const MAP_IRRADIANCE: i32 = 8;
+pub const MAP_PREFILTER: i32 = 9;
+pub const MAP_BRDF: i32 = 10;
+
+// Maximum number of material maps
+pub const MAX_MATERIAL_MAPS: usize = 12;
+
+// N-patch layouts
+pub const NPT_9PATCH: i32 = 0;
+pub const NPT_3PATCH_VERTICAL: i32 = 1;
+pub const NPT_3PATCH_HORIZONTAL: i32 = 2;
+
+// Texture filter modes
+pub const FILTER_POINT: i32 = 0;
+pub const FILTER_BILINEAR: i32 = 1;
+pub const FILTER_TRILINEAR: i32 = 2;
+pub const FILTER_ANISOTROPIC_4X: i32 = 3;
+pub const FILTER_ANISOTROPIC_8X: i32 = 4;
+pub const FILTER_ANISOTROPIC_16X: i32 = 5;
+
+// Texture wrap modes
+pub const WRAP_REPEAT: i32 = 0;
+pub const WRAP_CLAMP: i32 = 1;
+pub const WRAP_MIRROR_REPEAT: i32 = 2;
+pub const WRAP_MIRROR_CLAMP: i32 = 3;
+
+// OpenGL
+pub const GL_TEXTURE_2D: c_uint = 0x0DE1;
+pub const GL_TEXTURE0: c_uint = 0x84C0;
+
+// Font types
+pub const FONT_DEFAULT: i32 = 0;
+pub const FONT_BITMAP: i32 = 1;
+pub const FONT_SDF: i32 = 2;
+
+// Shader uniform data types
+pub const UNIFORM_FLOAT: i32 = 0;
+pub const UNIFORM_VEC2: i32 = 1;
+pub const UNIFORM_VEC3: i32 = 2;
+pub const UNIFORM_VEC4: i32 = 3;
+pub const UNIFORM_INT: i32 = 4;
+pub const UNIFORM_IVEC2: i32 = 5;
+pub const UNIFORM_IVEC3: i32 = 6;
+pub const UNIFORM_IVEC4: i32 = 7;
+
 // Structures
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct AudioStream {
+    pub sampleRate: c_uint,
+    pub sampleSize: c_uint,
+    pub channels: c_uint,
+    pub buffer: *mut c_void,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct BoneInfo {
+    pub name: [c_char; 32],
+    pub parent: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct BoundingBox {
+    pub min: Vector3,
+    pub max: Vector3,
+}
+
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub struct Camera2D {
@@ -155,6 +259,16 @@ pub struct Camera2D {
     pub zoom: c_float,
 }
 
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Camera3D {
+    pub position: Vector3,
+    pub target: Vector3,
+    pub up: Vector3,
+    pub fovy: c_float,
+    pub type_: c_int,
+}
+
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct CharInfo {
@@ -194,6 +308,109 @@ pub struct Image {
     pub format: c_int,
 }
 
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct Material {
+    pub shader: Shader,
+    pub maps: *mut MaterialMap,
+    pub params: *mut c_float,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct MaterialMap {
+    pub texture: Texture2D,
+    pub color: Color,
+    pub value: c_float,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct Matrix {
+    pub m0: c_float,
+    pub m4: c_float,
+    pub m8: c_float,
+    pub m12: c_float,
+    pub m1: c_float,
+    pub m5: c_float,
+    pub m9: c_float,
+    pub m13: c_float,
+    pub m2: c_float,
+    pub m6: c_float,
+    pub m10: c_float,
+    pub m14: c_float,
+    pub m3: c_float,
+    pub m7: c_float,
+    pub m11: c_float,
+    pub m15: c_float,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct Mesh {
+    pub vertexCount: c_int,
+    pub triangleCount: c_int,
+    pub vertices: *mut c_float,
+    pub texcoords: *mut c_float,
+    pub texcoords2: *mut c_float,
+    pub normals: *mut c_float,
+    pub tangents: *mut c_float,
+    pub colors: *mut c_uchar,
+    pub indices: *mut c_ushort,
+    pub animVertices: *mut c_float,
+    pub animNormals: *mut c_float,
+    pub boneIds: *mut c_int,
+    pub boneWeights: *mut c_float,
+    pub vaoId: c_uint,
+    pub vboId: *mut c_uint,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct Model {
+    pub transform: Matrix,
+    pub meshCount: c_int,
+    pub meshes: *mut Mesh,
+    pub materialCount: c_int,
+    pub materials: *mut Material,
+    pub meshMaterial: *mut c_int,
+    pub boneCount: c_int,
+    pub bones: *mut BoneInfo,
+    pub bindPose: *mut Transform,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ModelAnimation {
+    pub boneCount: c_int,
+    pub bones: *mut BoneInfo,
+    pub frameCount: c_int,
+    pub framePoses: *mut *mut Transform,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct Music {
+    pub ctxType: c_int,
+    pub ctxData: *mut c_void,
+    pub sampleCount: c_uint,
+    pub loopCount: c_uint,
+    pub stream: AudioStream,
+}
+
+pub type Quaternion = Vector4;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct NPatchInfo {
+    pub sourceRec: Rectangle,
+    pub left: c_int,
+    pub top: c_int,
+    pub right: c_int,
+    pub bottom: c_int,
+    pub type_: c_int,
+}
+
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct Rectangle {
@@ -203,6 +420,29 @@ pub struct Rectangle {
     pub height: c_float,
 }
 
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct RenderTexture2D {
+    pub id: c_uint,
+    pub texture: Texture2D,
+    pub depth: Texture2D,
+    pub depthTexture: bool,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct Shader {
+    pub id: c_uint,
+    pub locs: *mut c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct Sound {
+    pub sampleCount: c_uint,
+    pub stream: AudioStream,
+}
+
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct Texture2D {
@@ -213,6 +453,14 @@ pub struct Texture2D {
     pub format: c_int,
 }
 
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct Transform {
+    pub translation: Vector3,
+    pub rotation: Quaternion,
+    pub scale: Vector3,
+}
+
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct Vector2 {
@@ -228,6 +476,59 @@ pub struct Vector3 {
     pub z: c_float,
 }
 
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct Vector4 {
+    pub x: c_float,
+    pub y: c_float,
+    pub z: c_float,
+    pub w: c_float,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct Wave {
+    pub sampleCount: c_uint,
+    pub sampleRate: c_uint,
+    pub sampleSize: c_uint,
+    pub channels: c_uint,
+    pub data: *mut c_void,
+}
+
+// stb_truetype
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct stbtt__buf {
+    pub data: *mut c_uchar,
+    pub cursor: c_int,
+    pub size: c_int,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct stbtt_fontinfo {
+    pub userdata: *mut c_void,
+    pub data: *mut c_uchar,
+    pub fontstart: c_int,
+    pub numGlyphs: c_int,
+    pub loca: c_int,
+    pub head: c_int,
+    pub glyf: c_int,
+    pub hhea: c_int,
+    pub hmtx: c_int,
+    pub kern: c_int,
+    pub gpos: c_int,
+    pub svg: c_int,
+    pub index_map: c_int,
+    pub indexToLocFormat: c_int,
+    pub cff: stbtt__buf,
+    pub charstrings: stbtt__buf,
+    pub gsubrs: stbtt__buf,
+    pub subrs: stbtt__buf,
+    pub fontdicts: stbtt__buf,
+    pub fdselect: stbtt__buf,
+}
+
 // Functions
 extern "C" {
     // Core
@@ -273,6 +574,10 @@ extern "C" {
     pub fn EndDrawing();
     pub fn BeginMode2D(camera: Camera2D);
     pub fn EndMode2D();
+    pub fn BeginMode3D(camera: Camera3D);
+    pub fn EndMode3D();
+    pub fn BeginTextureMode(target: RenderTexture2D);
+    pub fn EndTextureMode();
 
     // Timing-related functions
     pub fn SetTargetFPS(fps: c_int);
@@ -284,6 +589,11 @@ extern "C" {
     pub fn SetConfigFlags(flags: c_uint);
     pub fn GetRandomValue(min: c_int, max: c_int) -> c_int;
 
+    // Files management functions
+    pub fn IsFileDropped() -> bool;
+    pub fn GetDroppedFiles(count: *mut c_int) -> *mut *mut c_char;
+    pub fn ClearDroppedFiles();
+
     // Input-related functions: keyboard
     pub fn IsKeyPressed(key: c_int) -> bool;
     pub fn IsKeyDown(key: c_int) -> bool;
@@ -464,13 +774,119 @@ extern "C" {
     ) -> bool;
 
     // Textures
+    // Image loading functions
+    pub fn LoadImage(fileName: *const c_char) -> Image;
+    pub fn LoadImageEx(pixels: *mut Color, width: c_int, height: c_int) -> Image;
+    pub fn UnloadImage(image: Image);
+
+    // Image generation functions
+    pub fn GenImageColor(width: c_int, height: c_int, color: Color) -> Image;
+    pub fn GenImageGradientV(width: c_int, height: c_int, top: Color, bottom: Color) -> Image;
+    pub fn GenImageGradientH(width: c_int, height: c_int, left: Color, right: Color) -> Image;
+    pub fn GenImageGradientRadial(
+        width: c_int,
+        height: c_int,
+        density: c_float,
+        inner: Color,
+        outer: Color,
+    ) -> Image;
+    pub fn GenImageChecked(
+        width: c_int,
+        height: c_int,
+        checksX: c_int,
+        checksY: c_int,
+        col1: Color,
+        col2: Color,
+    ) -> Image;
+    pub fn GenImageWhiteNoise(width: c_int, height: c_int, factor: c_float) -> Image;
+    pub fn GenImagePerlinNoise(
+        width: c_int,
+        height: c_int,
+        offsetX: c_int,
+        offsetY: c_int,
+        scale: c_float,
+    ) -> Image;
+    pub fn GenImageCellular(width: c_int, height: c_int, tileSize: c_int) -> Image;
+
+    // Image manipulation functions
+    pub fn ImageCopy(image: Image) -> Image;
+    pub fn ImageFromImage(image: Image, rec: Rectangle) -> Image;
+    pub fn ImageFormat(image: *mut Image, newFormat: c_int);
+    pub fn ImageAlphaPremultiply(image: *mut Image);
+    pub fn ImageCrop(image: *mut Image, crop: Rectangle);
+    pub fn ImageResize(image: *mut Image, newWidth: c_int, newHeight: c_int);
+    pub fn ImageResizeNN(image: *mut Image, newWidth: c_int, newHeight: c_int);
+    pub fn ImageResizeCanvas(
+        image: *mut Image,
+        newWidth: c_int,
+        newHeight: c_int,
+        offsetX: c_int,
+        offsetY: c_int,
+        color: Color,
+    );
+    pub fn ImageFlipVertical(image: *mut Image);
+    pub fn ImageFlipHorizontal(image: *mut Image);
+    pub fn ImageRotateCW(image: *mut Image);
+    pub fn ImageRotateCCW(image: *mut Image);
+    pub fn ImageColorTint(image: *mut Image, color: Color);
+    pub fn ImageColorInvert(image: *mut Image);
+    pub fn ImageColorGrayscale(image: *mut Image);
+    pub fn ImageColorContrast(image: *mut Image, contrast: c_float);
+    pub fn ImageColorBrightness(image: *mut Image, brightness: c_int);
+    pub fn ImageColorReplace(image: *mut Image, color: Color, replace: Color);
+
     // Texture loading function
     pub fn LoadTexture(fileName: *const c_char) -> Texture2D;
+    pub fn LoadTextureFromImage(image: Image) -> Texture2D;
+    pub fn LoadRenderTexture(width: c_int, height: c_int) -> RenderTexture2D;
     pub fn UnloadTexture(texture: Texture2D);
+    pub fn UnloadRenderTexture(target: RenderTexture2D);
+    pub fn GetTextureData(texture: Texture2D) -> Image;
+    pub fn GetScreenData() -> Image;
+    pub fn UpdateTexture(texture: Texture2D, pixels: *const c_void);
+
+    // Texture configuration functions
+    pub fn GenTextureMipmaps(texture: *mut Texture2D);
+    pub fn SetTextureFilter(texture: Texture2D, filterMode: c_int);
+    pub fn SetTextureWrap(texture: Texture2D, wrapMode: c_int);
 
     // Texture drawing functions
     pub fn DrawTexture(texture: Texture2D, posX: c_int, posY: c_int, tint: Color);
+    pub fn DrawTextureV(texture: Texture2D, position: Vector2, tint: Color);
+    pub fn DrawTextureEx(
+        texture: Texture2D,
+        position: Vector2,
+        rotation: c_float,
+        scale: c_float,
+        tint: Color,
+    );
     pub fn DrawTextureRec(texture: Texture2D, sourceRec: Rectangle, position: Vector2, tint: Color);
+    pub fn DrawTextureQuad(
+        texture: Texture2D,
+        tiling: Vector2,
+        offset: Vector2,
+        quad: Rectangle,
+        tint: Color,
+    );
+    pub fn DrawTexturePro(
+        texture: Texture2D,
+        sourceRec: Rectangle,
+        destRec: Rectangle,
+        origin: Vector2,
+        rotation: c_float,
+        tint: Color,
+    );
+    pub fn DrawTextureNPatch(
+        texture: Texture2D,
+        nPatchInfo: NPatchInfo,
+        destRec: Rectangle,
+        origin: Vector2,
+        rotation: c_float,
+        tint: Color,
+    );
+
+    // Image/Texture misc functions
+    pub fn GetPixelDataSize(width: c_int, height: c_int, format: c_int) -> c_int;
 
     // Text
     // Font loading/unloading functions
@@ -481,6 +897,15 @@ extern "C" {
         fontChars: *mut c_int,
         charsCount: c_int,
     ) -> Font;
+    pub fn LoadFontFromImage(image: Image, key: Color, firstChar: c_int) -> Font;
+    pub fn GenImageFontAtlas(
+        chars: *const CharInfo,
+        recs: *mut *mut Rectangle,
+        charsCount: c_int,
+        fontSize: c_int,
+        padding: c_int,
+        packMethod: c_int,
+    ) -> Image;
     pub fn UnloadFont(font: Font);
 
     // Text drawing functions
@@ -512,4 +937,334 @@ extern "C" {
         fontSize: c_float,
         spacing: c_float,
     ) -> Vector2;
+
+    // Models
+    // Basic geometric 3D shapes drawing functions
+    pub fn DrawLine3D(startPos: Vector3, endPos: Vector3, color: Color);
+    pub fn DrawPoint3D(position: Vector3, color: Color);
+    pub fn DrawCircle3D(
+        center: Vector3,
+        radius: c_float,
+        rotationAxis: Vector3,
+        rotationAngle: c_float,
+        color: Color,
+    );
+    pub fn DrawCube(
+        position: Vector3,
+        width: c_float,
+        height: c_float,
+        length: c_float,
+        color: Color,
+    );
+    pub fn DrawCubeV(position: Vector3, size: Vector3, color: Color);
+    pub fn DrawCubeWires(
+        position: Vector3,
+        width: c_float,
+        height: c_float,
+        length: c_float,
+        color: Color,
+    );
+    pub fn DrawCubeWiresV(position: Vector3, size: Vector3, color: Color);
+    pub fn DrawCubeTexture(
+        texture: Texture2D,
+        position: Vector3,
+        width: c_float,
+        height: c_float,
+        length: c_float,
+        color: Color,
+    );
+    pub fn DrawSphere(centerPos: Vector3, radius: c_float, color: Color);
+    pub fn DrawSphereEx(
+        centerPos: Vector3,
+        radius: c_float,
+        rings: c_int,
+        slices: c_int,
+        color: Color,
+    );
+    pub fn DrawSphereWires(
+        centerPos: Vector3,
+        radius: c_float,
+        rings: c_int,
+        slices: c_int,
+        color: Color,
+    );
+    pub fn DrawCylinder(
+        position: Vector3,
+        radiusTop: c_float,
+        radiusBottom: c_float,
+        height: c_float,
+        slices: c_int,
+        color: Color,
+    );
+    pub fn DrawCylinderWires(
+        position: Vector3,
+        radiusTop: c_float,
+        radiusBottom: c_float,
+        height: c_float,
+        slices: c_int,
+        color: Color,
+    );
+    pub fn DrawPlane(centerPos: Vector3, size: Vector2, color: Color);
+    pub fn DrawGrid(slices: c_int, spacing: c_float);
+    pub fn DrawGizmo(position: Vector3);
+    pub fn DrawBoundingBox(box_: BoundingBox, color: Color);
+    pub fn DrawBillboard(
+        camera: Camera3D,
+        texture: Texture2D,
+        center: Vector3,
+        size: c_float,
+        tint: Color,
+    );
+    pub fn DrawBillboardRec(
+        camera: Camera3D,
+        texture: Texture2D,
+        sourceRec: Rectangle,
+        center: Vector3,
+        size: c_float,
+        tint: Color,
+    );
+
+    // Model loading/unloading functions
+    pub fn LoadModelFromMesh(mesh: Mesh) -> Model;
+    pub fn UnloadModel(model: Model);
+
+    // Mesh loading/unloading functions
+    pub fn UnloadMesh(mesh: Mesh);
+
+    // Material loading/unloading functions
+    pub fn SetModelMeshMaterial(model: *mut Model, meshId: c_int, materialId: c_int);
+
+    // Model animations loading/unloading functions
+    pub fn LoadModelAnimations(
+        fileName: *const c_char,
+        animsCount: *mut c_int,
+    ) -> *mut ModelAnimation;
+    pub fn UpdateModelAnimation(model: Model, anim: ModelAnimation, frame: c_int);
+    pub fn UnloadModelAnimation(anim: ModelAnimation);
+    pub fn IsModelAnimationValid(model: Model, anim: ModelAnimation) -> bool;
+    // Patched (external/patches/models_load_model_checked.c)
+    pub fn LoadModelChecked(fileName: *const c_char) -> Model;
+    // Patched (external/patches/models_gltf_animations.c)
+    #[cfg(feature = "gltf")]
+    pub fn LoadGLTFModelSkeleton(fileName: *const c_char, model: *mut Model) -> bool;
+    #[cfg(feature = "gltf")]
+    pub fn LoadGLTFModelAnimations(
+        fileName: *const c_char,
+        animCount: *mut c_int,
+    ) -> *mut ModelAnimation;
+
+    // Mesh generation functions
+    pub fn GenMeshPoly(sides: c_int, radius: c_float) -> Mesh;
+    pub fn GenMeshPlane(width: c_float, length: c_float, resX: c_int, resZ: c_int) -> Mesh;
+    pub fn GenMeshCube(width: c_float, height: c_float, length: c_float) -> Mesh;
+    pub fn GenMeshSphere(radius: c_float, rings: c_int, slices: c_int) -> Mesh;
+    pub fn GenMeshHemiSphere(radius: c_float, rings: c_int, slices: c_int) -> Mesh;
+    pub fn GenMeshCylinder(radius: c_float, height: c_float, slices: c_int) -> Mesh;
+    pub fn GenMeshTorus(radius: c_float, size: c_float, radSeg: c_int, sides: c_int) -> Mesh;
+    pub fn GenMeshKnot(radius: c_float, size: c_float, radSeg: c_int, sides: c_int) -> Mesh;
+    pub fn GenMeshHeightmap(heightmap: Image, size: Vector3) -> Mesh;
+    pub fn GenMeshCubicmap(cubicmap: Image, cubeSize: Vector3) -> Mesh;
+
+    // Mesh manipulation functions
+    pub fn MeshBoundingBox(mesh: Mesh) -> BoundingBox;
+    pub fn MeshTangents(mesh: *mut Mesh);
+    pub fn MeshBinormals(mesh: *mut Mesh);
+
+    // Model drawing functions
+    pub fn DrawModel(model: Model, position: Vector3, scale: c_float, tint: Color);
+    pub fn DrawModelEx(
+        model: Model,
+        position: Vector3,
+        rotationAxis: Vector3,
+        rotationAngle: c_float,
+        scale: Vector3,
+        tint: Color,
+    );
+    pub fn DrawModelWires(model: Model, position: Vector3, scale: c_float, tint: Color);
+    pub fn DrawModelWiresEx(
+        model: Model,
+        position: Vector3,
+        rotationAxis: Vector3,
+        rotationAngle: c_float,
+        scale: Vector3,
+        tint: Color,
+    );
+
+    // Shaders
+    // Shader loading/unloading functions
+    pub fn LoadShaderCode(vsCode: *const c_char, fsCode: *const c_char) -> Shader;
+    pub fn UnloadShader(shader: Shader);
+    pub fn GetShaderDefault() -> Shader;
+    pub fn GetTextureDefault() -> Texture2D;
+
+    // Shader configuration functions
+    pub fn GetShaderLocation(shader: Shader, uniformName: *const c_char) -> c_int;
+    pub fn SetShaderValue(
+        shader: Shader,
+        uniformLoc: c_int,
+        value: *const c_void,
+        uniformType: c_int,
+    );
+    pub fn SetShaderValueMatrix(shader: Shader, uniformLoc: c_int, mat: Matrix);
+
+    // Shading begin/end functions
+    pub fn BeginShaderMode(shader: Shader);
+    pub fn EndShaderMode();
+
+    // Audio
+    // Audio device management functions
+    pub fn InitAudioDevice();
+    pub fn CloseAudioDevice();
+    pub fn IsAudioDeviceReady() -> bool;
+    pub fn SetMasterVolume(volume: c_float);
+
+    // Wave/Sound loading/unloading functions
+    pub fn LoadWave(fileName: *const c_char) -> Wave;
+    pub fn LoadSound(fileName: *const c_char) -> Sound;
+    pub fn LoadSoundFromWave(wave: Wave) -> Sound;
+    pub fn UnloadWave(wave: Wave);
+    pub fn UnloadSound(sound: Sound);
+
+    // Wave/Sound management functions
+    pub fn PlaySound(sound: Sound);
+    pub fn StopSound(sound: Sound);
+    pub fn PauseSound(sound: Sound);
+    pub fn ResumeSound(sound: Sound);
+    pub fn PlaySoundMulti(sound: Sound);
+    pub fn StopSoundMulti();
+    pub fn IsSoundPlaying(sound: Sound) -> bool;
+    pub fn SetSoundVolume(sound: Sound, volume: c_float);
+    pub fn SetSoundPitch(sound: Sound, pitch: c_float);
+    pub fn WaveFormat(wave: *mut Wave, sampleRate: c_int, sampleSize: c_int, channels: c_int);
+    pub fn WaveCopy(wave: Wave) -> Wave;
+    pub fn WaveCrop(wave: *mut Wave, initSample: c_int, finalSample: c_int);
+
+    // Music management functions
+    pub fn LoadMusicStream(fileName: *const c_char) -> Music;
+    pub fn UnloadMusicStream(music: Music);
+    pub fn PlayMusicStream(music: Music);
+    pub fn UpdateMusicStream(music: Music);
+    pub fn StopMusicStream(music: Music);
+    pub fn PauseMusicStream(music: Music);
+    pub fn ResumeMusicStream(music: Music);
+    pub fn IsMusicPlaying(music: Music) -> bool;
+    pub fn SetMusicVolume(music: Music, volume: c_float);
+    pub fn SetMusicPitch(music: Music, pitch: c_float);
+    pub fn SetMusicLoopCount(music: Music, count: c_int);
+    pub fn GetMusicTimeLength(music: Music) -> c_float;
+    pub fn GetMusicTimePlayed(music: Music) -> c_float;
+    // Patched (external/patches/raudio_seek_music_stream.c)
+    pub fn SeekMusicStream(music: Music, position: c_float);
+
+    // AudioStream management functions
+    pub fn InitAudioStream(sampleRate: c_uint, sampleSize: c_uint, channels: c_uint)
+        -> AudioStream;
+    pub fn UpdateAudioStream(stream: AudioStream, data: *const c_void, samplesCount: c_int);
+    pub fn CloseAudioStream(stream: AudioStream);
+    pub fn IsAudioStreamProcessed(stream: AudioStream) -> bool;
+    pub fn PlayAudioStream(stream: AudioStream);
+    pub fn PauseAudioStream(stream: AudioStream);
+    pub fn ResumeAudioStream(stream: AudioStream);
+    pub fn IsAudioStreamPlaying(stream: AudioStream) -> bool;
+    pub fn StopAudioStream(stream: AudioStream);
+    pub fn SetAudioStreamVolume(stream: AudioStream, volume: c_float);
+    pub fn SetAudioStreamPitch(stream: AudioStream, pitch: c_float);
+
+    // External
+    // rlgl
+    pub fn rlglDraw();
+    pub fn rlGetGlTextureFormats(
+        format: c_int,
+        glInternalFormat: *mut c_uint,
+        glFormat: *mut c_uint,
+        glType: *mut c_uint,
+    );
+
+    // glad
+    pub static mut glad_glActiveTexture: Option<unsafe extern "C" fn(texture: c_uint)>;
+    pub static mut glad_glBindTexture:
+        Option<unsafe extern "C" fn(target: c_uint, texture: c_uint)>;
+    pub static mut glad_glTexSubImage2D: Option<
+        unsafe extern "C" fn(
+            target: c_uint,
+            level: c_int,
+            xoffset: c_int,
+            yoffset: c_int,
+            width: c_int,
+            height: c_int,
+            format: c_uint,
+            type_: c_uint,
+            pixels: *const c_void,
+        ),
+    >;
+
+    // libc
+    pub fn malloc(size: usize) -> *mut c_void;
+    pub fn free(ptr: *mut c_void);
+
+    // stb_image
+    pub fn stbi_load_from_memory(
+        buffer: *const c_uchar,
+        len: c_int,
+        x: *mut c_int,
+        y: *mut c_int,
+        channels_in_file: *mut c_int,
+        desired_channels: c_int,
+    ) -> *mut c_uchar;
+    pub fn stbi_zlib_decode_noheader_buffer(
+        obuffer: *mut c_char,
+        olen: c_int,
+        ibuffer: *const c_char,
+        ilen: c_int,
+    ) -> c_int;
+
+    // stb_truetype
+    #[cfg(test)]
+    pub static rayquaza_stbtt_fontinfo_size: usize;
+    pub fn stbtt_InitFont(info: *mut stbtt_fontinfo, data: *const c_uchar, offset: c_int) -> c_int;
+    pub fn stbtt_ScaleForPixelHeight(info: *const stbtt_fontinfo, pixels: c_float) -> c_float;
+    pub fn stbtt_GetFontVMetrics(
+        info: *const stbtt_fontinfo,
+        ascent: *mut c_int,
+        descent: *mut c_int,
+        lineGap: *mut c_int,
+    );
+    pub fn stbtt_GetCodepointHMetrics(
+        info: *const stbtt_fontinfo,
+        codepoint: c_int,
+        advanceWidth: *mut c_int,
+        leftSideBearing: *mut c_int,
+    );
+    pub fn stbtt_GetCodepointBitmap(
+        info: *const stbtt_fontinfo,
+        scale_x: c_float,
+        scale_y: c_float,
+        codepoint: c_int,
+        width: *mut c_int,
+        height: *mut c_int,
+        xoff: *mut c_int,
+        yoff: *mut c_int,
+    ) -> *mut c_uchar;
+    pub fn stbtt_GetCodepointSDF(
+        info: *const stbtt_fontinfo,
+        scale: c_float,
+        codepoint: c_int,
+        padding: c_int,
+        onedge_value: c_uchar,
+        pixel_dist_scale: c_float,
+        width: *mut c_int,
+        height: *mut c_int,
+        xoff: *mut c_int,
+        yoff: *mut c_int,
+    ) -> *mut c_uchar;
+
+    // stb_image_write
+    pub fn stbi_write_png(
+        filename: *const c_char,
+        w: c_int,
+        h: c_int,
+        comp: c_int,
+        data: *const c_void,
+        stride_in_bytes: c_int,
+    ) -> c_int;
 }
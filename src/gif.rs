@@ -0,0 +1,289 @@
+//! Animated GIF encoding.
+
+use crate::color::Color;
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+};
+
+/// Minimum LZW code size, for 256 colors palettes.
+const MIN_CODE_SIZE: u8 = 8;
+
+/// LZW clear code.
+const CLEAR_CODE: u16 = 1 << MIN_CODE_SIZE;
+
+/// LZW end of information code.
+const END_CODE: u16 = CLEAR_CODE + 1;
+
+/// Maximum number of LZW codes.
+const MAX_CODES: u16 = 4096;
+
+/// Writer of animated GIF files, looping forever.
+pub(crate) struct GifEncoder<W: Write> {
+    writer: W,
+    width: u16,
+    height: u16,
+}
+
+impl<W: Write> GifEncoder<W> {
+    /// Writes the GIF header.
+    pub(crate) fn new(mut writer: W, width: u16, height: u16) -> io::Result<GifEncoder<W>> {
+        writer.write_all(b"GIF89a")?;
+        writer.write_all(&width.to_le_bytes())?;
+        writer.write_all(&height.to_le_bytes())?;
+        // No global color table, background color and pixel aspect ratio
+        writer.write_all(&[0, 0, 0])?;
+        // Netscape application extension (infinite loop)
+        writer.write_all(&[0x21, 0xFF, 0x0B])?;
+        writer.write_all(b"NETSCAPE2.0")?;
+        writer.write_all(&[0x03, 0x01, 0x00, 0x00, 0x00])?;
+        Ok(GifEncoder {
+            writer,
+            width,
+            height,
+        })
+    }
+
+    /// Writes a frame displayed for `delay` hundredths of a second.
+    ///
+    /// Frames with more than 256 colors are quantized to a fixed palette.
+    pub(crate) fn write_frame(&mut self, pixels: &[Color], delay: u16) -> io::Result<()> {
+        assert_eq!(pixels.len(), self.width as usize * self.height as usize);
+        let (palette, indices) = quantize(pixels);
+        // Graphic control extension
+        self.writer.write_all(&[0x21, 0xF9, 0x04, 0x00])?;
+        self.writer.write_all(&delay.to_le_bytes())?;
+        self.writer.write_all(&[0x00, 0x00])?;
+        // Image descriptor, with a local color table of 256 colors
+        self.writer.write_all(&[0x2C, 0x00, 0x00, 0x00, 0x00])?;
+        self.writer.write_all(&self.width.to_le_bytes())?;
+        self.writer.write_all(&self.height.to_le_bytes())?;
+        self.writer.write_all(&[0x87])?;
+        for index in 0..256 {
+            let color = palette.get(index).copied().unwrap_or_default();
+            self.writer
+                .write_all(&[color.red, color.green, color.blue])?;
+        }
+        // Image data, in sub-blocks of 255 bytes at most
+        self.writer.write_all(&[MIN_CODE_SIZE])?;
+        for block in compress(&indices).chunks(255) {
+            self.writer.write_all(&[block.len() as u8])?;
+            self.writer.write_all(block)?;
+        }
+        self.writer.write_all(&[0x00])
+    }
+
+    /// Writes the GIF trailer.
+    pub(crate) fn finish(mut self) -> io::Result<()> {
+        self.writer.write_all(&[0x3B])?;
+        self.writer.flush()
+    }
+}
+
+/// Returns the palette and the palette indices of pixels.
+fn quantize(pixels: &[Color]) -> (Vec<Color>, Vec<u8>) {
+    let mut palette = Vec::new();
+    let mut lookup = HashMap::new();
+    let mut indices = Vec::with_capacity(pixels.len());
+    for pixel in pixels {
+        let key = (pixel.red, pixel.green, pixel.blue);
+        let index = match lookup.get(&key) {
+            Some(&index) => index,
+            None if palette.len() < 256 => {
+                let index = palette.len() as u8;
+                palette.push(Color::new(pixel.red, pixel.green, pixel.blue, 255));
+                lookup.insert(key, index);
+                index
+            }
+            // Too many colors, falls back to 6 levels of red and blue, 7 levels of green
+            None => return quantize_fixed(pixels),
+        };
+        indices.push(index);
+    }
+    (palette, indices)
+}
+
+/// Returns the fixed palette and the palette indices of pixels.
+fn quantize_fixed(pixels: &[Color]) -> (Vec<Color>, Vec<u8>) {
+    let level = |value: u8, levels: u16| (value as u16 * (levels - 1) + 127) / 255;
+    let value = |level: u16, levels: u16| (level * 255 / (levels - 1)) as u8;
+    let mut palette = Vec::with_capacity(252);
+    for red in 0..6 {
+        for green in 0..7 {
+            for blue in 0..6 {
+                palette.push(Color::new(
+                    value(red, 6),
+                    value(green, 7),
+                    value(blue, 6),
+                    255,
+                ));
+            }
+        }
+    }
+    let indices = pixels
+        .iter()
+        .map(|pixel| {
+            (level(pixel.red, 6) * 42 + level(pixel.green, 7) * 6 + level(pixel.blue, 6)) as u8
+        })
+        .collect();
+    (palette, indices)
+}
+
+/// Writer of variable-length codes, least significant bit first.
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    length: u8,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, size: u8) {
+        self.buffer |= (code as u32) << self.length;
+        self.length += size;
+        while self.length >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.length -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.length > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+/// Compresses palette indices (LZW).
+fn compress(indices: &[u8]) -> Vec<u8> {
+    let mut writer = BitWriter {
+        bytes: Vec::new(),
+        buffer: 0,
+        length: 0,
+    };
+    let mut codes = HashMap::new();
+    let mut next_code = END_CODE + 1;
+    let mut code_size = MIN_CODE_SIZE + 1;
+    writer.write(CLEAR_CODE, code_size);
+    let mut indices = indices.iter();
+    if let Some(&first) = indices.next() {
+        let mut prefix = first as u16;
+        for &index in indices {
+            let key = (prefix, index);
+            if let Some(&code) = codes.get(&key) {
+                prefix = code;
+                continue;
+            }
+            writer.write(prefix, code_size);
+            if next_code < MAX_CODES {
+                codes.insert(key, next_code);
+                next_code += 1;
+                if next_code > 1 << code_size && code_size < 12 {
+                    code_size += 1;
+                }
+            } else {
+                writer.write(CLEAR_CODE, code_size);
+                codes.clear();
+                next_code = END_CODE + 1;
+                code_size = MIN_CODE_SIZE + 1;
+            }
+            prefix = index as u16;
+        }
+        writer.write(prefix, code_size);
+    }
+    writer.write(END_CODE, code_size);
+    writer.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        color::Color,
+        gif::{compress, quantize, CLEAR_CODE, END_CODE},
+    };
+    use std::collections::HashMap;
+
+    /// Decompresses LZW data, as a GIF decoder does.
+    fn decompress(bytes: &[u8]) -> Vec<u8> {
+        let mut output = Vec::new();
+        let mut table: HashMap<u16, Vec<u8>> = HashMap::new();
+        let mut next_code = END_CODE + 1;
+        let mut code_size = 9;
+        let mut previous: Option<Vec<u8>> = None;
+        let (mut buffer, mut length, mut position) = (0u32, 0, 0);
+        loop {
+            while length < code_size {
+                buffer |= (bytes[position] as u32) << length;
+                position += 1;
+                length += 8;
+            }
+            let code = (buffer & ((1 << code_size) - 1)) as u16;
+            buffer >>= code_size;
+            length -= code_size;
+            if code == CLEAR_CODE {
+                table.clear();
+                next_code = END_CODE + 1;
+                code_size = 9;
+                previous = None;
+                continue;
+            } else if code == END_CODE {
+                return output;
+            }
+            let entry = match (code < CLEAR_CODE, table.get(&code), &previous) {
+                (true, _, _) => vec![code as u8],
+                (false, Some(entry), _) => entry.clone(),
+                (false, None, Some(previous)) => {
+                    let mut entry = previous.clone();
+                    entry.push(previous[0]);
+                    entry
+                }
+                _ => panic!("invalid code"),
+            };
+            if let Some(mut previous) = previous {
+                if next_code < 4096 {
+                    previous.push(entry[0]);
+                    table.insert(next_code, previous);
+                    next_code += 1;
+                    if next_code == 1 << code_size && code_size < 12 {
+                        code_size += 1;
+                    }
+                }
+            }
+            output.extend_from_slice(&entry);
+            previous = Some(entry);
+        }
+    }
+
+    #[test]
+    fn test_compress() {
+        let mut state = 1u32;
+        let indices: Vec<u8> = (0..100_000)
+            .map(|index| {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                if index % 7 == 0 {
+                    (state >> 16) as u8
+                } else {
+                    (index / 1000) as u8
+                }
+            })
+            .collect();
+        assert_eq!(decompress(&compress(&indices)), indices);
+        assert_eq!(decompress(&compress(&[])), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn test_quantize() {
+        let pixels = [Color::RED, Color::BLUE, Color::RED];
+        let (palette, indices) = quantize(&pixels);
+        assert_eq!(palette, vec![Color::RED, Color::BLUE]);
+        assert_eq!(indices, vec![0, 1, 0]);
+        let pixels: Vec<Color> = (0..=255)
+            .flat_map(|red| vec![Color::new(red, 0, 0, 255), Color::new(red, 255, 255, 255)])
+            .collect();
+        let (palette, indices) = quantize(&pixels);
+        assert_eq!(palette.len(), 252);
+        assert_eq!(palette[indices[0] as usize], Color::BLACK);
+        assert_eq!(palette[indices[511] as usize], Color::WHITE);
+    }
+}
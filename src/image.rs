@@ -0,0 +1,661 @@
+//! Image management.
+
+use crate::{
+    asset::{self, ExtractedAsset},
+    color::Color,
+    ffi,
+    math::Rectangle,
+    window::Window,
+};
+use std::{ffi::CString, fmt, os::raw::c_int, path::Path, slice};
+
+/// Kinds of image loading errors.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LoadImageError {
+    ReadFailed,
+}
+
+impl fmt::Display for LoadImageError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{:?}", self)
+    }
+}
+
+impl std::error::Error for LoadImageError {}
+
+/// Returns a file type (e.g. `png` or `.PNG`) without dot, in lowercase.
+pub(crate) fn normalize_file_type(file_type: &str) -> String {
+    file_type.trim_start_matches('.').to_lowercase()
+}
+
+/// Returns whether images of a normalized file type are decoded by [`Image::from_memory`].
+pub(crate) fn is_decodable(file_type: &str) -> bool {
+    matches!(
+        file_type,
+        "png" | "bmp" | "tga" | "jpg" | "jpeg" | "gif" | "psd" | "hdr"
+    )
+}
+
+/// Kinds of image exporting errors.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExportImageError {
+    UnsupportedFormat,
+    WriteFailed,
+}
+
+impl fmt::Display for ExportImageError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{:?}", self)
+    }
+}
+
+impl std::error::Error for ExportImageError {}
+
+/// Kinds of pixel formats.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PixelFormat {
+    // Uncompressed formats
+    Grayscale = ffi::UNCOMPRESSED_GRAYSCALE,
+    GrayAlpha = ffi::UNCOMPRESSED_GRAY_ALPHA,
+    R5G6B5 = ffi::UNCOMPRESSED_R5G6B5,
+    R8G8B8 = ffi::UNCOMPRESSED_R8G8B8,
+    R5G5B5A1 = ffi::UNCOMPRESSED_R5G5B5A1,
+    R4G4B4A4 = ffi::UNCOMPRESSED_R4G4B4A4,
+    R8G8B8A8 = ffi::UNCOMPRESSED_R8G8B8A8,
+    R32 = ffi::UNCOMPRESSED_R32,
+    R32G32B32 = ffi::UNCOMPRESSED_R32G32B32,
+    R32G32B32A32 = ffi::UNCOMPRESSED_R32G32B32A32,
+
+    // Compressed formats
+    Dxt1Rgb = ffi::COMPRESSED_DXT1_RGB,
+    Dxt1Rgba = ffi::COMPRESSED_DXT1_RGBA,
+    Dxt3Rgba = ffi::COMPRESSED_DXT3_RGBA,
+    Dxt5Rgba = ffi::COMPRESSED_DXT5_RGBA,
+    Etc1Rgb = ffi::COMPRESSED_ETC1_RGB,
+    Etc2Rgb = ffi::COMPRESSED_ETC2_RGB,
+    Etc2EacRgba = ffi::COMPRESSED_ETC2_EAC_RGBA,
+    PvrtRgb = ffi::COMPRESSED_PVRT_RGB,
+    PvrtRgba = ffi::COMPRESSED_PVRT_RGBA,
+    Astc4x4Rgba = ffi::COMPRESSED_ASTC_4X4_RGBA,
+    Astc8x8Rgba = ffi::COMPRESSED_ASTC_8X8_RGBA,
+}
+
+impl PixelFormat {
+    /// Converts a raw pixel format, `None` if unknown (e.g. for images that failed to load).
+    pub(crate) fn from_raw(format: c_int) -> Option<PixelFormat> {
+        let format = match format {
+            ffi::UNCOMPRESSED_GRAYSCALE => PixelFormat::Grayscale,
+            ffi::UNCOMPRESSED_GRAY_ALPHA => PixelFormat::GrayAlpha,
+            ffi::UNCOMPRESSED_R5G6B5 => PixelFormat::R5G6B5,
+            ffi::UNCOMPRESSED_R8G8B8 => PixelFormat::R8G8B8,
+            ffi::UNCOMPRESSED_R5G5B5A1 => PixelFormat::R5G5B5A1,
+            ffi::UNCOMPRESSED_R4G4B4A4 => PixelFormat::R4G4B4A4,
+            ffi::UNCOMPRESSED_R8G8B8A8 => PixelFormat::R8G8B8A8,
+            ffi::UNCOMPRESSED_R32 => PixelFormat::R32,
+            ffi::UNCOMPRESSED_R32G32B32 => PixelFormat::R32G32B32,
+            ffi::UNCOMPRESSED_R32G32B32A32 => PixelFormat::R32G32B32A32,
+            ffi::COMPRESSED_DXT1_RGB => PixelFormat::Dxt1Rgb,
+            ffi::COMPRESSED_DXT1_RGBA => PixelFormat::Dxt1Rgba,
+            ffi::COMPRESSED_DXT3_RGBA => PixelFormat::Dxt3Rgba,
+            ffi::COMPRESSED_DXT5_RGBA => PixelFormat::Dxt5Rgba,
+            ffi::COMPRESSED_ETC1_RGB => PixelFormat::Etc1Rgb,
+            ffi::COMPRESSED_ETC2_RGB => PixelFormat::Etc2Rgb,
+            ffi::COMPRESSED_ETC2_EAC_RGBA => PixelFormat::Etc2EacRgba,
+            ffi::COMPRESSED_PVRT_RGB => PixelFormat::PvrtRgb,
+            ffi::COMPRESSED_PVRT_RGBA => PixelFormat::PvrtRgba,
+            ffi::COMPRESSED_ASTC_4X4_RGBA => PixelFormat::Astc4x4Rgba,
+            ffi::COMPRESSED_ASTC_8X8_RGBA => PixelFormat::Astc8x8Rgba,
+            _ => return None,
+        };
+        Some(format)
+    }
+
+    /// Checks if the format is a compressed one.
+    #[inline]
+    pub fn is_compressed(self) -> bool {
+        self as i32 >= ffi::COMPRESSED_DXT1_RGB
+    }
+}
+
+/// Image stored in CPU memory (RAM).
+pub struct Image {
+    pub(crate) raw: ffi::Image,
+}
+
+impl Image {
+    /// Creates an image filled with a color.
+    pub fn new(width: i32, height: i32, color: impl Into<Color>) -> Image {
+        unsafe { Image::from_raw(ffi::GenImageColor(width, height, color.into().into())) }
+    }
+
+    /// Creates an image from RGBA pixels, row by row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pixels` does not contain exactly `width * height` colors.
+    pub fn from_pixels(width: i32, height: i32, pixels: &[Color]) -> Image {
+        assert_eq!(pixels.len(), (width * height) as usize);
+        unsafe {
+            Image::from_raw(ffi::LoadImageEx(
+                pixels.as_ptr() as *mut ffi::Color,
+                width,
+                height,
+            ))
+        }
+    }
+
+    /// Loads image from file into CPU memory (RAM).
+    ///
+    /// See `Window::load_image` to resolve the path through the mounted asset sources.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Image, LoadImageError> {
+        unsafe {
+            let path = CString::new(path.as_ref().display().to_string()).unwrap();
+            let raw = ffi::LoadImage(path.as_ptr());
+            if !raw.data.is_null() {
+                Ok(Image::from_raw(raw))
+            } else {
+                Err(LoadImageError::ReadFailed)
+            }
+        }
+    }
+
+    /// Loads image from encoded bytes (PNG, BMP, TGA, JPEG, GIF, PSD or HDR) into CPU memory (RAM).
+    pub fn from_memory(bytes: &[u8]) -> Result<Image, LoadImageError> {
+        unsafe {
+            let mut width = 0;
+            let mut height = 0;
+            let mut channels = 0;
+            let data = ffi::stbi_load_from_memory(
+                bytes.as_ptr(),
+                bytes.len() as c_int,
+                &mut width,
+                &mut height,
+                &mut channels,
+                0,
+            );
+            let format = match channels {
+                1 => PixelFormat::Grayscale,
+                2 => PixelFormat::GrayAlpha,
+                3 => PixelFormat::R8G8B8,
+                _ => PixelFormat::R8G8B8A8,
+            };
+            if !data.is_null() {
+                Ok(Image::from_raw(ffi::Image {
+                    data: data as *mut _,
+                    width,
+                    height,
+                    mipmaps: 1,
+                    format: format as i32,
+                }))
+            } else {
+                Err(LoadImageError::ReadFailed)
+            }
+        }
+    }
+
+    /// Generates an image with a vertical gradient.
+    pub fn gradient_vertical(
+        width: i32,
+        height: i32,
+        top: impl Into<Color>,
+        bottom: impl Into<Color>,
+    ) -> Image {
+        unsafe {
+            Image::from_raw(ffi::GenImageGradientV(
+                width,
+                height,
+                top.into().into(),
+                bottom.into().into(),
+            ))
+        }
+    }
+
+    /// Generates an image with an horizontal gradient.
+    pub fn gradient_horizontal(
+        width: i32,
+        height: i32,
+        left: impl Into<Color>,
+        right: impl Into<Color>,
+    ) -> Image {
+        unsafe {
+            Image::from_raw(ffi::GenImageGradientH(
+                width,
+                height,
+                left.into().into(),
+                right.into().into(),
+            ))
+        }
+    }
+
+    /// Generates an image with a radial gradient.
+    pub fn gradient_radial(
+        width: i32,
+        height: i32,
+        density: f32,
+        inner: impl Into<Color>,
+        outer: impl Into<Color>,
+    ) -> Image {
+        unsafe {
+            Image::from_raw(ffi::GenImageGradientRadial(
+                width,
+                height,
+                density,
+                inner.into().into(),
+                outer.into().into(),
+            ))
+        }
+    }
+
+    /// Generates an image with a checked pattern.
+    pub fn checked(
+        width: i32,
+        height: i32,
+        checks_x: i32,
+        checks_y: i32,
+        color1: impl Into<Color>,
+        color2: impl Into<Color>,
+    ) -> Image {
+        unsafe {
+            Image::from_raw(ffi::GenImageChecked(
+                width,
+                height,
+                checks_x,
+                checks_y,
+                color1.into().into(),
+                color2.into().into(),
+            ))
+        }
+    }
+
+    /// Generates an image with white noise.
+    pub fn white_noise(width: i32, height: i32, factor: f32) -> Image {
+        unsafe { Image::from_raw(ffi::GenImageWhiteNoise(width, height, factor)) }
+    }
+
+    /// Generates an image with perlin noise.
+    pub fn perlin_noise(
+        width: i32,
+        height: i32,
+        offset_x: i32,
+        offset_y: i32,
+        scale: f32,
+    ) -> Image {
+        unsafe {
+            Image::from_raw(ffi::GenImagePerlinNoise(
+                width, height, offset_x, offset_y, scale,
+            ))
+        }
+    }
+
+    /// Generates an image with cellular algorithm (bigger tile size means bigger cells).
+    pub fn cellular(width: i32, height: i32, tile_size: i32) -> Image {
+        unsafe { Image::from_raw(ffi::GenImageCellular(width, height, tile_size)) }
+    }
+
+    /// Wraps a raw image.
+    #[inline]
+    pub(crate) fn from_raw(raw: ffi::Image) -> Image {
+        Image { raw }
+    }
+
+    /// Returns the image width.
+    pub fn width(&self) -> i32 {
+        self.raw.width
+    }
+
+    /// Returns the image height.
+    pub fn height(&self) -> i32 {
+        self.raw.height
+    }
+
+    /// Returns the image pixel format, `None` if unknown (e.g. empty image).
+    pub fn format(&self) -> Option<PixelFormat> {
+        PixelFormat::from_raw(self.raw.format)
+    }
+
+    /// Returns the pixels if the image format is `PixelFormat::R8G8B8A8`.
+    pub fn pixels(&self) -> Option<&[Color]> {
+        if self.format() == Some(PixelFormat::R8G8B8A8) && !self.raw.data.is_null() {
+            unsafe {
+                Some(slice::from_raw_parts(
+                    self.raw.data as *const Color,
+                    (self.raw.width * self.raw.height) as usize,
+                ))
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Returns the mutable pixels if the image format is `PixelFormat::R8G8B8A8`.
+    pub fn pixels_mut(&mut self) -> Option<&mut [Color]> {
+        if self.format() == Some(PixelFormat::R8G8B8A8) && !self.raw.data.is_null() {
+            unsafe {
+                Some(slice::from_raw_parts_mut(
+                    self.raw.data as *mut Color,
+                    (self.raw.width * self.raw.height) as usize,
+                ))
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Converts the image to a new pixel format (uncompressed formats only).
+    pub fn set_format(&mut self, format: PixelFormat) {
+        unsafe {
+            ffi::ImageFormat(&mut self.raw, format as i32);
+        }
+    }
+
+    /// Creates an image from a part of this image.
+    pub fn sub_image(&self, rectangle: impl Into<Rectangle>) -> Image {
+        unsafe { Image::from_raw(ffi::ImageFromImage(self.raw, rectangle.into().into())) }
+    }
+
+    /// Crops the image to a defined rectangle.
+    pub fn crop(&mut self, rectangle: impl Into<Rectangle>) {
+        unsafe {
+            ffi::ImageCrop(&mut self.raw, rectangle.into().into());
+        }
+    }
+
+    /// Resizes the image (bicubic scaling algorithm).
+    pub fn resize(&mut self, width: i32, height: i32) {
+        unsafe {
+            ffi::ImageResize(&mut self.raw, width, height);
+        }
+    }
+
+    /// Resizes the image (nearest-neighbor scaling algorithm).
+    pub fn resize_nearest(&mut self, width: i32, height: i32) {
+        unsafe {
+            ffi::ImageResizeNN(&mut self.raw, width, height);
+        }
+    }
+
+    /// Resizes the canvas and fills the new area with a color.
+    pub fn resize_canvas(
+        &mut self,
+        width: i32,
+        height: i32,
+        offset_x: i32,
+        offset_y: i32,
+        color: impl Into<Color>,
+    ) {
+        unsafe {
+            ffi::ImageResizeCanvas(
+                &mut self.raw,
+                width,
+                height,
+                offset_x,
+                offset_y,
+                color.into().into(),
+            );
+        }
+    }
+
+    /// Flips the image vertically.
+    pub fn flip_vertical(&mut self) {
+        unsafe {
+            ffi::ImageFlipVertical(&mut self.raw);
+        }
+    }
+
+    /// Flips the image horizontally.
+    pub fn flip_horizontal(&mut self) {
+        unsafe {
+            ffi::ImageFlipHorizontal(&mut self.raw);
+        }
+    }
+
+    /// Rotates the image clockwise by 90 degrees.
+    pub fn rotate_cw(&mut self) {
+        unsafe {
+            ffi::ImageRotateCW(&mut self.raw);
+        }
+    }
+
+    /// Rotates the image counter-clockwise by 90 degrees.
+    pub fn rotate_ccw(&mut self) {
+        unsafe {
+            ffi::ImageRotateCCW(&mut self.raw);
+        }
+    }
+
+    /// Tints the image with a color.
+    pub fn tint(&mut self, color: impl Into<Color>) {
+        unsafe {
+            ffi::ImageColorTint(&mut self.raw, color.into().into());
+        }
+    }
+
+    /// Inverts the image colors.
+    pub fn invert(&mut self) {
+        unsafe {
+            ffi::ImageColorInvert(&mut self.raw);
+        }
+    }
+
+    /// Converts the image colors to grayscale.
+    pub fn grayscale(&mut self) {
+        unsafe {
+            ffi::ImageColorGrayscale(&mut self.raw);
+        }
+    }
+
+    /// Adjusts the image contrast (from -100 to 100).
+    pub fn contrast(&mut self, contrast: f32) {
+        unsafe {
+            ffi::ImageColorContrast(&mut self.raw, contrast);
+        }
+    }
+
+    /// Adjusts the image brightness (from -255 to 255).
+    pub fn brightness(&mut self, brightness: i32) {
+        unsafe {
+            ffi::ImageColorBrightness(&mut self.raw, brightness);
+        }
+    }
+
+    /// Replaces a color by another one.
+    pub fn replace_color(&mut self, color: impl Into<Color>, replace: impl Into<Color>) {
+        unsafe {
+            ffi::ImageColorReplace(&mut self.raw, color.into().into(), replace.into().into());
+        }
+    }
+
+    /// Premultiplies the image colors by alpha.
+    pub fn alpha_premultiply(&mut self) {
+        unsafe {
+            ffi::ImageAlphaPremultiply(&mut self.raw);
+        }
+    }
+
+    /// Exports the image as a PNG file.
+    pub fn export_png<P: AsRef<Path>>(&self, path: P) -> Result<(), ExportImageError> {
+        let converted;
+        let image = match self.format() {
+            Some(PixelFormat::R8G8B8A8) => self,
+            Some(format) if !format.is_compressed() => {
+                let mut image = self.clone();
+                image.set_format(PixelFormat::R8G8B8A8);
+                converted = image;
+                &converted
+            }
+            _ => return Err(ExportImageError::UnsupportedFormat),
+        };
+        let pixels = image.pixels().ok_or(ExportImageError::UnsupportedFormat)?;
+        unsafe {
+            let path = CString::new(path.as_ref().display().to_string()).unwrap();
+            let result = ffi::stbi_write_png(
+                path.as_ptr(),
+                image.width(),
+                image.height(),
+                4,
+                pixels.as_ptr() as *const _,
+                image.width() * 4,
+            );
+            if result != 0 {
+                Ok(())
+            } else {
+                Err(ExportImageError::WriteFailed)
+            }
+        }
+    }
+}
+
+/// Image.
+impl Window {
+    /// Loads image from file into CPU memory (RAM).
+    ///
+    /// The path is resolved through the mounted asset sources first.
+    pub fn load_image<P: AsRef<Path>>(&self, path: P) -> Result<Image, LoadImageError> {
+        let path = path.as_ref();
+        if is_decodable(&normalize_file_type(asset::file_type(path))) {
+            if let Some(bytes) = self
+                .read_mounted_asset(path)
+                .map_err(|_| LoadImageError::ReadFailed)?
+            {
+                return Image::from_memory(&bytes);
+            }
+        }
+        let extracted = self
+            .extract_asset(path)
+            .map_err(|_| LoadImageError::ReadFailed)?;
+        Image::load(extracted.as_ref().map_or(path, ExtractedAsset::path))
+    }
+}
+
+/// Screenshot.
+impl Window {
+    /// Reads the pixels drawn in the current frame into an RGBA image.
+    ///
+    /// Must be called within `Window::draw`, once the frame is drawn: the content of the
+    /// framebuffer is undefined after it has been swapped.
+    pub fn screenshot(&self) -> Image {
+        unsafe {
+            // Pending draw calls are batched
+            ffi::rlglDraw();
+            Image::from_raw(ffi::GetScreenData())
+        }
+    }
+
+    /// Saves the pixels drawn in the current frame as a PNG file, see `screenshot`.
+    pub fn save_screenshot<P: AsRef<Path>>(&self, path: P) -> Result<(), ExportImageError> {
+        self.screenshot().export_png(path)
+    }
+}
+
+impl Clone for Image {
+    fn clone(&self) -> Image {
+        unsafe { Image::from_raw(ffi::ImageCopy(self.raw)) }
+    }
+}
+
+impl Drop for Image {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::UnloadImage(self.raw);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        color::Color,
+        ffi,
+        image::{Image, PixelFormat},
+        math::Rectangle,
+    };
+    use std::mem;
+
+    #[test]
+    fn test_pixel_format_from_raw() {
+        assert_eq!(PixelFormat::from_raw(0), None);
+        assert_eq!(
+            PixelFormat::from_raw(ffi::UNCOMPRESSED_GRAYSCALE),
+            Some(PixelFormat::Grayscale)
+        );
+        assert_eq!(
+            PixelFormat::from_raw(ffi::COMPRESSED_ASTC_8X8_RGBA),
+            Some(PixelFormat::Astc8x8Rgba)
+        );
+        assert_eq!(
+            PixelFormat::from_raw(ffi::COMPRESSED_ASTC_8X8_RGBA + 1),
+            None
+        );
+    }
+
+    #[test]
+    fn test_image_new() {
+        let image = Image::new(4, 2, Color::RED);
+        assert_eq!((image.width(), image.height()), (4, 2));
+        assert_eq!(image.format(), Some(PixelFormat::R8G8B8A8));
+        assert_eq!(image.pixels(), Some(&[Color::RED; 8][..]));
+    }
+
+    #[test]
+    fn test_image_empty() {
+        let image = Image::from_raw(unsafe { mem::zeroed() });
+        assert_eq!(image.format(), None);
+        assert_eq!(image.pixels(), None);
+    }
+
+    #[test]
+    fn test_image_checked() {
+        let image = Image::checked(4, 4, 2, 2, Color::RED, Color::BLUE);
+        let pixels = image.pixels().unwrap();
+        assert_eq!(pixels[0], Color::RED);
+        assert_eq!(pixels[2], Color::BLUE);
+        assert_eq!(pixels[10], Color::RED);
+    }
+
+    #[test]
+    fn test_image_crop() {
+        let (r, g, b, w) = (Color::RED, Color::GREEN, Color::BLUE, Color::WHITE);
+        let mut image = Image::from_pixels(2, 2, &[r, g, b, w]);
+        image.crop(Rectangle::new(1.0, 0.0, 1.0, 2.0));
+        assert_eq!((image.width(), image.height()), (1, 2));
+        assert_eq!(image.pixels(), Some(&[g, w][..]));
+    }
+
+    #[test]
+    fn test_image_resize() {
+        let (r, g, b, w) = (Color::RED, Color::GREEN, Color::BLUE, Color::WHITE);
+        let mut image = Image::from_pixels(2, 2, &[r, g, b, w]);
+        image.resize_nearest(4, 2);
+        assert_eq!(image.pixels(), Some(&[r, r, g, g, b, b, w, w][..]));
+        image.resize(8, 8);
+        assert_eq!((image.width(), image.height()), (8, 8));
+    }
+
+    #[test]
+    fn test_image_flip() {
+        let (r, g, b, w) = (Color::RED, Color::GREEN, Color::BLUE, Color::WHITE);
+        let mut image = Image::from_pixels(2, 2, &[r, g, b, w]);
+        image.flip_horizontal();
+        assert_eq!(image.pixels(), Some(&[g, r, w, b][..]));
+        image.flip_vertical();
+        assert_eq!(image.pixels(), Some(&[w, b, g, r][..]));
+    }
+
+    #[test]
+    fn test_image_set_format() {
+        let mut image = Image::new(2, 2, Color::WHITE);
+        image.set_format(PixelFormat::Grayscale);
+        assert_eq!(image.format(), Some(PixelFormat::Grayscale));
+        assert_eq!(image.pixels(), None);
+        image.set_format(PixelFormat::R8G8B8A8);
+        assert_eq!(image.pixels(), Some(&[Color::WHITE; 4][..]));
+    }
+
+    #[test]
+    fn test_image_pixels_mut() {
+        let mut image = Image::new(2, 1, Color::BLACK);
+        image.pixels_mut().unwrap()[1] = Color::RED;
+        assert_eq!(image.pixels(), Some(&[Color::BLACK, Color::RED][..]));
+    }
+}
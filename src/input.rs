@@ -1,11 +1,15 @@
 //! Input management.
 
-use crate::{ffi, math::Vector2, window::Window};
+use crate::{ffi, math::Vector2, recording::Playback, window::Window};
 use std::{
     ffi::{CStr, CString},
-    mem,
+    path::PathBuf,
+    slice, vec,
 };
 
+/// Number of keyboard key codes.
+pub(crate) const KEY_COUNT: i32 = 512;
+
 /// Kinds of mouse buttons.
 #[repr(i32)]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -15,6 +19,11 @@ pub enum MouseButton {
     Middle = ffi::MOUSE_MIDDLE_BUTTON,
 }
 
+impl MouseButton {
+    pub(crate) const ALL: [MouseButton; 3] =
+        [MouseButton::Left, MouseButton::Right, MouseButton::Middle];
+}
+
 /// Gamepad number.
 #[repr(i32)]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -25,6 +34,11 @@ pub enum Gamepad {
     Four = ffi::GAMEPAD_PLAYER4,
 }
 
+impl Gamepad {
+    pub(crate) const ALL: [Gamepad; 4] =
+        [Gamepad::One, Gamepad::Two, Gamepad::Three, Gamepad::Four];
+}
+
 /// Kinds of gamepad axis.
 #[repr(i32)]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -42,6 +56,17 @@ pub enum GamepadAxis {
     RightTrigger = ffi::GAMEPAD_AXIS_RIGHT_TRIGGER,
 }
 
+impl GamepadAxis {
+    pub(crate) const ALL: [GamepadAxis; 6] = [
+        GamepadAxis::LeftX,
+        GamepadAxis::LeftY,
+        GamepadAxis::RightX,
+        GamepadAxis::RightY,
+        GamepadAxis::LeftTrigger,
+        GamepadAxis::RightTrigger,
+    ];
+}
+
 /// Kinds of gamepad buttons.
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -74,6 +99,28 @@ pub enum GamepadButton {
     MiddleRightThumb,
 }
 
+impl GamepadButton {
+    pub(crate) const ALL: [GamepadButton; 17] = [
+        GamepadButton::LeftFaceUp,
+        GamepadButton::LeftFaceRight,
+        GamepadButton::LeftFaceDown,
+        GamepadButton::LeftFaceLeft,
+        GamepadButton::RightFaceUp,
+        GamepadButton::RightFaceRight,
+        GamepadButton::RightFaceDown,
+        GamepadButton::RightFaceLeft,
+        GamepadButton::LeftTrigger1,
+        GamepadButton::LeftTrigger2,
+        GamepadButton::RightTrigger1,
+        GamepadButton::RightTrigger2,
+        GamepadButton::MiddleLeft,
+        GamepadButton::Middle,
+        GamepadButton::MiddleRight,
+        GamepadButton::MiddleLeftThumb,
+        GamepadButton::MiddleRightThumb,
+    ];
+}
+
 /// Kinds of keyboard keys.
 #[repr(i32)]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -190,144 +237,316 @@ pub enum Key {
     KeypadEqual = ffi::KEY_KP_EQUAL,
 }
 
+impl Key {
+    /// Converts a raw key code.
+    pub(crate) fn from_raw(key: i32) -> Option<Key> {
+        match key {
+            ffi::KEY_APOSTROPHE => Some(Key::Apostrophe),
+            ffi::KEY_COMMA => Some(Key::Comma),
+            ffi::KEY_MINUS => Some(Key::Minus),
+            ffi::KEY_PERIOD => Some(Key::Period),
+            ffi::KEY_SLASH => Some(Key::Slash),
+            ffi::KEY_ZERO => Some(Key::Zero),
+            ffi::KEY_ONE => Some(Key::One),
+            ffi::KEY_TWO => Some(Key::Two),
+            ffi::KEY_THREE => Some(Key::Three),
+            ffi::KEY_FOUR => Some(Key::Four),
+            ffi::KEY_FIVE => Some(Key::Five),
+            ffi::KEY_SIX => Some(Key::Six),
+            ffi::KEY_SEVEN => Some(Key::Seven),
+            ffi::KEY_EIGHT => Some(Key::Eight),
+            ffi::KEY_NINE => Some(Key::Nine),
+            ffi::KEY_SEMICOLON => Some(Key::Semicolon),
+            ffi::KEY_EQUAL => Some(Key::Equal),
+            ffi::KEY_A => Some(Key::A),
+            ffi::KEY_B => Some(Key::B),
+            ffi::KEY_C => Some(Key::C),
+            ffi::KEY_D => Some(Key::D),
+            ffi::KEY_E => Some(Key::E),
+            ffi::KEY_F => Some(Key::F),
+            ffi::KEY_G => Some(Key::G),
+            ffi::KEY_H => Some(Key::H),
+            ffi::KEY_I => Some(Key::I),
+            ffi::KEY_J => Some(Key::J),
+            ffi::KEY_K => Some(Key::K),
+            ffi::KEY_L => Some(Key::L),
+            ffi::KEY_M => Some(Key::M),
+            ffi::KEY_N => Some(Key::N),
+            ffi::KEY_O => Some(Key::O),
+            ffi::KEY_P => Some(Key::P),
+            ffi::KEY_Q => Some(Key::Q),
+            ffi::KEY_R => Some(Key::R),
+            ffi::KEY_S => Some(Key::S),
+            ffi::KEY_T => Some(Key::T),
+            ffi::KEY_U => Some(Key::U),
+            ffi::KEY_V => Some(Key::V),
+            ffi::KEY_W => Some(Key::W),
+            ffi::KEY_X => Some(Key::X),
+            ffi::KEY_Y => Some(Key::Y),
+            ffi::KEY_Z => Some(Key::Z),
+            ffi::KEY_SPACE => Some(Key::Space),
+            ffi::KEY_ESCAPE => Some(Key::Escape),
+            ffi::KEY_ENTER => Some(Key::Enter),
+            ffi::KEY_TAB => Some(Key::Tab),
+            ffi::KEY_BACKSPACE => Some(Key::Backspace),
+            ffi::KEY_INSERT => Some(Key::Insert),
+            ffi::KEY_DELETE => Some(Key::Delete),
+            ffi::KEY_RIGHT => Some(Key::Right),
+            ffi::KEY_LEFT => Some(Key::Left),
+            ffi::KEY_DOWN => Some(Key::Down),
+            ffi::KEY_UP => Some(Key::Up),
+            ffi::KEY_PAGE_UP => Some(Key::PageUp),
+            ffi::KEY_PAGE_DOWN => Some(Key::PageDown),
+            ffi::KEY_HOME => Some(Key::Home),
+            ffi::KEY_END => Some(Key::End),
+            ffi::KEY_CAPS_LOCK => Some(Key::CapsLock),
+            ffi::KEY_SCROLL_LOCK => Some(Key::ScrollLock),
+            ffi::KEY_NUM_LOCK => Some(Key::NumLock),
+            ffi::KEY_PRINT_SCREEN => Some(Key::PrintScreen),
+            ffi::KEY_PAUSE => Some(Key::Pause),
+            ffi::KEY_F1 => Some(Key::F1),
+            ffi::KEY_F2 => Some(Key::F2),
+            ffi::KEY_F3 => Some(Key::F3),
+            ffi::KEY_F4 => Some(Key::F4),
+            ffi::KEY_F5 => Some(Key::F5),
+            ffi::KEY_F6 => Some(Key::F6),
+            ffi::KEY_F7 => Some(Key::F7),
+            ffi::KEY_F8 => Some(Key::F8),
+            ffi::KEY_F9 => Some(Key::F9),
+            ffi::KEY_F10 => Some(Key::F10),
+            ffi::KEY_F11 => Some(Key::F11),
+            ffi::KEY_F12 => Some(Key::F12),
+            ffi::KEY_LEFT_SHIFT => Some(Key::LeftShift),
+            ffi::KEY_LEFT_CONTROL => Some(Key::LeftControl),
+            ffi::KEY_LEFT_ALT => Some(Key::LeftAlt),
+            ffi::KEY_LEFT_SUPER => Some(Key::LeftSuper),
+            ffi::KEY_RIGHT_SHIFT => Some(Key::RightShift),
+            ffi::KEY_RIGHT_CONTROL => Some(Key::RightControl),
+            ffi::KEY_RIGHT_ALT => Some(Key::RightAlt),
+            ffi::KEY_RIGHT_SUPER => Some(Key::RightSuper),
+            ffi::KEY_KB_MENU => Some(Key::Menu),
+            ffi::KEY_LEFT_BRACKET => Some(Key::LeftBracket),
+            ffi::KEY_BACKSLASH => Some(Key::Backslash),
+            ffi::KEY_RIGHT_BRACKET => Some(Key::RightBracket),
+            ffi::KEY_GRAVE => Some(Key::Grave),
+            ffi::KEY_KP_0 => Some(Key::KeypadZero),
+            ffi::KEY_KP_1 => Some(Key::KeypadOne),
+            ffi::KEY_KP_2 => Some(Key::KeypadTwo),
+            ffi::KEY_KP_3 => Some(Key::KeypadThree),
+            ffi::KEY_KP_4 => Some(Key::KeypadFour),
+            ffi::KEY_KP_5 => Some(Key::KeypadFive),
+            ffi::KEY_KP_6 => Some(Key::KeypadSix),
+            ffi::KEY_KP_7 => Some(Key::KeypadSeven),
+            ffi::KEY_KP_8 => Some(Key::KeypadEight),
+            ffi::KEY_KP_9 => Some(Key::KeypadNine),
+            ffi::KEY_KP_DECIMAL => Some(Key::KeypadDecimal),
+            ffi::KEY_KP_DIVIDE => Some(Key::KeypadDivide),
+            ffi::KEY_KP_MULTIPLY => Some(Key::KeypadMultiply),
+            ffi::KEY_KP_SUBTRACT => Some(Key::KeypadSubtract),
+            ffi::KEY_KP_ADD => Some(Key::KeypadAdd),
+            ffi::KEY_KP_ENTER => Some(Key::KeypadEnter),
+            ffi::KEY_KP_EQUAL => Some(Key::KeypadEqual),
+            _ => None,
+        }
+    }
+}
+
+/// Kinds of input events.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputEvent {
+    KeyDown(Key),
+    KeyUp(Key),
+    Char(char),
+    MouseMove(Vector2),
+    MouseButton {
+        button: MouseButton,
+        pressed: bool,
+    },
+    Wheel(i32),
+    GamepadButton {
+        gamepad: Gamepad,
+        button: GamepadButton,
+        pressed: bool,
+    },
+    GamepadAxis {
+        gamepad: Gamepad,
+        axis: GamepadAxis,
+        value: f32,
+    },
+    Resize {
+        width: i32,
+        height: i32,
+    },
+    FileDrop(Vec<PathBuf>),
+}
+
+/// Input state kept between frames to detect changes.
+#[derive(Default)]
+pub(crate) struct InputState {
+    mouse_position: Vector2,
+    gamepad_axes: [[f32; 6]; 4],
+    pub(crate) playback: Playback,
+    pub(crate) char_cursor: usize,
+}
+
+/// Iterator over the input events of a frame.
+pub struct Events {
+    events: vec::IntoIter<InputEvent>,
+}
+
+impl Iterator for Events {
+    type Item = InputEvent;
+
+    fn next(&mut self) -> Option<InputEvent> {
+        self.events.next()
+    }
+}
+
 /// Input.
 impl Window {
+    /// Returns the input events which happened during the current frame, grouped by kind.
+    ///
+    /// Must be called once per frame: characters and dropped files are consumed, mouse and
+    /// gamepad axis movements are relative to the previous call.
+    pub fn events(&self) -> Events {
+        let mut events = Vec::new();
+        unsafe {
+            if ffi::IsWindowResized() {
+                events.push(InputEvent::Resize {
+                    width: ffi::GetScreenWidth(),
+                    height: ffi::GetScreenHeight(),
+                });
+            }
+        }
+        for key in (0..KEY_COUNT).filter_map(Key::from_raw) {
+            if self.is_key_pressed(key) {
+                events.push(InputEvent::KeyDown(key));
+            } else if self.is_key_released(key) {
+                events.push(InputEvent::KeyUp(key));
+            }
+        }
+        while let Some(character) = self.char_pressed() {
+            events.push(InputEvent::Char(character));
+        }
+        let mouse_position = self.mouse_position();
+        if mouse_position != self.input.borrow().mouse_position {
+            self.input.borrow_mut().mouse_position = mouse_position;
+            events.push(InputEvent::MouseMove(mouse_position));
+        }
+        for &button in MouseButton::ALL.iter() {
+            if self.is_mouse_button_pressed(button) {
+                events.push(InputEvent::MouseButton {
+                    button,
+                    pressed: true,
+                });
+            } else if self.is_mouse_button_released(button) {
+                events.push(InputEvent::MouseButton {
+                    button,
+                    pressed: false,
+                });
+            }
+        }
+        let wheel = self.mouse_wheel_move();
+        if wheel != 0 {
+            events.push(InputEvent::Wheel(wheel));
+        }
+        for &gamepad in Gamepad::ALL.iter() {
+            if !self.is_gamepad_available(gamepad) {
+                continue;
+            }
+            for &button in GamepadButton::ALL.iter() {
+                if self.is_gamepad_button_pressed(gamepad, button) {
+                    events.push(InputEvent::GamepadButton {
+                        gamepad,
+                        button,
+                        pressed: true,
+                    });
+                } else if self.is_gamepad_button_released(gamepad, button) {
+                    events.push(InputEvent::GamepadButton {
+                        gamepad,
+                        button,
+                        pressed: false,
+                    });
+                }
+            }
+            for &axis in GamepadAxis::ALL.iter() {
+                let value = self.gamepad_axis_movement(gamepad, axis);
+                let previous =
+                    &mut self.input.borrow_mut().gamepad_axes[gamepad as usize][axis as usize];
+                if value != *previous {
+                    *previous = value;
+                    events.push(InputEvent::GamepadAxis {
+                        gamepad,
+                        axis,
+                        value,
+                    });
+                }
+            }
+        }
+        unsafe {
+            if ffi::IsFileDropped() {
+                let mut count = 0;
+                let files = ffi::GetDroppedFiles(&mut count);
+                let files = slice::from_raw_parts(files, count as usize)
+                    .iter()
+                    .map(|&file| PathBuf::from(CStr::from_ptr(file).to_string_lossy().as_ref()))
+                    .collect();
+                ffi::ClearDroppedFiles();
+                events.push(InputEvent::FileDrop(files));
+            }
+        }
+        Events {
+            events: events.into_iter(),
+        }
+    }
+
     /// Detects if a key has been pressed once.
     #[inline]
     pub fn is_key_pressed(&self, key: Key) -> bool {
-        unsafe { ffi::IsKeyPressed(key as i32) }
+        self.recorded(|frame, previous| frame.is_key_down(key) && !previous.is_key_down(key))
+            .unwrap_or_else(|| unsafe { ffi::IsKeyPressed(key as i32) })
     }
 
     /// Detects if a key is being pressed.
     #[inline]
     pub fn is_key_down(&self, key: Key) -> bool {
-        unsafe { ffi::IsKeyDown(key as i32) }
+        self.recorded(|frame, _| frame.is_key_down(key))
+            .unwrap_or_else(|| unsafe { ffi::IsKeyDown(key as i32) })
     }
 
     /// Detects if a key has been released once.
     #[inline]
     pub fn is_key_released(&self, key: Key) -> bool {
-        unsafe { ffi::IsKeyReleased(key as i32) }
+        self.recorded(|frame, previous| !frame.is_key_down(key) && previous.is_key_down(key))
+            .unwrap_or_else(|| unsafe { ffi::IsKeyReleased(key as i32) })
     }
 
     /// Detects if a key is not being pressed
     #[inline]
     pub fn is_key_up(&self, key: Key) -> bool {
-        unsafe { ffi::IsKeyUp(key as i32) }
+        self.recorded(|frame, _| !frame.is_key_down(key))
+            .unwrap_or_else(|| unsafe { ffi::IsKeyUp(key as i32) })
     }
 
     /// Gets latest key pressed.
+    ///
+    /// Keys are read from the same queue as characters, see `char_pressed`.
     pub fn last_key_pressed(&self) -> Option<Key> {
-        unsafe {
+        match self.recorded_char() {
+            Some(character) => character.and_then(|character| Key::from_raw(character as i32)),
+            None => unsafe { Key::from_raw(ffi::GetKeyPressed()) },
+        }
+    }
+
+    /// Gets the next character typed, following the keyboard layout; call it repeatedly to get
+    /// all the characters typed during the frame.
+    pub fn char_pressed(&self) -> Option<char> {
+        self.recorded_char().unwrap_or_else(|| unsafe {
             match ffi::GetKeyPressed() {
-                ffi::KEY_APOSTROPHE => Some(Key::Apostrophe),
-                ffi::KEY_COMMA => Some(Key::Comma),
-                ffi::KEY_MINUS => Some(Key::Minus),
-                ffi::KEY_PERIOD => Some(Key::Period),
-                ffi::KEY_SLASH => Some(Key::Slash),
-                ffi::KEY_ZERO => Some(Key::Zero),
-                ffi::KEY_ONE => Some(Key::One),
-                ffi::KEY_TWO => Some(Key::Two),
-                ffi::KEY_THREE => Some(Key::Three),
-                ffi::KEY_FOUR => Some(Key::Four),
-                ffi::KEY_FIVE => Some(Key::Five),
-                ffi::KEY_SIX => Some(Key::Six),
-                ffi::KEY_SEVEN => Some(Key::Seven),
-                ffi::KEY_EIGHT => Some(Key::Eight),
-                ffi::KEY_NINE => Some(Key::Nine),
-                ffi::KEY_SEMICOLON => Some(Key::Semicolon),
-                ffi::KEY_EQUAL => Some(Key::Equal),
-                ffi::KEY_A => Some(Key::A),
-                ffi::KEY_B => Some(Key::B),
-                ffi::KEY_C => Some(Key::C),
-                ffi::KEY_D => Some(Key::D),
-                ffi::KEY_E => Some(Key::E),
-                ffi::KEY_F => Some(Key::F),
-                ffi::KEY_G => Some(Key::G),
-                ffi::KEY_H => Some(Key::H),
-                ffi::KEY_I => Some(Key::I),
-                ffi::KEY_J => Some(Key::J),
-                ffi::KEY_K => Some(Key::K),
-                ffi::KEY_L => Some(Key::L),
-                ffi::KEY_M => Some(Key::M),
-                ffi::KEY_N => Some(Key::N),
-                ffi::KEY_O => Some(Key::O),
-                ffi::KEY_P => Some(Key::P),
-                ffi::KEY_Q => Some(Key::Q),
-                ffi::KEY_R => Some(Key::R),
-                ffi::KEY_S => Some(Key::S),
-                ffi::KEY_T => Some(Key::T),
-                ffi::KEY_U => Some(Key::U),
-                ffi::KEY_V => Some(Key::V),
-                ffi::KEY_W => Some(Key::W),
-                ffi::KEY_X => Some(Key::X),
-                ffi::KEY_Y => Some(Key::Y),
-                ffi::KEY_Z => Some(Key::Z),
-                ffi::KEY_SPACE => Some(Key::Space),
-                ffi::KEY_ESCAPE => Some(Key::Escape),
-                ffi::KEY_ENTER => Some(Key::Enter),
-                ffi::KEY_TAB => Some(Key::Tab),
-                ffi::KEY_BACKSPACE => Some(Key::Backspace),
-                ffi::KEY_INSERT => Some(Key::Insert),
-                ffi::KEY_DELETE => Some(Key::Delete),
-                ffi::KEY_RIGHT => Some(Key::Right),
-                ffi::KEY_LEFT => Some(Key::Left),
-                ffi::KEY_DOWN => Some(Key::Down),
-                ffi::KEY_UP => Some(Key::Up),
-                ffi::KEY_PAGE_UP => Some(Key::PageUp),
-                ffi::KEY_PAGE_DOWN => Some(Key::PageDown),
-                ffi::KEY_HOME => Some(Key::Home),
-                ffi::KEY_END => Some(Key::End),
-                ffi::KEY_CAPS_LOCK => Some(Key::CapsLock),
-                ffi::KEY_SCROLL_LOCK => Some(Key::ScrollLock),
-                ffi::KEY_NUM_LOCK => Some(Key::NumLock),
-                ffi::KEY_PRINT_SCREEN => Some(Key::PrintScreen),
-                ffi::KEY_PAUSE => Some(Key::Pause),
-                ffi::KEY_F1 => Some(Key::F1),
-                ffi::KEY_F2 => Some(Key::F2),
-                ffi::KEY_F3 => Some(Key::F3),
-                ffi::KEY_F4 => Some(Key::F4),
-                ffi::KEY_F5 => Some(Key::F5),
-                ffi::KEY_F6 => Some(Key::F6),
-                ffi::KEY_F7 => Some(Key::F7),
-                ffi::KEY_F8 => Some(Key::F8),
-                ffi::KEY_F9 => Some(Key::F9),
-                ffi::KEY_F10 => Some(Key::F10),
-                ffi::KEY_F11 => Some(Key::F11),
-                ffi::KEY_F12 => Some(Key::F12),
-                ffi::KEY_LEFT_SHIFT => Some(Key::LeftShift),
-                ffi::KEY_LEFT_CONTROL => Some(Key::LeftControl),
-                ffi::KEY_LEFT_ALT => Some(Key::LeftAlt),
-                ffi::KEY_LEFT_SUPER => Some(Key::LeftSuper),
-                ffi::KEY_RIGHT_SHIFT => Some(Key::RightShift),
-                ffi::KEY_RIGHT_CONTROL => Some(Key::RightControl),
-                ffi::KEY_RIGHT_ALT => Some(Key::RightAlt),
-                ffi::KEY_RIGHT_SUPER => Some(Key::RightSuper),
-                ffi::KEY_KB_MENU => Some(Key::Menu),
-                ffi::KEY_LEFT_BRACKET => Some(Key::LeftBracket),
-                ffi::KEY_BACKSLASH => Some(Key::Backslash),
-                ffi::KEY_RIGHT_BRACKET => Some(Key::RightBracket),
-                ffi::KEY_GRAVE => Some(Key::Grave),
-                ffi::KEY_KP_0 => Some(Key::KeypadZero),
-                ffi::KEY_KP_1 => Some(Key::KeypadOne),
-                ffi::KEY_KP_2 => Some(Key::KeypadTwo),
-                ffi::KEY_KP_3 => Some(Key::KeypadThree),
-                ffi::KEY_KP_4 => Some(Key::KeypadFour),
-                ffi::KEY_KP_5 => Some(Key::KeypadFive),
-                ffi::KEY_KP_6 => Some(Key::KeypadSix),
-                ffi::KEY_KP_7 => Some(Key::KeypadSeven),
-                ffi::KEY_KP_8 => Some(Key::KeypadEight),
-                ffi::KEY_KP_9 => Some(Key::KeypadNine),
-                ffi::KEY_KP_DECIMAL => Some(Key::KeypadDecimal),
-                ffi::KEY_KP_DIVIDE => Some(Key::KeypadDivide),
-                ffi::KEY_KP_MULTIPLY => Some(Key::KeypadMultiply),
-                ffi::KEY_KP_SUBTRACT => Some(Key::KeypadSubtract),
-                ffi::KEY_KP_ADD => Some(Key::KeypadAdd),
-                ffi::KEY_KP_ENTER => Some(Key::KeypadEnter),
-                ffi::KEY_KP_EQUAL => Some(Key::KeypadEqual),
-                _ => None,
+                0 => None,
+                character => std::char::from_u32(character as u32),
             }
-        }
+        })
     }
 
     /// Sets a custom key to exit program (default is ESC).
@@ -341,43 +560,54 @@ impl Window {
     /// Detect if a mouse button has been pressed once.
     #[inline]
     pub fn is_mouse_button_pressed(&self, button: MouseButton) -> bool {
-        unsafe { ffi::IsMouseButtonPressed(button as i32) }
+        self.recorded(|frame, previous| {
+            frame.is_mouse_button_down(button) && !previous.is_mouse_button_down(button)
+        })
+        .unwrap_or_else(|| unsafe { ffi::IsMouseButtonPressed(button as i32) })
     }
 
     /// Detects if a mouse button is being pressed.
     #[inline]
     pub fn is_mouse_button_down(&self, button: MouseButton) -> bool {
-        unsafe { ffi::IsMouseButtonDown(button as i32) }
+        self.recorded(|frame, _| frame.is_mouse_button_down(button))
+            .unwrap_or_else(|| unsafe { ffi::IsMouseButtonDown(button as i32) })
     }
 
     /// Detects if a mouse button has been released once.
     #[inline]
     pub fn is_mouse_button_released(&self, button: MouseButton) -> bool {
-        unsafe { ffi::IsMouseButtonReleased(button as i32) }
+        self.recorded(|frame, previous| {
+            !frame.is_mouse_button_down(button) && previous.is_mouse_button_down(button)
+        })
+        .unwrap_or_else(|| unsafe { ffi::IsMouseButtonReleased(button as i32) })
     }
 
     /// Detects if a mouse button is not being pressed.
     #[inline]
     pub fn is_mouse_button_up(&self, button: MouseButton) -> bool {
-        unsafe { ffi::IsMouseButtonUp(button as i32) }
+        self.recorded(|frame, _| !frame.is_mouse_button_down(button))
+            .unwrap_or_else(|| unsafe { ffi::IsMouseButtonUp(button as i32) })
     }
 
     /// Returns mouse X position.
     #[inline]
     pub fn mouse_x(&self) -> i32 {
-        unsafe { ffi::GetMouseX() }
+        self.recorded(|frame, _| frame.mouse_position.x as i32)
+            .unwrap_or_else(|| unsafe { ffi::GetMouseX() })
     }
 
     /// Returns mouse Y position.
     #[inline]
     pub fn mouse_y(&self) -> i32 {
-        unsafe { ffi::GetMouseY() }
+        self.recorded(|frame, _| frame.mouse_position.y as i32)
+            .unwrap_or_else(|| unsafe { ffi::GetMouseY() })
     }
 
     /// Returns mouse position.
     #[inline]
     pub fn mouse_position(&self) -> Vector2 {
-        unsafe { ffi::GetMousePosition().into() }
+        self.recorded(|frame, _| frame.mouse_position)
+            .unwrap_or_else(|| unsafe { ffi::GetMousePosition().into() })
     }
 
     /// Sets mouse position.
@@ -407,13 +637,15 @@ impl Window {
     /// Returns mouse wheel vertical movement.
     #[inline]
     pub fn mouse_wheel_move(&self) -> i32 {
-        unsafe { ffi::GetMouseWheelMove() }
+        self.recorded(|frame, _| frame.wheel)
+            .unwrap_or_else(|| unsafe { ffi::GetMouseWheelMove() })
     }
 
     /// Detects if a gamepad is available.
     #[inline]
     pub fn is_gamepad_available(&self, gamepad: Gamepad) -> bool {
-        unsafe { ffi::IsGamepadAvailable(gamepad as i32) }
+        self.recorded(|frame, _| frame.gamepad(gamepad).is_some())
+            .unwrap_or_else(|| unsafe { ffi::IsGamepadAvailable(gamepad as i32) })
     }
 
     /// Checks gamepad name (if available).
@@ -439,46 +671,66 @@ impl Window {
     /// Detects if a gamepad button has been pressed once.
     #[inline]
     pub fn is_gamepad_button_pressed(&self, gamepad: Gamepad, button: GamepadButton) -> bool {
-        unsafe { ffi::IsGamepadButtonPressed(gamepad as i32, button as i32) }
+        self.recorded(|frame, previous| {
+            frame.is_gamepad_button_down(gamepad, button)
+                && !previous.is_gamepad_button_down(gamepad, button)
+        })
+        .unwrap_or_else(|| unsafe { ffi::IsGamepadButtonPressed(gamepad as i32, button as i32) })
     }
     /// Detects if a gamepad button is being pressed.
     pub fn is_gamepad_button_down(&self, gamepad: Gamepad, button: GamepadButton) -> bool {
-        unsafe { ffi::IsGamepadButtonDown(gamepad as i32, button as i32) }
+        self.recorded(|frame, _| frame.is_gamepad_button_down(gamepad, button))
+            .unwrap_or_else(|| unsafe { ffi::IsGamepadButtonDown(gamepad as i32, button as i32) })
     }
 
     /// Detects if a gamepad button has been released once.
     #[inline]
     pub fn is_gamepad_button_released(&self, gamepad: Gamepad, button: GamepadButton) -> bool {
-        unsafe { ffi::IsGamepadButtonReleased(gamepad as i32, button as i32) }
+        self.recorded(|frame, previous| {
+            !frame.is_gamepad_button_down(gamepad, button)
+                && previous.is_gamepad_button_down(gamepad, button)
+        })
+        .unwrap_or_else(|| unsafe { ffi::IsGamepadButtonReleased(gamepad as i32, button as i32) })
     }
 
     /// Detects if a gamepad button is not being pressed.
     #[inline]
     pub fn is_gamepad_button_up(&self, gamepad: Gamepad, button: GamepadButton) -> bool {
-        unsafe { ffi::IsGamepadButtonUp(gamepad as i32, button as i32) }
+        self.recorded(|frame, _| !frame.is_gamepad_button_down(gamepad, button))
+            .unwrap_or_else(|| unsafe { ffi::IsGamepadButtonUp(gamepad as i32, button as i32) })
     }
 
     /// Gets the last gamepad button pressed.
     pub fn gamepad_button_pressed(&self) -> Option<GamepadButton> {
-        unsafe {
-            let button = ffi::GetGamepadButtonPressed();
-            if button > 0 {
-                Some(mem::transmute(button))
-            } else {
-                None
-            }
-        }
+        let button = self
+            .recorded(|frame, _| frame.last_gamepad_button)
+            .unwrap_or_else(|| unsafe { ffi::GetGamepadButtonPressed() });
+        // Recordings may hold any value
+        GamepadButton::ALL
+            .iter()
+            .copied()
+            .find(|&known| known as i32 == button)
     }
 
     /// Returns gamepad axis count for a gamepad.
     #[inline]
     pub fn gamepad_axis_count(&self, gamepad: Gamepad) -> i32 {
-        unsafe { ffi::GetGamepadAxisCount(gamepad as i32) }
+        self.recorded(|frame, _| {
+            frame
+                .gamepad(gamepad)
+                .map_or(0, |gamepad| gamepad.axis_count as i32)
+        })
+        .unwrap_or_else(|| unsafe { ffi::GetGamepadAxisCount(gamepad as i32) })
     }
 
     /// Returns axis movement value for a gamepad axis.
     #[inline]
     pub fn gamepad_axis_movement(&self, gamepad: Gamepad, axis: GamepadAxis) -> f32 {
-        unsafe { ffi::GetGamepadAxisMovement(gamepad as i32, axis as i32) }
+        self.recorded(|frame, _| {
+            frame
+                .gamepad(gamepad)
+                .map_or(0.0, |gamepad| gamepad.axes[axis as usize])
+        })
+        .unwrap_or_else(|| unsafe { ffi::GetGamepadAxisMovement(gamepad as i32, axis as i32) })
     }
 }
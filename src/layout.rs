@@ -0,0 +1,631 @@
+//! Text layout: alignment, wrapping, truncation and styled spans.
+
+use crate::{
+    color::Color,
+    drawing::Canvas,
+    ffi,
+    math::{Rectangle, Vector2},
+    text::Font,
+};
+use std::ops::Range;
+
+/// Text appended to truncated lines.
+const ELLIPSIS: &str = "...";
+
+/// Width of tabulations, in spaces.
+const TAB_SIZE: f32 = 4.0;
+
+/// Kinds of horizontal text alignments.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HorizontalAlignment {
+    Left,
+    Center,
+    Right,
+    /// Stretches the spaces of wrapped lines to fill the width.
+    Justify,
+}
+
+/// Kinds of vertical text alignments.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VerticalAlignment {
+    Top,
+    Middle,
+    Bottom,
+}
+
+/// Text drawn with the same font, size and color.
+#[derive(Clone)]
+struct Span<'a> {
+    text: String,
+    font: &'a Font,
+    size: f32,
+    color: Color,
+}
+
+/// Character to lay out.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Item {
+    char: char,
+    index: usize,
+    span: usize,
+    size: f32,
+    advance: f32,
+}
+
+/// Line of characters to lay out.
+#[derive(Clone, Debug, PartialEq)]
+struct ItemLine {
+    items: Vec<Item>,
+    /// Size of the line characters, or of the line break for empty lines.
+    size: f32,
+    /// Whether the line ends a paragraph (not justified).
+    paragraph_end: bool,
+}
+
+/// Builder of text layouts, made of styled spans placed within bounds.
+#[derive(Clone)]
+pub struct TextLayout<'a> {
+    spans: Vec<Span<'a>>,
+    bounds: Rectangle,
+    horizontal_alignment: HorizontalAlignment,
+    vertical_alignment: VerticalAlignment,
+    line_height: f32,
+    spacing: f32,
+    wrap: bool,
+    ellipsis: bool,
+}
+
+impl<'a> TextLayout<'a> {
+    /// Creates a `TextLayout` within bounds, wrapping lines aligned to the top left.
+    pub fn new(bounds: impl Into<Rectangle>) -> TextLayout<'a> {
+        TextLayout {
+            spans: Vec::new(),
+            bounds: bounds.into(),
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Top,
+            line_height: 1.5,
+            spacing: 0.0,
+            wrap: true,
+            ellipsis: false,
+        }
+    }
+
+    /// Appends a text span.
+    pub fn span(
+        mut self,
+        text: &str,
+        font: &'a Font,
+        size: f32,
+        color: impl Into<Color>,
+    ) -> TextLayout<'a> {
+        self.spans.push(Span {
+            text: text.to_string(),
+            font,
+            size,
+            color: color.into(),
+        });
+        self
+    }
+
+    /// Sets the horizontal alignment of lines.
+    pub fn alignment(mut self, alignment: HorizontalAlignment) -> TextLayout<'a> {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the vertical alignment of the text within the bounds.
+    pub fn vertical_alignment(mut self, alignment: VerticalAlignment) -> TextLayout<'a> {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Sets the line height, relative to the largest size of each line (1.5 by default).
+    pub fn line_height(mut self, line_height: f32) -> TextLayout<'a> {
+        self.line_height = line_height;
+        self
+    }
+
+    /// Sets the spacing between characters, in pixels.
+    pub fn spacing(mut self, spacing: f32) -> TextLayout<'a> {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets whether lines are wrapped at the bounds width, between words if possible.
+    pub fn wrap(mut self, wrap: bool) -> TextLayout<'a> {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Sets whether text overflowing the bounds is truncated with an ellipsis.
+    pub fn ellipsis(mut self, ellipsis: bool) -> TextLayout<'a> {
+        self.ellipsis = ellipsis;
+        self
+    }
+
+    /// Computes the layout.
+    pub fn build(&self) -> TextBlock<'a> {
+        let mut items: Vec<Item> = Vec::new();
+        let mut index = 0;
+        for (span_index, span) in self.spans.iter().enumerate() {
+            let scale = span.size / span.font.base_size() as f32;
+            for char in span.text.chars() {
+                // Kerning adjusts the distance to the previous character of the span
+                match items.last_mut() {
+                    Some(previous) if previous.span == span_index => {
+                        previous.advance += span.font.kerning(previous.char, char) as f32 * scale;
+                    }
+                    _ => {}
+                }
+                items.push(Item {
+                    char,
+                    index,
+                    span: span_index,
+                    size: span.size,
+                    advance: char_advance(char, |char| span.font.advance(char, span.size)),
+                });
+                index += 1;
+            }
+        }
+        let spans = &self.spans;
+        let ellipsis = |span: usize| -> Vec<Item> {
+            ELLIPSIS
+                .chars()
+                .map(|char| Item {
+                    char,
+                    index: 0,
+                    span,
+                    size: spans[span].size,
+                    advance: spans[span].font.advance(char, spans[span].size),
+                })
+                .collect()
+        };
+        let (glyphs, tops, lines, truncated) = self.layout(&items, &ellipsis);
+        TextBlock {
+            spans: self.spans.clone(),
+            glyphs,
+            tops,
+            lines,
+            truncated,
+        }
+    }
+
+    /// Places characters, returning the glyphs, their vertical drawing positions, the lines and
+    /// whether the text was truncated.
+    fn layout(
+        &self,
+        items: &[Item],
+        ellipsis: &dyn Fn(usize) -> Vec<Item>,
+    ) -> (Vec<LayoutGlyph>, Vec<f32>, Vec<LayoutLine>, bool) {
+        let mut lines = self.break_lines(items);
+        let mut truncated = false;
+        if self.ellipsis {
+            // Keeps the lines fitting in the bounds height, at least one
+            let mut height = 0.0;
+            let count = lines
+                .iter()
+                .take_while(|line| {
+                    height += line.size * self.line_height;
+                    height <= self.bounds.height
+                })
+                .count()
+                .max(1);
+            if count < lines.len() {
+                let next = lines[count].items.first().map(|item| item.index);
+                lines.truncate(count);
+                let last = lines.last_mut().unwrap();
+                let next =
+                    next.unwrap_or_else(|| last.items.last().map_or(0, |item| item.index + 1));
+                self.truncate(&mut last.items, next, ellipsis, true);
+                truncated = true;
+            }
+            for line in &mut lines {
+                if self.width(&line.items) > self.bounds.width {
+                    let next = line.items.last().map_or(0, |item| item.index + 1);
+                    self.truncate(&mut line.items, next, ellipsis, false);
+                    truncated = true;
+                }
+            }
+        }
+        let height: f32 = lines.iter().map(|line| line.size * self.line_height).sum();
+        let mut y = self.bounds.y
+            + match self.vertical_alignment {
+                VerticalAlignment::Top => 0.0,
+                VerticalAlignment::Middle => (self.bounds.height - height) / 2.0,
+                VerticalAlignment::Bottom => self.bounds.height - height,
+            };
+        let mut glyphs = Vec::new();
+        let mut tops = Vec::new();
+        let mut layout_lines = Vec::new();
+        for line in &lines {
+            let line_height = line.size * self.line_height;
+            let width = self.width(&line.items);
+            let visible = visible_length(&line.items);
+            let mut extra = 0.0;
+            let mut x = self.bounds.x;
+            match self.horizontal_alignment {
+                HorizontalAlignment::Left => {}
+                HorizontalAlignment::Center => x += (self.bounds.width - width) / 2.0,
+                HorizontalAlignment::Right => x += self.bounds.width - width,
+                HorizontalAlignment::Justify if !line.paragraph_end => {
+                    let spaces = line.items[..visible]
+                        .iter()
+                        .filter(|item| item.char == ' ')
+                        .count();
+                    if spaces > 0 && width < self.bounds.width {
+                        extra = (self.bounds.width - width) / spaces as f32;
+                    }
+                }
+                HorizontalAlignment::Justify => {}
+            }
+            let start = glyphs.len();
+            let line_x = x;
+            for (position, item) in line.items.iter().enumerate() {
+                let mut advance = item.advance + self.spacing;
+                if item.char == ' ' && position < visible {
+                    advance += extra;
+                }
+                glyphs.push(LayoutGlyph {
+                    char: item.char,
+                    index: item.index,
+                    span: item.span,
+                    rectangle: Rectangle::new(x, y, advance, line_height),
+                });
+                // Smaller characters share the bottom of the largest ones
+                tops.push(y + line.size - item.size);
+                x += advance;
+            }
+            let width = if extra > 0.0 {
+                self.bounds.width
+            } else {
+                width
+            };
+            layout_lines.push(LayoutLine {
+                rectangle: Rectangle::new(line_x, y, width, line_height),
+                glyphs: start..glyphs.len(),
+            });
+            y += line_height;
+        }
+        (glyphs, tops, layout_lines, truncated)
+    }
+
+    /// Splits characters into lines, at line breaks and, when wrapping, at the bounds width.
+    fn break_lines(&self, items: &[Item]) -> Vec<ItemLine> {
+        let mut lines = Vec::new();
+        let mut start = 0;
+        let mut width = 0.0;
+        let mut last_space = None;
+        let line = |range: Range<usize>, paragraph_end: bool, break_size: Option<f32>| {
+            let line_items = &items[range.clone()];
+            // Empty lines take the size of their line break, or of the previous character
+            let size = line_items
+                .iter()
+                .map(|item| item.size)
+                .fold(None, |size: Option<f32>, item| {
+                    Some(size.map_or(item, |size| size.max(item)))
+                })
+                .or(break_size)
+                .or_else(|| range.start.checked_sub(1).map(|index| items[index].size))
+                .unwrap_or(0.0);
+            ItemLine {
+                items: line_items.to_vec(),
+                size,
+                paragraph_end,
+            }
+        };
+        for (index, item) in items.iter().enumerate() {
+            if item.char == '\n' {
+                lines.push(line(start..index, true, Some(item.size)));
+                start = index + 1;
+                width = 0.0;
+                last_space = None;
+                continue;
+            }
+            width += item.advance + self.spacing;
+            // Trailing spaces may overflow
+            if self.wrap
+                && item.char != ' '
+                && width - self.spacing > self.bounds.width
+                && index > start
+            {
+                let end = last_space.map_or(index, |space| space + 1);
+                lines.push(line(start..end, false, None));
+                start = end;
+                width = items[start..=index]
+                    .iter()
+                    .map(|item| item.advance + self.spacing)
+                    .sum();
+                last_space = None;
+            }
+            if item.char == ' ' {
+                last_space = Some(index);
+            }
+        }
+        lines.push(line(start..items.len(), true, None));
+        lines
+    }
+
+    /// Removes the last characters of a line until it fits with an ellipsis, then appends it.
+    ///
+    /// `next` is the index of the first hidden character, given to the ellipsis glyphs.
+    fn truncate(
+        &self,
+        items: &mut Vec<Item>,
+        next: usize,
+        ellipsis: &dyn Fn(usize) -> Vec<Item>,
+        hidden: bool,
+    ) {
+        let span = items.last().map_or(0, |item| item.span);
+        let mut dots = ellipsis(span);
+        let mut next = next;
+        loop {
+            items.truncate(visible_length(items));
+            let mut line = items.clone();
+            line.extend_from_slice(&dots);
+            if (hidden && items.is_empty()) || self.width(&line) <= self.bounds.width {
+                break;
+            }
+            match items.pop() {
+                Some(item) => next = item.index,
+                None => break,
+            }
+        }
+        for dot in &mut dots {
+            dot.index = next;
+        }
+        items.extend(dots);
+    }
+
+    /// Returns the width of a line, without trailing spaces.
+    fn width(&self, items: &[Item]) -> f32 {
+        let visible = &items[..visible_length(items)];
+        visible.iter().map(|item| item.advance).sum::<f32>()
+            + self.spacing * visible.len().saturating_sub(1) as f32
+    }
+}
+
+/// Returns the advance of a character from the advance of the font glyphs: tabulations are as
+/// wide as `TAB_SIZE` spaces, other control characters (e.g. line breaks) take no room.
+fn char_advance(char: char, glyph_advance: impl Fn(char) -> f32) -> f32 {
+    match char {
+        '\t' => glyph_advance(' ') * TAB_SIZE,
+        char if char.is_control() => 0.0,
+        char => glyph_advance(char),
+    }
+}
+
+/// Returns the number of characters of a line without trailing spaces.
+fn visible_length(items: &[Item]) -> usize {
+    items
+        .iter()
+        .rposition(|item| item.char != ' ')
+        .map_or(0, |index| index + 1)
+}
+
+/// Glyph placed by a text layout.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LayoutGlyph {
+    pub char: char,
+    /// Index of the character in the text of all spans, in characters (for ellipsis glyphs, index
+    /// of the first hidden character).
+    pub index: usize,
+    /// Index of the span.
+    pub span: usize,
+    /// Area of the character on its line, up to the next character.
+    pub rectangle: Rectangle,
+}
+
+/// Line placed by a text layout.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LayoutLine {
+    /// Area of the line, without trailing spaces.
+    pub rectangle: Rectangle,
+    /// Range of the line glyphs.
+    pub glyphs: Range<usize>,
+}
+
+/// Text laid out by a `TextLayout`.
+#[derive(Clone)]
+pub struct TextBlock<'a> {
+    spans: Vec<Span<'a>>,
+    glyphs: Vec<LayoutGlyph>,
+    tops: Vec<f32>,
+    lines: Vec<LayoutLine>,
+    truncated: bool,
+}
+
+impl<'a> TextBlock<'a> {
+    /// Returns the placed glyphs, line breaks excluded.
+    pub fn glyphs(&self) -> &[LayoutGlyph] {
+        &self.glyphs
+    }
+
+    /// Returns the placed lines.
+    pub fn lines(&self) -> &[LayoutLine] {
+        &self.lines
+    }
+
+    /// Returns the area covered by the lines.
+    pub fn bounds(&self) -> Rectangle {
+        let mut lines = self.lines.iter().map(|line| line.rectangle);
+        let first = lines.next().unwrap_or_default();
+        lines.fold(first, |bounds, line| {
+            let x = bounds.x.min(line.x);
+            let y = bounds.y.min(line.y);
+            Rectangle::new(
+                x,
+                y,
+                (bounds.x + bounds.width).max(line.x + line.width) - x,
+                (bounds.y + bounds.height).max(line.y + line.height) - y,
+            )
+        })
+    }
+
+    /// Checks if the text was truncated with an ellipsis.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Returns the index of the character at a point, if any.
+    pub fn hit_test(&self, point: impl Into<Vector2>) -> Option<usize> {
+        let point = point.into();
+        let contains = |rectangle: &Rectangle| {
+            point.x >= rectangle.x
+                && point.x < rectangle.x + rectangle.width
+                && point.y >= rectangle.y
+                && point.y < rectangle.y + rectangle.height
+        };
+        self.glyphs
+            .iter()
+            .find(|glyph| contains(&glyph.rectangle))
+            .map(|glyph| glyph.index)
+    }
+}
+
+/// Text layout.
+impl Canvas {
+    /// Draws laid out text.
+    ///
+    /// SDF fonts are drawn with their shader, the custom shader mode being restored afterwards.
+    pub fn draw_text_block(&mut self, block: &TextBlock) {
+        let mut is_sdf_mode = false;
+        for (glyph, &top) in block.glyphs.iter().zip(&block.tops) {
+            if glyph.char == ' ' || glyph.char.is_control() {
+                continue;
+            }
+            let span = &block.spans[glyph.span];
+            let font = span.font;
+            let index = match font.glyph_index(glyph.char) {
+                Some(index) => index,
+                None => continue,
+            };
+            let info = &font.chars_info()[index];
+            let source = font.chars_rectangles()[index];
+            let scale = span.size / font.base_size() as f32;
+            let destination = Rectangle::new(
+                glyph.rectangle.x + info.offsetX as f32 * scale,
+                top + info.offsetY as f32 * scale,
+                source.width * scale,
+                source.height * scale,
+            );
+            // SDF fonts share the shader of the window
+            match &font.sdf_shader {
+                Some(shader) if !is_sdf_mode => unsafe { ffi::BeginShaderMode(shader.raw) },
+                None if is_sdf_mode => self.restore_shader_mode(),
+                _ => {}
+            }
+            is_sdf_mode = font.sdf_shader.is_some();
+            unsafe {
+                ffi::DrawTexturePro(
+                    font.raw.texture,
+                    source.into(),
+                    destination.into(),
+                    Vector2::ZERO.into(),
+                    0.0,
+                    span.color.into(),
+                );
+            }
+        }
+        if is_sdf_mode {
+            self.restore_shader_mode();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        layout::{char_advance, HorizontalAlignment, Item, TextLayout, VerticalAlignment},
+        math::Rectangle,
+    };
+
+    /// Returns characters 10 pixels wide and 10 pixels high.
+    fn items(text: &str) -> Vec<Item> {
+        text.chars()
+            .enumerate()
+            .map(|(index, char)| Item {
+                char,
+                index,
+                span: 0,
+                size: 10.0,
+                advance: 10.0,
+            })
+            .collect()
+    }
+
+    fn ellipsis(span: usize) -> Vec<Item> {
+        vec![Item {
+            char: '~',
+            index: 0,
+            span,
+            size: 10.0,
+            advance: 10.0,
+        }]
+    }
+
+    fn lines(layout: &TextLayout, text: &str) -> Vec<String> {
+        let (glyphs, _, lines, _) = layout.layout(&items(text), &ellipsis);
+        lines
+            .iter()
+            .map(|line| {
+                glyphs[line.glyphs.clone()]
+                    .iter()
+                    .map(|glyph| glyph.char)
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_wrap() {
+        let layout = TextLayout::new(Rectangle::new(0.0, 0.0, 50.0, 100.0));
+        assert_eq!(lines(&layout, "ab cd ef\ngh"), vec!["ab cd ", "ef", "gh"]);
+        assert_eq!(lines(&layout, "abcdefgh"), vec!["abcde", "fgh"]);
+        assert_eq!(lines(&layout.wrap(false), "abcdefgh"), vec!["abcdefgh"]);
+    }
+
+    #[test]
+    fn test_alignment() {
+        let layout = TextLayout::new(Rectangle::new(0.0, 0.0, 100.0, 100.0))
+            .alignment(HorizontalAlignment::Right)
+            .vertical_alignment(VerticalAlignment::Bottom)
+            .line_height(1.0);
+        let (glyphs, _, lines, _) = layout.layout(&items("ab"), &ellipsis);
+        assert_eq!(lines[0].rectangle, Rectangle::new(80.0, 90.0, 20.0, 10.0));
+        assert_eq!(glyphs[1].rectangle, Rectangle::new(90.0, 90.0, 10.0, 10.0));
+        let layout = TextLayout::new(Rectangle::new(0.0, 0.0, 60.0, 100.0))
+            .alignment(HorizontalAlignment::Justify);
+        let (glyphs, _, _, _) = layout.layout(&items("a b c d e"), &ellipsis);
+        // "a b c " is stretched: 2 spaces share 10 extra pixels
+        assert_eq!(glyphs[2].rectangle.x, 25.0);
+        assert_eq!(glyphs[4].rectangle.x, 50.0);
+        assert_eq!(glyphs[6].rectangle.x, 0.0);
+    }
+
+    #[test]
+    fn test_ellipsis() {
+        let layout = TextLayout::new(Rectangle::new(0.0, 0.0, 60.0, 15.0))
+            .line_height(1.0)
+            .ellipsis(true);
+        let (glyphs, _, lines, truncated) = layout.layout(&items("ab cd ef"), &ellipsis);
+        assert!(truncated);
+        assert_eq!(lines.len(), 1);
+        let text: String = glyphs.iter().map(|glyph| glyph.char).collect();
+        assert_eq!(text, "ab cd~");
+        assert_eq!(glyphs[5].index, 6);
+        let layout = layout.wrap(false);
+        let (glyphs, _, _, _) = layout.layout(&items("abcdefgh"), &ellipsis);
+        let text: String = glyphs.iter().map(|glyph| glyph.char).collect();
+        assert_eq!(text, "abcde~");
+        assert_eq!(glyphs[5].index, 5);
+    }
+
+    #[test]
+    fn test_char_advance() {
+        let glyph_advance = |char| if char == ' ' { 5.0 } else { 10.0 };
+        assert_eq!(char_advance('a', glyph_advance), 10.0);
+        assert_eq!(char_advance('\t', glyph_advance), 20.0);
+        assert_eq!(char_advance('\n', glyph_advance), 0.0);
+        assert_eq!(char_advance('\u{7}', glyph_advance), 0.0);
+    }
+}
@@ -7,6 +7,12 @@
 //! - Multi-platform: `Windows`, `Linux` and `macOS` (tested via CI for the 3 platforms).
 //! - Idiomatic: `Rust` concepts overuse, safe API, follows `Rust` API & style guidelines.
 //!
+//! # Headless rendering
+//!
+//! The `headless` feature (Linux only) builds raylib against the OSMesa software renderer instead
+//! of a windowing system. It is not additive: once enabled anywhere in the dependency graph, no
+//! window of the program can be shown (see `WindowBuilder::headless`).
+//!
 //! # Example
 //!
 //! ```rust,no_run
@@ -25,21 +31,34 @@
 //! ```
 
 // Internal modules
+mod bmfont;
 mod cursor;
 mod ffi;
+mod gif;
 mod shapes;
+mod shapes_3d;
 mod timing;
 
 // Public modules
+pub mod action;
+pub mod asset;
+pub mod audio;
 pub mod camera;
+pub mod capture;
 pub mod collision;
 pub mod color;
 pub mod drawing;
 pub mod error;
+pub mod image;
 pub mod input;
+pub mod layout;
 pub mod math;
 pub mod misc;
+pub mod model;
+pub mod recording;
 pub mod result;
+pub mod shader;
+pub mod sprite;
 pub mod text;
 pub mod texture;
 pub mod window;
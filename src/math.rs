@@ -64,6 +64,9 @@ macro_rules! vector {
     ($x:expr, $y: expr, $z: expr) => {
         Vector3::new($x, $y, $z)
     };
+    ($x:expr, $y: expr, $z: expr, $w: expr) => {
+        Vector4::new($x, $y, $z, $w)
+    };
 }
 
 /// Vector2 type.
@@ -371,7 +374,7 @@ impl Vector3 {
             length = 1.0;
         }
         let length = 1.0 / length;
-        vector!(self.x * length, self.x * length, self.x * length)
+        vector!(self.x * length, self.y * length, self.z * length)
     }
 
     /// Returns a new `Vector3` with components linearly interpolated by `amount` towards `vector`.
@@ -622,6 +625,692 @@ impl SubAssign<f32> for Vector3 {
     }
 }
 
+/// Vector4 type.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Vector4 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Vector4 {
+    pub const ZERO: Vector4 = vector!(0.0, 0.0, 0.0, 0.0);
+    pub const ONE: Vector4 = vector!(1.0, 1.0, 1.0, 1.0);
+
+    /// Creates a `Vector4`.
+    #[inline]
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Vector4 {
+        Vector4 { x, y, z, w }
+    }
+
+    /// Calculates the vector length.
+    pub fn length(self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    /// Calculates the dot product with `vector`.
+    pub fn dot(self, vector: Vector4) -> f32 {
+        self.x * vector.x + self.y * vector.y + self.z * vector.z + self.w * vector.w
+    }
+
+    /// Returns a new `Vector4` with components linearly interpolated by `amount` towards `vector`.
+    pub fn lerp(self, vector: Vector4, amount: f32) -> Vector4 {
+        vector!(
+            self.x + amount * (vector.x - self.x),
+            self.y + amount * (vector.y - self.y),
+            self.z + amount * (vector.z - self.z),
+            self.w + amount * (vector.w - self.w)
+        )
+    }
+}
+
+impl From<&Vector4> for Vector4 {
+    #[inline]
+    fn from(vector: &Vector4) -> Vector4 {
+        *vector
+    }
+}
+
+impl From<[f32; 4]> for Vector4 {
+    #[inline]
+    fn from(value: [f32; 4]) -> Vector4 {
+        vector!(value[0], value[1], value[2], value[3])
+    }
+}
+
+impl From<(f32, f32, f32, f32)> for Vector4 {
+    #[inline]
+    fn from((x, y, z, w): (f32, f32, f32, f32)) -> Vector4 {
+        vector!(x, y, z, w)
+    }
+}
+
+impl From<ffi::Vector4> for Vector4 {
+    #[inline]
+    fn from(vector: ffi::Vector4) -> Vector4 {
+        unsafe { mem::transmute(vector) }
+    }
+}
+
+impl Into<[f32; 4]> for Vector4 {
+    #[inline]
+    fn into(self) -> [f32; 4] {
+        [self.x, self.y, self.z, self.w]
+    }
+}
+
+impl Into<(f32, f32, f32, f32)> for Vector4 {
+    #[inline]
+    fn into(self) -> (f32, f32, f32, f32) {
+        (self.x, self.y, self.z, self.w)
+    }
+}
+
+impl Into<ffi::Vector4> for Vector4 {
+    #[inline]
+    fn into(self) -> ffi::Vector4 {
+        unsafe { mem::transmute(self) }
+    }
+}
+
+/// 4x4 matrix type, following the OpenGL conventions.
+///
+/// Fields are named after their column-major index: `m0`, `m1`, `m2` and `m3` form the first
+/// column, `m12`, `m13` and `m14` the translation. Like raylib's, they are laid out in memory row by
+/// row (`m0`, `m4`, `m8`, `m12`, `m1`…). Vectors are columns: `a * b` applies `b` and then `a`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Matrix {
+    pub m0: f32,
+    pub m4: f32,
+    pub m8: f32,
+    pub m12: f32,
+    pub m1: f32,
+    pub m5: f32,
+    pub m9: f32,
+    pub m13: f32,
+    pub m2: f32,
+    pub m6: f32,
+    pub m10: f32,
+    pub m14: f32,
+    pub m3: f32,
+    pub m7: f32,
+    pub m11: f32,
+    pub m15: f32,
+}
+
+impl Matrix {
+    pub const ZERO: Matrix = Matrix::from_rows([
+        [0.0, 0.0, 0.0, 0.0],
+        [0.0, 0.0, 0.0, 0.0],
+        [0.0, 0.0, 0.0, 0.0],
+        [0.0, 0.0, 0.0, 0.0],
+    ]);
+    pub const IDENTITY: Matrix = Matrix::from_rows([
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+
+    /// Creates a `Matrix` from its rows.
+    #[inline]
+    pub const fn from_rows(rows: [[f32; 4]; 4]) -> Matrix {
+        Matrix {
+            m0: rows[0][0],
+            m4: rows[0][1],
+            m8: rows[0][2],
+            m12: rows[0][3],
+            m1: rows[1][0],
+            m5: rows[1][1],
+            m9: rows[1][2],
+            m13: rows[1][3],
+            m2: rows[2][0],
+            m6: rows[2][1],
+            m10: rows[2][2],
+            m14: rows[2][3],
+            m3: rows[3][0],
+            m7: rows[3][1],
+            m11: rows[3][2],
+            m15: rows[3][3],
+        }
+    }
+
+    /// Returns the rows of the matrix.
+    pub fn rows(&self) -> [[f32; 4]; 4] {
+        [
+            [self.m0, self.m4, self.m8, self.m12],
+            [self.m1, self.m5, self.m9, self.m13],
+            [self.m2, self.m6, self.m10, self.m14],
+            [self.m3, self.m7, self.m11, self.m15],
+        ]
+    }
+
+    /// Creates a translation matrix.
+    pub fn translate(x: f32, y: f32, z: f32) -> Matrix {
+        Matrix::from_rows([
+            [1.0, 0.0, 0.0, x],
+            [0.0, 1.0, 0.0, y],
+            [0.0, 0.0, 1.0, z],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Creates a rotation matrix around `axis` by `angle` in radians.
+    pub fn rotate(axis: Vector3, angle: f32) -> Matrix {
+        let Vector3 { x, y, z } = axis.normalized();
+        let (sin, cos) = angle.sin_cos();
+        let t = 1.0 - cos;
+        Matrix::from_rows([
+            [
+                x * x * t + cos,
+                x * y * t - z * sin,
+                x * z * t + y * sin,
+                0.0,
+            ],
+            [
+                y * x * t + z * sin,
+                y * y * t + cos,
+                y * z * t - x * sin,
+                0.0,
+            ],
+            [
+                z * x * t - y * sin,
+                z * y * t + x * sin,
+                z * z * t + cos,
+                0.0,
+            ],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Creates a rotation matrix around the X axis by `angle` in radians.
+    pub fn rotate_x(angle: f32) -> Matrix {
+        let (sin, cos) = angle.sin_cos();
+        Matrix::from_rows([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, cos, -sin, 0.0],
+            [0.0, sin, cos, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Creates a rotation matrix around the Y axis by `angle` in radians.
+    pub fn rotate_y(angle: f32) -> Matrix {
+        let (sin, cos) = angle.sin_cos();
+        Matrix::from_rows([
+            [cos, 0.0, sin, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [-sin, 0.0, cos, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Creates a rotation matrix around the Z axis by `angle` in radians.
+    pub fn rotate_z(angle: f32) -> Matrix {
+        let (sin, cos) = angle.sin_cos();
+        Matrix::from_rows([
+            [cos, -sin, 0.0, 0.0],
+            [sin, cos, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Creates a scaling matrix.
+    pub fn scale(x: f32, y: f32, z: f32) -> Matrix {
+        Matrix::from_rows([
+            [x, 0.0, 0.0, 0.0],
+            [0.0, y, 0.0, 0.0],
+            [0.0, 0.0, z, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Creates a perspective projection matrix, `fovy` being the vertical field of view in
+    /// radians.
+    pub fn perspective(fovy: f32, aspect: f32, near: f32, far: f32) -> Matrix {
+        let top = near * (fovy / 2.0).tan();
+        let right = top * aspect;
+        Matrix::frustum(-right, right, -top, top, near, far)
+    }
+
+    /// Creates a perspective projection matrix from the near plane limits.
+    pub fn frustum(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Matrix {
+        let width = right - left;
+        let height = top - bottom;
+        let depth = far - near;
+        Matrix::from_rows([
+            [2.0 * near / width, 0.0, (right + left) / width, 0.0],
+            [0.0, 2.0 * near / height, (top + bottom) / height, 0.0],
+            [0.0, 0.0, -(far + near) / depth, -2.0 * far * near / depth],
+            [0.0, 0.0, -1.0, 0.0],
+        ])
+    }
+
+    /// Creates an orthographic projection matrix.
+    pub fn ortho(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Matrix {
+        let width = right - left;
+        let height = top - bottom;
+        let depth = far - near;
+        Matrix::from_rows([
+            [2.0 / width, 0.0, 0.0, -(right + left) / width],
+            [0.0, 2.0 / height, 0.0, -(top + bottom) / height],
+            [0.0, 0.0, -2.0 / depth, -(far + near) / depth],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Creates a view matrix looking from `eye` towards `target`.
+    pub fn look_at(eye: Vector3, target: Vector3, up: Vector3) -> Matrix {
+        let z = (eye - target).normalized();
+        let x = up.cross(z).normalized();
+        let y = z.cross(x);
+        Matrix::from_rows([
+            [x.x, x.y, x.z, -x.dot(eye)],
+            [y.x, y.y, y.z, -y.dot(eye)],
+            [z.x, z.y, z.z, -z.dot(eye)],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Calculates the 2x2 sub-determinants shared by the determinant and the inverse.
+    fn sub_determinants(values: &[f32; 16]) -> [f32; 12] {
+        let [a00, a01, a02, a03, a10, a11, a12, a13, a20, a21, a22, a23, a30, a31, a32, a33] =
+            *values;
+        [
+            a00 * a11 - a01 * a10,
+            a00 * a12 - a02 * a10,
+            a00 * a13 - a03 * a10,
+            a01 * a12 - a02 * a11,
+            a01 * a13 - a03 * a11,
+            a02 * a13 - a03 * a12,
+            a20 * a31 - a21 * a30,
+            a20 * a32 - a22 * a30,
+            a20 * a33 - a23 * a30,
+            a21 * a32 - a22 * a31,
+            a21 * a33 - a23 * a31,
+            a22 * a33 - a23 * a32,
+        ]
+    }
+
+    /// Calculates the determinant.
+    pub fn determinant(&self) -> f32 {
+        let [b00, b01, b02, b03, b04, b05, b06, b07, b08, b09, b10, b11] =
+            Matrix::sub_determinants(&(*self).into());
+        b00 * b11 - b01 * b10 + b02 * b09 + b03 * b08 - b04 * b07 + b05 * b06
+    }
+
+    /// Returns the inverse matrix, or `None` if the matrix isn't invertible.
+    pub fn inverted(self) -> Option<Matrix> {
+        let values: [f32; 16] = self.into();
+        let [a00, a01, a02, a03, a10, a11, a12, a13, a20, a21, a22, a23, a30, a31, a32, a33] =
+            values;
+        let [b00, b01, b02, b03, b04, b05, b06, b07, b08, b09, b10, b11] =
+            Matrix::sub_determinants(&values);
+        let determinant = b00 * b11 - b01 * b10 + b02 * b09 + b03 * b08 - b04 * b07 + b05 * b06;
+        if determinant == 0.0 {
+            return None;
+        }
+        let inverse = 1.0 / determinant;
+        Some(Matrix::from([
+            (a11 * b11 - a12 * b10 + a13 * b09) * inverse,
+            (-a01 * b11 + a02 * b10 - a03 * b09) * inverse,
+            (a31 * b05 - a32 * b04 + a33 * b03) * inverse,
+            (-a21 * b05 + a22 * b04 - a23 * b03) * inverse,
+            (-a10 * b11 + a12 * b08 - a13 * b07) * inverse,
+            (a00 * b11 - a02 * b08 + a03 * b07) * inverse,
+            (-a30 * b05 + a32 * b02 - a33 * b01) * inverse,
+            (a20 * b05 - a22 * b02 + a23 * b01) * inverse,
+            (a10 * b10 - a11 * b08 + a13 * b06) * inverse,
+            (-a00 * b10 + a01 * b08 - a03 * b06) * inverse,
+            (a30 * b04 - a31 * b02 + a33 * b00) * inverse,
+            (-a20 * b04 + a21 * b02 - a23 * b00) * inverse,
+            (-a10 * b09 + a11 * b07 - a12 * b06) * inverse,
+            (a00 * b09 - a01 * b07 + a02 * b06) * inverse,
+            (-a30 * b03 + a31 * b01 - a32 * b00) * inverse,
+            (a20 * b03 - a21 * b01 + a22 * b00) * inverse,
+        ]))
+    }
+
+    /// Transposes the matrix.
+    pub fn transpose(&mut self) {
+        *self = self.transposed();
+    }
+
+    /// Returns a new `Matrix` transposed.
+    pub fn transposed(self) -> Matrix {
+        let rows = self.rows();
+        let mut columns = [[0.0; 4]; 4];
+        for (row, values) in rows.iter().enumerate() {
+            for (column, value) in values.iter().enumerate() {
+                columns[column][row] = *value;
+            }
+        }
+        Matrix::from_rows(columns)
+    }
+}
+
+impl From<&Matrix> for Matrix {
+    #[inline]
+    fn from(matrix: &Matrix) -> Matrix {
+        *matrix
+    }
+}
+
+impl From<[f32; 16]> for Matrix {
+    /// Creates a `Matrix` from values in column-major order.
+    #[inline]
+    fn from(value: [f32; 16]) -> Matrix {
+        Matrix::from_rows([
+            [value[0], value[4], value[8], value[12]],
+            [value[1], value[5], value[9], value[13]],
+            [value[2], value[6], value[10], value[14]],
+            [value[3], value[7], value[11], value[15]],
+        ])
+    }
+}
+
+impl From<ffi::Matrix> for Matrix {
+    #[inline]
+    fn from(matrix: ffi::Matrix) -> Matrix {
+        unsafe { mem::transmute(matrix) }
+    }
+}
+
+impl Into<[f32; 16]> for Matrix {
+    /// Returns the values in column-major order.
+    #[inline]
+    fn into(self) -> [f32; 16] {
+        [
+            self.m0, self.m1, self.m2, self.m3, self.m4, self.m5, self.m6, self.m7, self.m8,
+            self.m9, self.m10, self.m11, self.m12, self.m13, self.m14, self.m15,
+        ]
+    }
+}
+
+impl Into<ffi::Matrix> for Matrix {
+    #[inline]
+    fn into(self) -> ffi::Matrix {
+        unsafe { mem::transmute(self) }
+    }
+}
+
+impl Mul for Matrix {
+    type Output = Matrix;
+    fn mul(self, matrix: Matrix) -> Matrix {
+        let left = self.rows();
+        let right = matrix.rows();
+        let mut result = [[0.0; 4]; 4];
+        for (row, values) in result.iter_mut().enumerate() {
+            for (column, value) in values.iter_mut().enumerate() {
+                *value = (0..4)
+                    .map(|index| left[row][index] * right[index][column])
+                    .sum();
+            }
+        }
+        Matrix::from_rows(result)
+    }
+}
+
+impl Mul<Vector3> for Matrix {
+    type Output = Vector3;
+    /// Transforms a point.
+    fn mul(self, vector: Vector3) -> Vector3 {
+        vector!(
+            self.m0 * vector.x + self.m4 * vector.y + self.m8 * vector.z + self.m12,
+            self.m1 * vector.x + self.m5 * vector.y + self.m9 * vector.z + self.m13,
+            self.m2 * vector.x + self.m6 * vector.y + self.m10 * vector.z + self.m14
+        )
+    }
+}
+
+impl MulAssign for Matrix {
+    fn mul_assign(&mut self, matrix: Matrix) {
+        *self = *self * matrix;
+    }
+}
+
+/// Quaternion type, representing a rotation.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quaternion {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Quaternion {
+    pub const IDENTITY: Quaternion = Quaternion::new(0.0, 0.0, 0.0, 1.0);
+
+    /// Creates a `Quaternion`.
+    #[inline]
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Quaternion {
+        Quaternion { x, y, z, w }
+    }
+
+    /// Creates a rotation around `axis` by `angle` in radians.
+    pub fn from_axis_angle(axis: Vector3, angle: f32) -> Quaternion {
+        let axis = axis.normalized();
+        let (sin, cos) = (angle / 2.0).sin_cos();
+        Quaternion::new(axis.x * sin, axis.y * sin, axis.z * sin, cos)
+    }
+
+    /// Creates a rotation from angles in radians around the X, Y and Z axes, applied in this
+    /// order.
+    pub fn from_euler(x: f32, y: f32, z: f32) -> Quaternion {
+        let (x1, x0) = (x / 2.0).sin_cos();
+        let (y1, y0) = (y / 2.0).sin_cos();
+        let (z1, z0) = (z / 2.0).sin_cos();
+        Quaternion::new(
+            x1 * y0 * z0 - x0 * y1 * z1,
+            x0 * y1 * z0 + x1 * y0 * z1,
+            x0 * y0 * z1 - x1 * y1 * z0,
+            x0 * y0 * z0 + x1 * y1 * z1,
+        )
+    }
+
+    /// Calculates the quaternion length.
+    pub fn length(self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    /// Calculates the dot product with `quaternion`.
+    pub fn dot(self, quaternion: Quaternion) -> f32 {
+        self.x * quaternion.x
+            + self.y * quaternion.y
+            + self.z * quaternion.z
+            + self.w * quaternion.w
+    }
+
+    /// Normalizes the quaternion.
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    /// Returns a new `Quaternion` with normalized components.
+    pub fn normalized(self) -> Quaternion {
+        let mut length = self.length();
+        if length == 0.0 {
+            length = 1.0;
+        }
+        let length = 1.0 / length;
+        Quaternion::new(
+            self.x * length,
+            self.y * length,
+            self.z * length,
+            self.w * length,
+        )
+    }
+
+    /// Returns the inverse rotation.
+    pub fn inverted(self) -> Quaternion {
+        let length = self.dot(self);
+        if length == 0.0 {
+            return self;
+        }
+        let length = 1.0 / length;
+        Quaternion::new(
+            -self.x * length,
+            -self.y * length,
+            -self.z * length,
+            self.w * length,
+        )
+    }
+
+    /// Returns a new `Quaternion` spherically interpolated by `amount` towards `quaternion`.
+    pub fn slerp(self, quaternion: Quaternion, amount: f32) -> Quaternion {
+        let mut cos = self.dot(quaternion);
+        let mut target = quaternion;
+        // Takes the shortest path
+        if cos < 0.0 {
+            cos = -cos;
+            target = Quaternion::new(-target.x, -target.y, -target.z, -target.w);
+        }
+        let (from, to) = if cos > 0.9995 {
+            // Falls back to linear interpolation when the rotations are too close
+            (1.0 - amount, amount)
+        } else {
+            let angle = cos.acos();
+            let sin = angle.sin();
+            (
+                ((1.0 - amount) * angle).sin() / sin,
+                (amount * angle).sin() / sin,
+            )
+        };
+        Quaternion::new(
+            self.x * from + target.x * to,
+            self.y * from + target.y * to,
+            self.z * from + target.z * to,
+            self.w * from + target.w * to,
+        )
+        .normalized()
+    }
+
+    /// Returns the rotation matrix.
+    pub fn to_matrix(self) -> Matrix {
+        let Quaternion { x, y, z, w } = self.normalized();
+        Matrix::from_rows([
+            [
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y - z * w),
+                2.0 * (x * z + y * w),
+                0.0,
+            ],
+            [
+                2.0 * (x * y + z * w),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z - x * w),
+                0.0,
+            ],
+            [
+                2.0 * (x * z - y * w),
+                2.0 * (y * z + x * w),
+                1.0 - 2.0 * (x * x + y * y),
+                0.0,
+            ],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+}
+
+impl Default for Quaternion {
+    #[inline]
+    fn default() -> Quaternion {
+        Quaternion::IDENTITY
+    }
+}
+
+impl From<&Quaternion> for Quaternion {
+    #[inline]
+    fn from(quaternion: &Quaternion) -> Quaternion {
+        *quaternion
+    }
+}
+
+impl From<ffi::Quaternion> for Quaternion {
+    #[inline]
+    fn from(quaternion: ffi::Quaternion) -> Quaternion {
+        unsafe { mem::transmute(quaternion) }
+    }
+}
+
+impl Into<ffi::Quaternion> for Quaternion {
+    #[inline]
+    fn into(self) -> ffi::Quaternion {
+        unsafe { mem::transmute(self) }
+    }
+}
+
+impl Mul for Quaternion {
+    type Output = Quaternion;
+    /// Combines two rotations: `a * b` applies `b` and then `a`.
+    fn mul(self, quaternion: Quaternion) -> Quaternion {
+        let (a, b) = (self, quaternion);
+        Quaternion::new(
+            a.x * b.w + a.w * b.x + a.y * b.z - a.z * b.y,
+            a.y * b.w + a.w * b.y + a.z * b.x - a.x * b.z,
+            a.z * b.w + a.w * b.z + a.x * b.y - a.y * b.x,
+            a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+        )
+    }
+}
+
+impl Mul<Vector3> for Quaternion {
+    type Output = Vector3;
+    /// Rotates a vector.
+    fn mul(self, vector: Vector3) -> Vector3 {
+        let rotated = self * Quaternion::new(vector.x, vector.y, vector.z, 0.0) * self.inverted();
+        vector!(rotated.x, rotated.y, rotated.z)
+    }
+}
+
+impl MulAssign for Quaternion {
+    fn mul_assign(&mut self, quaternion: Quaternion) {
+        *self = *self * quaternion;
+    }
+}
+
+/// Bounding box type.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct BoundingBox {
+    pub min: Vector3,
+    pub max: Vector3,
+}
+
+impl BoundingBox {
+    /// Creates a `BoundingBox`.
+    #[inline]
+    pub const fn new(min: Vector3, max: Vector3) -> BoundingBox {
+        BoundingBox { min, max }
+    }
+}
+
+impl From<&BoundingBox> for BoundingBox {
+    #[inline]
+    fn from(bounding_box: &BoundingBox) -> BoundingBox {
+        *bounding_box
+    }
+}
+
+impl From<ffi::BoundingBox> for BoundingBox {
+    #[inline]
+    fn from(bounding_box: ffi::BoundingBox) -> BoundingBox {
+        unsafe { mem::transmute(bounding_box) }
+    }
+}
+
+impl Into<ffi::BoundingBox> for BoundingBox {
+    #[inline]
+    fn into(self) -> ffi::BoundingBox {
+        unsafe { mem::transmute(self) }
+    }
+}
+
 #[inline]
 /// Clamps a value between the given minimum and maximum values.
 pub fn clamp<T: PartialOrd>(value: T, min: T, max: T) -> T {
@@ -636,7 +1325,20 @@ pub fn clamp<T: PartialOrd>(value: T, min: T, max: T) -> T {
 
 #[cfg(test)]
 mod tests {
-    use crate::math::{clamp, Vector2, Vector3};
+    use crate::math::{clamp, BoundingBox, Matrix, Quaternion, Vector2, Vector3, Vector4};
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    fn assert_matrix_eq(left: Matrix, right: Matrix) {
+        let left: [f32; 16] = left.into();
+        let right: [f32; 16] = right.into();
+        for (left, right) in left.iter().zip(right.iter()) {
+            assert!((left - right).abs() < 1e-5, "{:?} != {:?}", left, right);
+        }
+    }
+
+    fn assert_vector3_eq(left: Vector3, right: Vector3) {
+        assert!((left - right).length() < 1e-5, "{:?} != {:?}", left, right);
+    }
 
     #[test]
     fn test_vector2_new() {
@@ -718,6 +1420,150 @@ mod tests {
         assert_eq!(value, (1.0, 1.0, 1.0));
     }
 
+    #[test]
+    fn test_vector3_normalized() {
+        assert_eq!(
+            Vector3::new(0.0, 3.0, 4.0).normalized(),
+            Vector3::new(0.0, 0.6, 0.8)
+        );
+        assert_eq!(Vector3::ZERO.normalized(), Vector3::ZERO);
+    }
+
+    #[test]
+    fn test_vector4_new() {
+        assert_eq!(Vector4::new(0.0, 0.0, 0.0, 0.0), Vector4::ZERO);
+        assert_eq!(Vector4::new(1.0, 1.0, 1.0, 1.0), Vector4::ONE);
+    }
+
+    #[test]
+    fn test_vector4_from_f32_array() {
+        assert_eq!(Vector4::from([0.0, 0.0, 0.0, 0.0]), Vector4::ZERO);
+        assert_eq!(Vector4::from([1.0, 1.0, 1.0, 1.0]), Vector4::ONE);
+    }
+
+    #[test]
+    fn test_vector4_into_f32_slice() {
+        let value: (f32, f32, f32, f32) = Vector4::ONE.into();
+        assert_eq!(value, (1.0, 1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn test_matrix_from_f32_array() {
+        let values = [
+            0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0,
+        ];
+        let matrix = Matrix::from(values);
+        assert_eq!(matrix.m1, 1.0);
+        assert_eq!(matrix.m12, 12.0);
+        assert_eq!(matrix.rows()[0], [0.0, 4.0, 8.0, 12.0]);
+        let value: [f32; 16] = matrix.into();
+        assert_eq!(value, values);
+    }
+
+    #[test]
+    fn test_matrix_mul() {
+        let matrix = Matrix::translate(1.0, 2.0, 3.0) * Matrix::scale(2.0, 2.0, 2.0);
+        assert_eq!(matrix * Vector3::ONE, Vector3::new(3.0, 4.0, 5.0));
+        assert_eq!(matrix * Matrix::IDENTITY, matrix);
+        assert_eq!(Matrix::IDENTITY * matrix, matrix);
+    }
+
+    #[test]
+    fn test_matrix_rotate() {
+        assert_matrix_eq(Matrix::rotate(Vector3::RIGHT, 1.0), Matrix::rotate_x(1.0));
+        assert_matrix_eq(Matrix::rotate(Vector3::UP, 1.0), Matrix::rotate_y(1.0));
+        assert_matrix_eq(Matrix::rotate(Vector3::FORWARD, 1.0), Matrix::rotate_z(1.0));
+        assert_vector3_eq(
+            Matrix::rotate_z(FRAC_PI_2) * Vector3::RIGHT,
+            Vector3::new(0.0, 1.0, 0.0),
+        );
+    }
+
+    #[test]
+    fn test_matrix_inverted() {
+        let matrix = Matrix::translate(1.0, 2.0, 3.0) * Matrix::rotate_y(0.5);
+        assert_matrix_eq(matrix * matrix.inverted().unwrap(), Matrix::IDENTITY);
+        assert_eq!(Matrix::ZERO.inverted(), None);
+    }
+
+    #[test]
+    fn test_matrix_transposed() {
+        let matrix = Matrix::translate(1.0, 2.0, 3.0);
+        assert_eq!(matrix.transposed().rows()[3], [1.0, 2.0, 3.0, 1.0]);
+        assert_eq!(matrix.transposed().transposed(), matrix);
+    }
+
+    #[test]
+    fn test_matrix_look_at() {
+        let eye = Vector3::new(1.0, 2.0, 3.0);
+        let view = Matrix::look_at(eye, Vector3::ZERO, Vector3::UP);
+        assert_vector3_eq(view * eye, Vector3::ZERO);
+        assert_vector3_eq(view * Vector3::ZERO, Vector3::new(0.0, 0.0, -eye.length()));
+    }
+
+    #[test]
+    fn test_matrix_projections() {
+        let perspective = Matrix::perspective(FRAC_PI_2, 1.0, 1.0, 10.0);
+        assert_eq!(perspective.rows()[3], [0.0, 0.0, -1.0, 0.0]);
+        assert_vector3_eq(
+            perspective * Vector3::new(0.0, 0.0, -1.0),
+            Vector3::new(0.0, 0.0, -1.0),
+        );
+        let ortho = Matrix::ortho(0.0, 800.0, 450.0, 0.0, -1.0, 1.0);
+        assert_vector3_eq(
+            ortho * Vector3::new(800.0, 0.0, 0.0),
+            Vector3::new(1.0, 1.0, 0.0),
+        );
+    }
+
+    #[test]
+    fn test_quaternion_to_matrix() {
+        let axis = Vector3::new(1.0, 2.0, 3.0);
+        assert_matrix_eq(
+            Quaternion::from_axis_angle(axis, 0.5).to_matrix(),
+            Matrix::rotate(axis, 0.5),
+        );
+        assert_matrix_eq(Quaternion::IDENTITY.to_matrix(), Matrix::IDENTITY);
+    }
+
+    #[test]
+    fn test_quaternion_from_euler() {
+        assert_matrix_eq(
+            Quaternion::from_euler(0.1, 0.2, 0.3).to_matrix(),
+            Matrix::rotate_z(0.3) * Matrix::rotate_y(0.2) * Matrix::rotate_x(0.1),
+        );
+    }
+
+    #[test]
+    fn test_quaternion_mul() {
+        let x = Quaternion::from_axis_angle(Vector3::RIGHT, 0.5);
+        let y = Quaternion::from_axis_angle(Vector3::UP, 0.7);
+        assert_matrix_eq((x * y).to_matrix(), x.to_matrix() * y.to_matrix());
+        assert_vector3_eq(
+            Quaternion::from_axis_angle(Vector3::UP, PI) * Vector3::RIGHT,
+            Vector3::LEFT,
+        );
+    }
+
+    #[test]
+    fn test_quaternion_slerp() {
+        let from = Quaternion::IDENTITY;
+        let to = Quaternion::from_axis_angle(Vector3::UP, FRAC_PI_2);
+        assert_eq!(from.slerp(to, 0.0), from);
+        assert_matrix_eq(from.slerp(to, 1.0).to_matrix(), to.to_matrix());
+        assert_matrix_eq(
+            from.slerp(to, 0.5).to_matrix(),
+            Quaternion::from_axis_angle(Vector3::UP, FRAC_PI_2 / 2.0).to_matrix(),
+        );
+    }
+
+    #[test]
+    fn test_bounding_box_new() {
+        let bounding_box = BoundingBox::new(Vector3::ZERO, Vector3::ONE);
+        assert_eq!(bounding_box.min, Vector3::ZERO);
+        assert_eq!(bounding_box.max, Vector3::ONE);
+    }
+
     #[test]
     fn test_clamp() {
         assert_eq!(clamp(200, 100, 300), 200);
@@ -0,0 +1,703 @@
+//! Model management.
+//!
+//! Supported file formats are selected with Cargo features: `obj` (with `mtl` materials), `iqm`
+//! and `gltf` (`.gltf` and `.glb`). Skeletal animations are loaded from IQM and glTF files, glTF
+//! animations being sampled at 60 frames per second.
+
+use crate::{
+    asset::{file_type, AssetHandle, ExtractedAsset},
+    color::Color,
+    drawing::Canvas,
+    ffi,
+    image::Image,
+    math::{BoundingBox, Matrix, Vector3},
+    shader::Shader,
+    texture::Texture,
+    window::{Handle, Window},
+};
+use std::{ffi::CString, fmt, path::Path, ptr, rc::Rc, slice};
+
+/// Kinds of model loading errors.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LoadModelError {
+    ReadFailed,
+    InvalidFormat,
+    UnsupportedFormat,
+}
+
+impl fmt::Display for LoadModelError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{:?}", self)
+    }
+}
+
+impl std::error::Error for LoadModelError {}
+
+/// Checks that the file format is enabled through Cargo features.
+fn is_format_supported(path: &Path, animation: bool) -> bool {
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_lowercase);
+    match extension.as_deref() {
+        Some("obj") => !animation && cfg!(feature = "obj"),
+        Some("iqm") => cfg!(feature = "iqm"),
+        Some("gltf") | Some("glb") => cfg!(feature = "gltf"),
+        _ => false,
+    }
+}
+
+/// Vertex data defining a mesh, stored in CPU and GPU memory.
+pub struct Mesh {
+    pub(crate) _handle: Rc<Handle>,
+    pub(crate) raw: ffi::Mesh,
+}
+
+impl Mesh {
+    /// Returns the number of vertices.
+    pub fn vertex_count(&self) -> i32 {
+        self.raw.vertexCount
+    }
+
+    /// Returns the number of triangles.
+    pub fn triangle_count(&self) -> i32 {
+        self.raw.triangleCount
+    }
+
+    /// Computes the mesh bounding box limits.
+    pub fn bounding_box(&self) -> BoundingBox {
+        unsafe { ffi::MeshBoundingBox(self.raw).into() }
+    }
+
+    /// Computes the mesh tangents.
+    pub fn compute_tangents(&mut self) {
+        unsafe {
+            ffi::MeshTangents(&mut self.raw);
+        }
+    }
+
+    /// Computes the mesh binormals.
+    pub fn compute_binormals(&mut self) {
+        unsafe {
+            ffi::MeshBinormals(&mut self.raw);
+        }
+    }
+}
+
+impl Drop for Mesh {
+    fn drop(&mut self) {
+        // The mesh data is owned by a model when the mesh has been moved into it
+        if !self.raw.vboId.is_null() {
+            unsafe {
+                ffi::UnloadMesh(self.raw);
+            }
+        }
+    }
+}
+
+/// Kinds of material maps.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MaterialMap {
+    Albedo = ffi::MAP_ALBEDO,
+    Metalness = ffi::MAP_METALNESS,
+    Normal = ffi::MAP_NORMAL,
+    Roughness = ffi::MAP_ROUGHNESS,
+    Occlusion = ffi::MAP_OCCLUSION,
+    Emission = ffi::MAP_EMISSION,
+    Height = ffi::MAP_HEIGHT,
+    Cubemap = ffi::MAP_CUBEMAP,
+    Irradiance = ffi::MAP_IRRADIANCE,
+    Prefilter = ffi::MAP_PREFILTER,
+    Brdf = ffi::MAP_BRDF,
+}
+
+impl MaterialMap {
+    pub const DIFFUSE: MaterialMap = MaterialMap::Albedo;
+    pub const SPECULAR: MaterialMap = MaterialMap::Metalness;
+}
+
+/// Material of a model: a shader and its texture maps.
+///
+/// Textures and shaders are assigned through the model (see `Model::set_material_texture` and
+/// `Model::set_material_shader`), which keeps them alive.
+#[repr(transparent)]
+pub struct Material {
+    raw: ffi::Material,
+}
+
+impl Material {
+    /// Returns the data of a material map.
+    fn map(&self, map: MaterialMap) -> &ffi::MaterialMap {
+        unsafe { &*self.raw.maps.add(map as usize) }
+    }
+
+    /// Returns the mutable data of a material map.
+    fn map_mut(&mut self, map: MaterialMap) -> &mut ffi::MaterialMap {
+        unsafe { &mut *self.raw.maps.add(map as usize) }
+    }
+
+    /// Returns the color of a material map.
+    pub fn color(&self, map: MaterialMap) -> Color {
+        self.map(map).color.into()
+    }
+
+    /// Sets the color of a material map.
+    pub fn set_color(&mut self, map: MaterialMap, color: impl Into<Color>) {
+        self.map_mut(map).color = color.into().into();
+    }
+
+    /// Returns the value of a material map.
+    pub fn value(&self, map: MaterialMap) -> f32 {
+        self.map(map).value
+    }
+
+    /// Sets the value of a material map.
+    pub fn set_value(&mut self, map: MaterialMap, value: f32) {
+        self.map_mut(map).value = value;
+    }
+}
+
+/// Texture or shader assigned to a material, kept alive by the model.
+enum MaterialBinding {
+    Texture {
+        material: usize,
+        map: MaterialMap,
+        texture: AssetHandle<Texture>,
+    },
+    Shader {
+        material: usize,
+        shader: AssetHandle<Shader>,
+    },
+}
+
+/// Animation of a model skeleton.
+pub struct ModelAnimation {
+    pub(crate) raw: ffi::ModelAnimation,
+}
+
+impl ModelAnimation {
+    /// Loads all the animations from an IQM or glTF file.
+    ///
+    /// glTF animations are the ones of the first skin, sampled at 60 frames per second. See
+    /// `Window::load_model_animations` to resolve the path through the mounted asset sources.
+    pub fn load_all<P: AsRef<Path>>(path: P) -> Result<Vec<ModelAnimation>, LoadModelError> {
+        if !is_format_supported(path.as_ref(), true) {
+            return Err(LoadModelError::UnsupportedFormat);
+        }
+        unsafe {
+            let is_gltf = file_type(path.as_ref()).to_lowercase() != "iqm";
+            let path = CString::new(path.as_ref().display().to_string()).unwrap();
+            let mut count = 0;
+            let raws = if is_gltf {
+                load_gltf_animations(&path, &mut count)
+            } else {
+                ffi::LoadModelAnimations(path.as_ptr(), &mut count)
+            };
+            if !raws.is_null() {
+                let animations = slice::from_raw_parts(raws, count as usize)
+                    .iter()
+                    .map(|&raw| ModelAnimation { raw })
+                    .collect();
+                ffi::free(raws as *mut _);
+                Ok(animations)
+            } else {
+                Err(LoadModelError::ReadFailed)
+            }
+        }
+    }
+
+    /// Returns the number of bones.
+    pub fn bone_count(&self) -> i32 {
+        self.raw.boneCount
+    }
+
+    /// Returns the number of frames.
+    pub fn frame_count(&self) -> i32 {
+        self.raw.frameCount
+    }
+}
+
+impl Drop for ModelAnimation {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::UnloadModelAnimation(self.raw);
+        }
+    }
+}
+
+/// Loads the animations of a glTF file.
+#[cfg(feature = "gltf")]
+unsafe fn load_gltf_animations(path: &CString, count: &mut i32) -> *mut ffi::ModelAnimation {
+    ffi::LoadGLTFModelAnimations(path.as_ptr(), count)
+}
+
+#[cfg(not(feature = "gltf"))]
+unsafe fn load_gltf_animations(_: &CString, _: &mut i32) -> *mut ffi::ModelAnimation {
+    unreachable!()
+}
+
+/// Loads the skeleton of a glTF file into a model loaded from it, if all its meshes are skinned.
+#[cfg(feature = "gltf")]
+unsafe fn load_gltf_skeleton(path: &CString, raw: &mut ffi::Model) {
+    ffi::LoadGLTFModelSkeleton(path.as_ptr(), raw);
+}
+
+#[cfg(not(feature = "gltf"))]
+unsafe fn load_gltf_skeleton(_: &CString, _: &mut ffi::Model) {
+    unreachable!()
+}
+
+/// Model made of meshes, materials and an optional skeleton.
+pub struct Model {
+    pub(crate) _handle: Rc<Handle>,
+    pub(crate) raw: ffi::Model,
+    /// Textures loaded with the model file, not unloaded by raylib.
+    textures: Vec<ffi::Texture2D>,
+    bindings: Vec<MaterialBinding>,
+}
+
+impl Model {
+    /// Returns the number of meshes.
+    pub fn mesh_count(&self) -> i32 {
+        self.raw.meshCount
+    }
+
+    /// Returns the number of bones.
+    pub fn bone_count(&self) -> i32 {
+        self.raw.boneCount
+    }
+
+    /// Returns the local transform matrix.
+    pub fn transform(&self) -> Matrix {
+        self.raw.transform.into()
+    }
+
+    /// Sets the local transform matrix, applied before the drawing transformations.
+    pub fn set_transform(&mut self, transform: Matrix) {
+        self.raw.transform = transform.into();
+    }
+
+    /// Computes the bounding box limits of all the meshes.
+    pub fn bounding_box(&self) -> BoundingBox {
+        unsafe {
+            slice::from_raw_parts(self.raw.meshes, self.raw.meshCount as usize)
+                .iter()
+                .map(|&mesh| BoundingBox::from(ffi::MeshBoundingBox(mesh)))
+                .fold(None, |result: Option<BoundingBox>, bounding_box| {
+                    Some(match result {
+                        Some(result) => BoundingBox::new(
+                            Vector3::new(
+                                result.min.x.min(bounding_box.min.x),
+                                result.min.y.min(bounding_box.min.y),
+                                result.min.z.min(bounding_box.min.z),
+                            ),
+                            Vector3::new(
+                                result.max.x.max(bounding_box.max.x),
+                                result.max.y.max(bounding_box.max.y),
+                                result.max.z.max(bounding_box.max.z),
+                            ),
+                        ),
+                        None => bounding_box,
+                    })
+                })
+                .unwrap_or_default()
+        }
+    }
+
+    /// Returns the materials.
+    pub fn materials(&self) -> &[Material] {
+        unsafe {
+            slice::from_raw_parts(
+                self.raw.materials as *const Material,
+                self.raw.materialCount as usize,
+            )
+        }
+    }
+
+    /// Returns the mutable materials.
+    pub fn materials_mut(&mut self) -> &mut [Material] {
+        unsafe {
+            slice::from_raw_parts_mut(
+                self.raw.materials as *mut Material,
+                self.raw.materialCount as usize,
+            )
+        }
+    }
+
+    /// Sets the texture of a material map, kept alive by the model.
+    ///
+    /// Shared textures, such as the ones of an asset cache, are passed as handles: the model draws
+    /// their current version, following hot reloads.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `material` is out of bounds.
+    pub fn set_material_texture(
+        &mut self,
+        material: usize,
+        map: MaterialMap,
+        texture: impl Into<AssetHandle<Texture>>,
+    ) {
+        assert!(
+            material < self.raw.materialCount as usize,
+            "material out of bounds"
+        );
+        self.bindings.retain(|binding| match binding {
+            MaterialBinding::Texture {
+                material: bound_material,
+                map: bound_map,
+                ..
+            } => (*bound_material, *bound_map) != (material, map),
+            _ => true,
+        });
+        self.bindings.push(MaterialBinding::Texture {
+            material,
+            map,
+            texture: texture.into(),
+        });
+    }
+
+    /// Sets the shader used to draw a material, kept alive by the model.
+    ///
+    /// Shared shaders, such as the ones of an asset cache, are passed as handles: the model draws
+    /// with their current version, following hot reloads.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `material` is out of bounds.
+    pub fn set_material_shader(&mut self, material: usize, shader: impl Into<AssetHandle<Shader>>) {
+        assert!(
+            material < self.raw.materialCount as usize,
+            "material out of bounds"
+        );
+        self.bindings.retain(|binding| match binding {
+            MaterialBinding::Shader {
+                material: bound_material,
+                ..
+            } => *bound_material != material,
+            _ => true,
+        });
+        self.bindings.push(MaterialBinding::Shader {
+            material,
+            shader: shader.into(),
+        });
+    }
+
+    /// Calls a drawing function with the raw model, its materials using the current version of
+    /// the assigned textures and shaders.
+    fn draw_raw(&self, draw: impl FnOnce(ffi::Model)) {
+        if self.bindings.is_empty() {
+            draw(self.raw);
+            return;
+        }
+        let mut materials = unsafe {
+            slice::from_raw_parts(self.raw.materials, self.raw.materialCount as usize).to_vec()
+        };
+        let mut maps: Vec<Vec<ffi::MaterialMap>> = materials
+            .iter()
+            .map(|material| unsafe {
+                slice::from_raw_parts(material.maps, ffi::MAX_MATERIAL_MAPS).to_vec()
+            })
+            .collect();
+        for binding in &self.bindings {
+            match binding {
+                MaterialBinding::Texture {
+                    material,
+                    map,
+                    texture,
+                } => maps[*material][*map as usize].texture = texture.borrow().raw,
+                MaterialBinding::Shader { material, shader } => {
+                    materials[*material].shader = shader.borrow().raw
+                }
+            }
+        }
+        for (material, maps) in materials.iter_mut().zip(&mut maps) {
+            material.maps = maps.as_mut_ptr();
+        }
+        let mut raw = self.raw;
+        raw.materials = materials.as_mut_ptr();
+        draw(raw);
+    }
+
+    /// Sets the material used to draw a mesh.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mesh` or `material` is out of bounds.
+    pub fn set_mesh_material(&mut self, mesh: usize, material: usize) {
+        assert!(mesh < self.raw.meshCount as usize, "mesh out of bounds");
+        assert!(
+            material < self.raw.materialCount as usize,
+            "material out of bounds"
+        );
+        unsafe {
+            ffi::SetModelMeshMaterial(&mut self.raw, mesh as i32, material as i32);
+        }
+    }
+
+    /// Checks if an animation skeleton matches the model skeleton.
+    pub fn is_animation_valid(&self, animation: &ModelAnimation) -> bool {
+        unsafe { ffi::IsModelAnimationValid(self.raw, animation.raw) }
+    }
+
+    /// Updates the model pose to a frame of an animation, wrapping around the frame count.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the animation skeleton doesn't match the model skeleton.
+    pub fn update_animation(&mut self, animation: &ModelAnimation, frame: u32) {
+        assert!(
+            self.is_animation_valid(animation),
+            "animation skeleton doesn't match the model skeleton"
+        );
+        if animation.raw.frameCount > 0 {
+            let frame = frame % animation.raw.frameCount as u32;
+            unsafe {
+                ffi::UpdateModelAnimation(self.raw, animation.raw, frame as i32);
+            }
+        }
+    }
+}
+
+impl Drop for Model {
+    fn drop(&mut self) {
+        unsafe {
+            // Material textures are not unloaded by raylib, the assigned ones are owned by handles
+            for &texture in &self.textures {
+                ffi::UnloadTexture(texture);
+            }
+            ffi::UnloadModel(self.raw);
+        }
+    }
+}
+
+/// Models.
+impl Canvas {
+    /// Draws a model.
+    #[inline]
+    pub fn draw_model(
+        &mut self,
+        model: &Model,
+        position: impl Into<Vector3>,
+        scale: f32,
+        tint: impl Into<Color>,
+    ) {
+        let (position, tint) = (position.into().into(), tint.into().into());
+        model.draw_raw(|raw| unsafe {
+            ffi::DrawModel(raw, position, scale, tint);
+        });
+    }
+
+    /// Draws a model with extended parameters.
+    #[inline]
+    pub fn draw_model_ex(
+        &mut self,
+        model: &Model,
+        position: impl Into<Vector3>,
+        rotation_axis: impl Into<Vector3>,
+        rotation_angle: f32,
+        scale: impl Into<Vector3>,
+        tint: impl Into<Color>,
+    ) {
+        let (position, rotation_axis) = (position.into().into(), rotation_axis.into().into());
+        let (scale, tint) = (scale.into().into(), tint.into().into());
+        model.draw_raw(|raw| unsafe {
+            ffi::DrawModelEx(raw, position, rotation_axis, rotation_angle, scale, tint);
+        });
+    }
+
+    /// Draws a model wires.
+    #[inline]
+    pub fn draw_model_wires(
+        &mut self,
+        model: &Model,
+        position: impl Into<Vector3>,
+        scale: f32,
+        tint: impl Into<Color>,
+    ) {
+        let (position, tint) = (position.into().into(), tint.into().into());
+        model.draw_raw(|raw| unsafe {
+            ffi::DrawModelWires(raw, position, scale, tint);
+        });
+    }
+
+    /// Draws a model wires with extended parameters.
+    #[inline]
+    pub fn draw_model_wires_ex(
+        &mut self,
+        model: &Model,
+        position: impl Into<Vector3>,
+        rotation_axis: impl Into<Vector3>,
+        rotation_angle: f32,
+        scale: impl Into<Vector3>,
+        tint: impl Into<Color>,
+    ) {
+        let (position, rotation_axis) = (position.into().into(), rotation_axis.into().into());
+        let (scale, tint) = (scale.into().into(), tint.into().into());
+        model.draw_raw(|raw| unsafe {
+            ffi::DrawModelWiresEx(raw, position, rotation_axis, rotation_angle, scale, tint);
+        });
+    }
+}
+
+/// Model.
+impl Window {
+    /// Loads model (meshes, materials and skeleton) from file into GPU memory (VRAM).
+    ///
+    /// The skeleton of glTF files is the one of their first skin, loaded if all the meshes are
+    /// skinned and have normals. The path is resolved through the mounted asset sources first,
+    /// along with the files it references (materials, buffers and textures).
+    pub fn load_model<P: AsRef<Path>>(&self, path: P) -> Result<Model, LoadModelError> {
+        let path = path.as_ref();
+        if !is_format_supported(path, false) {
+            return Err(LoadModelError::UnsupportedFormat);
+        }
+        let extracted = self
+            .extract_asset(path)
+            .map_err(|_| LoadModelError::ReadFailed)?;
+        let path = extracted.as_ref().map_or(path, ExtractedAsset::path);
+        if !path.is_file() {
+            return Err(LoadModelError::ReadFailed);
+        }
+        unsafe {
+            let is_gltf = matches!(file_type(path).to_lowercase().as_str(), "gltf" | "glb");
+            let path = CString::new(path.display().to_string()).unwrap();
+            let mut raw = ffi::LoadModelChecked(path.as_ptr());
+            if raw.meshCount == 0 {
+                return Err(LoadModelError::InvalidFormat);
+            }
+            if is_gltf {
+                load_gltf_skeleton(&path, &mut raw);
+            }
+            let default_texture = ffi::GetTextureDefault();
+            let mut textures: Vec<ffi::Texture2D> = Vec::new();
+            for material in slice::from_raw_parts(raw.materials, raw.materialCount as usize) {
+                for map in slice::from_raw_parts(material.maps, ffi::MAX_MATERIAL_MAPS) {
+                    let id = map.texture.id;
+                    if id != 0
+                        && id != default_texture.id
+                        && textures.iter().all(|texture| texture.id != id)
+                    {
+                        textures.push(map.texture);
+                    }
+                }
+            }
+            Ok(Model {
+                _handle: self.handle.clone(),
+                raw,
+                textures,
+                bindings: Vec::new(),
+            })
+        }
+    }
+
+    /// Loads all the animations from an IQM or glTF file (see `ModelAnimation::load_all`).
+    ///
+    /// The path is resolved through the mounted asset sources first, along with the buffers it
+    /// references.
+    pub fn load_model_animations<P: AsRef<Path>>(
+        &self,
+        path: P,
+    ) -> Result<Vec<ModelAnimation>, LoadModelError> {
+        let path = path.as_ref();
+        if !is_format_supported(path, true) {
+            return Err(LoadModelError::UnsupportedFormat);
+        }
+        let extracted = self
+            .extract_asset(path)
+            .map_err(|_| LoadModelError::ReadFailed)?;
+        ModelAnimation::load_all(extracted.as_ref().map_or(path, ExtractedAsset::path))
+    }
+
+    /// Loads model from a mesh, using the default material.
+    pub fn load_model_from_mesh(&self, mut mesh: Mesh) -> Model {
+        unsafe {
+            let raw = ffi::LoadModelFromMesh(mesh.raw);
+            // The model takes the ownership of the mesh data
+            mesh.raw.vboId = ptr::null_mut();
+            Model {
+                _handle: self.handle.clone(),
+                raw,
+                textures: Vec::new(),
+                bindings: Vec::new(),
+            }
+        }
+    }
+
+    /// Wraps a generated mesh.
+    fn mesh(&self, raw: ffi::Mesh) -> Mesh {
+        Mesh {
+            _handle: self.handle.clone(),
+            raw,
+        }
+    }
+
+    /// Generates a polygonal mesh.
+    pub fn generate_poly_mesh(&self, sides: i32, radius: f32) -> Mesh {
+        unsafe { self.mesh(ffi::GenMeshPoly(sides, radius)) }
+    }
+
+    /// Generates a plane mesh (with subdivisions).
+    pub fn generate_plane_mesh(
+        &self,
+        width: f32,
+        length: f32,
+        resolution_x: i32,
+        resolution_z: i32,
+    ) -> Mesh {
+        unsafe { self.mesh(ffi::GenMeshPlane(width, length, resolution_x, resolution_z)) }
+    }
+
+    /// Generates a cuboid mesh.
+    pub fn generate_cube_mesh(&self, width: f32, height: f32, length: f32) -> Mesh {
+        unsafe { self.mesh(ffi::GenMeshCube(width, height, length)) }
+    }
+
+    /// Generates a sphere mesh.
+    pub fn generate_sphere_mesh(&self, radius: f32, rings: i32, slices: i32) -> Mesh {
+        unsafe { self.mesh(ffi::GenMeshSphere(radius, rings, slices)) }
+    }
+
+    /// Generates a half-sphere mesh (no bottom cap).
+    pub fn generate_hemisphere_mesh(&self, radius: f32, rings: i32, slices: i32) -> Mesh {
+        unsafe { self.mesh(ffi::GenMeshHemiSphere(radius, rings, slices)) }
+    }
+
+    /// Generates a cylinder mesh.
+    pub fn generate_cylinder_mesh(&self, radius: f32, height: f32, slices: i32) -> Mesh {
+        unsafe { self.mesh(ffi::GenMeshCylinder(radius, height, slices)) }
+    }
+
+    /// Generates a torus mesh.
+    pub fn generate_torus_mesh(
+        &self,
+        radius: f32,
+        size: f32,
+        radial_segments: i32,
+        sides: i32,
+    ) -> Mesh {
+        unsafe { self.mesh(ffi::GenMeshTorus(radius, size, radial_segments, sides)) }
+    }
+
+    /// Generates a trefoil knot mesh.
+    pub fn generate_knot_mesh(
+        &self,
+        radius: f32,
+        size: f32,
+        radial_segments: i32,
+        sides: i32,
+    ) -> Mesh {
+        unsafe { self.mesh(ffi::GenMeshKnot(radius, size, radial_segments, sides)) }
+    }
+
+    /// Generates a heightmap mesh from image data.
+    pub fn generate_heightmap_mesh(&self, heightmap: &Image, size: impl Into<Vector3>) -> Mesh {
+        unsafe { self.mesh(ffi::GenMeshHeightmap(heightmap.raw, size.into().into())) }
+    }
+
+    /// Generates a cubes-based map mesh from image data.
+    pub fn generate_cubicmap_mesh(&self, cubicmap: &Image, cube_size: impl Into<Vector3>) -> Mesh {
+        unsafe { self.mesh(ffi::GenMeshCubicmap(cubicmap.raw, cube_size.into().into())) }
+    }
+}
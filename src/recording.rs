@@ -0,0 +1,469 @@
+//! Input recording and replay.
+//!
+//! While recording or replaying, the input state queried through `Window` (keyboard, characters,
+//! mouse, gamepads) and the timing (`frame_time` and `time`) are captured once per frame, when
+//! `Window::draw` returns. Window events (resizing, dropped files) and random values aren't
+//! recorded.
+
+use crate::{
+    ffi,
+    input::{Gamepad, GamepadAxis, GamepadButton, Key, MouseButton, KEY_COUNT},
+    math::Vector2,
+    window::Window,
+};
+use std::{error::Error, fmt, fs, path::Path};
+
+/// Magic number of input recording files.
+const MAGIC: &[u8; 4] = b"RQIR";
+
+/// Version of the input recording file format.
+const VERSION: u8 = 1;
+
+/// Kinds of input recording loading errors.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LoadInputRecordingError {
+    ReadFailed,
+    InvalidFormat,
+}
+
+impl fmt::Display for LoadInputRecordingError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{:?}", self)
+    }
+}
+
+impl Error for LoadInputRecordingError {}
+
+/// Kinds of input recording saving errors.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SaveInputRecordingError {
+    WriteFailed,
+}
+
+impl fmt::Display for SaveInputRecordingError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{:?}", self)
+    }
+}
+
+impl Error for SaveInputRecordingError {}
+
+/// Recorded state of a gamepad.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct GamepadFrame {
+    pub(crate) gamepad: u8,
+    pub(crate) axis_count: u8,
+    pub(crate) buttons: u32,
+    pub(crate) axes: [f32; 6],
+}
+
+/// Recorded input state of a frame.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Frame {
+    pub(crate) frame_time: f32,
+    pub(crate) time: f64,
+    pub(crate) keys: Vec<u16>,
+    pub(crate) chars: Vec<char>,
+    pub(crate) mouse_position: Vector2,
+    pub(crate) mouse_buttons: u8,
+    pub(crate) wheel: i32,
+    pub(crate) last_gamepad_button: i32,
+    pub(crate) gamepads: Vec<GamepadFrame>,
+}
+
+impl Frame {
+    /// Captures the current input state, consuming the characters queue.
+    fn capture() -> Frame {
+        unsafe {
+            let mut chars = Vec::new();
+            loop {
+                match ffi::GetKeyPressed() {
+                    0 => break,
+                    character => chars.extend(std::char::from_u32(character as u32)),
+                }
+            }
+            let mut mouse_buttons = 0;
+            for &button in MouseButton::ALL.iter() {
+                if ffi::IsMouseButtonDown(button as i32) {
+                    mouse_buttons |= 1 << button as i32;
+                }
+            }
+            let mut gamepads = Vec::new();
+            for &gamepad in Gamepad::ALL.iter() {
+                if !ffi::IsGamepadAvailable(gamepad as i32) {
+                    continue;
+                }
+                let mut frame = GamepadFrame {
+                    gamepad: gamepad as u8,
+                    axis_count: ffi::GetGamepadAxisCount(gamepad as i32) as u8,
+                    ..Default::default()
+                };
+                for &button in GamepadButton::ALL.iter() {
+                    if ffi::IsGamepadButtonDown(gamepad as i32, button as i32) {
+                        frame.buttons |= 1 << button as i32;
+                    }
+                }
+                for &axis in GamepadAxis::ALL.iter() {
+                    frame.axes[axis as usize] =
+                        ffi::GetGamepadAxisMovement(gamepad as i32, axis as i32);
+                }
+                gamepads.push(frame);
+            }
+            Frame {
+                frame_time: ffi::GetFrameTime(),
+                time: ffi::GetTime(),
+                keys: (0..KEY_COUNT)
+                    .filter_map(Key::from_raw)
+                    .filter(|&key| ffi::IsKeyDown(key as i32))
+                    .map(|key| key as u16)
+                    .collect(),
+                chars,
+                mouse_position: ffi::GetMousePosition().into(),
+                mouse_buttons,
+                wheel: ffi::GetMouseWheelMove(),
+                last_gamepad_button: ffi::GetGamepadButtonPressed(),
+                gamepads,
+            }
+        }
+    }
+
+    /// Detects if a key is being pressed.
+    pub(crate) fn is_key_down(&self, key: Key) -> bool {
+        self.keys.contains(&(key as u16))
+    }
+
+    /// Detects if a mouse button is being pressed.
+    pub(crate) fn is_mouse_button_down(&self, button: MouseButton) -> bool {
+        self.mouse_buttons & (1 << button as i32) != 0
+    }
+
+    /// Returns the state of a gamepad, if available.
+    pub(crate) fn gamepad(&self, gamepad: Gamepad) -> Option<&GamepadFrame> {
+        self.gamepads
+            .iter()
+            .find(|frame| frame.gamepad == gamepad as u8)
+    }
+
+    /// Detects if a gamepad button is being pressed.
+    pub(crate) fn is_gamepad_button_down(&self, gamepad: Gamepad, button: GamepadButton) -> bool {
+        matches!(self.gamepad(gamepad), Some(frame) if frame.buttons & (1 << button as i32) != 0)
+    }
+
+    /// Writes the frame.
+    fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&self.frame_time.to_le_bytes());
+        bytes.extend_from_slice(&self.time.to_le_bytes());
+        bytes.extend_from_slice(&(self.keys.len() as u16).to_le_bytes());
+        for key in &self.keys {
+            bytes.extend_from_slice(&key.to_le_bytes());
+        }
+        bytes.push(self.chars.len() as u8);
+        for &character in &self.chars {
+            bytes.extend_from_slice(&(character as u32).to_le_bytes());
+        }
+        bytes.extend_from_slice(&self.mouse_position.x.to_le_bytes());
+        bytes.extend_from_slice(&self.mouse_position.y.to_le_bytes());
+        bytes.push(self.mouse_buttons);
+        bytes.extend_from_slice(&self.wheel.to_le_bytes());
+        bytes.extend_from_slice(&self.last_gamepad_button.to_le_bytes());
+        bytes.push(self.gamepads.len() as u8);
+        for gamepad in &self.gamepads {
+            bytes.push(gamepad.gamepad);
+            bytes.push(gamepad.axis_count);
+            bytes.extend_from_slice(&gamepad.buttons.to_le_bytes());
+            for axis in &gamepad.axes {
+                bytes.extend_from_slice(&axis.to_le_bytes());
+            }
+        }
+    }
+
+    /// Reads a frame.
+    fn read(reader: &mut Reader) -> Option<Frame> {
+        let frame_time = f32::from_le_bytes(reader.read()?);
+        let time = f64::from_le_bytes(reader.read()?);
+        let mut keys = Vec::new();
+        for _ in 0..u16::from_le_bytes(reader.read()?) {
+            keys.push(u16::from_le_bytes(reader.read()?));
+        }
+        let mut chars = Vec::new();
+        for _ in 0..reader.read::<[u8; 1]>()?[0] {
+            chars.push(std::char::from_u32(u32::from_le_bytes(reader.read()?))?);
+        }
+        let mouse_position = Vector2::new(
+            f32::from_le_bytes(reader.read()?),
+            f32::from_le_bytes(reader.read()?),
+        );
+        let [mouse_buttons] = reader.read()?;
+        let wheel = i32::from_le_bytes(reader.read()?);
+        let last_gamepad_button = i32::from_le_bytes(reader.read()?);
+        let mut gamepads = Vec::new();
+        for _ in 0..reader.read::<[u8; 1]>()?[0] {
+            let [gamepad, axis_count] = reader.read()?;
+            let buttons = u32::from_le_bytes(reader.read()?);
+            let mut axes = [0.0; 6];
+            for axis in axes.iter_mut() {
+                *axis = f32::from_le_bytes(reader.read()?);
+            }
+            gamepads.push(GamepadFrame {
+                gamepad,
+                axis_count,
+                buttons,
+                axes,
+            });
+        }
+        Some(Frame {
+            frame_time,
+            time,
+            keys,
+            chars,
+            mouse_position,
+            mouse_buttons,
+            wheel,
+            last_gamepad_button,
+            gamepads,
+        })
+    }
+}
+
+/// Reader of little-endian binary data.
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    /// Reads an array of bytes.
+    fn read<T: Default + AsMut<[u8]>>(&mut self) -> Option<T> {
+        let mut value = T::default();
+        let length = value.as_mut().len();
+        if self.bytes.len() < length {
+            return None;
+        }
+        let (head, tail) = self.bytes.split_at(length);
+        value.as_mut().copy_from_slice(head);
+        self.bytes = tail;
+        Some(value)
+    }
+}
+
+/// Recording of the input state, frame by frame.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InputRecording {
+    pub(crate) frames: Vec<Frame>,
+}
+
+impl InputRecording {
+    /// Loads an input recording from file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<InputRecording, LoadInputRecordingError> {
+        let bytes = fs::read(path).map_err(|_| LoadInputRecordingError::ReadFailed)?;
+        InputRecording::from_bytes(&bytes).ok_or(LoadInputRecordingError::InvalidFormat)
+    }
+
+    /// Decodes an input recording.
+    fn from_bytes(bytes: &[u8]) -> Option<InputRecording> {
+        let mut reader = Reader { bytes };
+        let magic: [u8; 4] = reader.read()?;
+        let [version] = reader.read()?;
+        if &magic != MAGIC || version != VERSION {
+            return None;
+        }
+        let count = u32::from_le_bytes(reader.read()?);
+        let mut frames = Vec::new();
+        for _ in 0..count {
+            frames.push(Frame::read(&mut reader)?);
+        }
+        if reader.bytes.is_empty() {
+            Some(InputRecording { frames })
+        } else {
+            None
+        }
+    }
+
+    /// Saves the input recording to file.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), SaveInputRecordingError> {
+        fs::write(path, self.to_bytes()).map_err(|_| SaveInputRecordingError::WriteFailed)
+    }
+
+    /// Encodes the input recording.
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(VERSION);
+        bytes.extend_from_slice(&(self.frames.len() as u32).to_le_bytes());
+        for frame in &self.frames {
+            frame.write(&mut bytes);
+        }
+        bytes
+    }
+
+    /// Returns the number of recorded frames.
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+}
+
+/// Source of the input state.
+pub(crate) enum Playback {
+    Live,
+    Recording(Vec<Frame>),
+    Replaying(Vec<Frame>, usize),
+}
+
+// `#[default]` on enum variants requires a newer compiler
+#[allow(clippy::derivable_impls)]
+impl Default for Playback {
+    fn default() -> Playback {
+        Playback::Live
+    }
+}
+
+impl Playback {
+    /// Returns the current and previous frames, when recording or replaying.
+    pub(crate) fn frames(&self) -> Option<(&Frame, Option<&Frame>)> {
+        match self {
+            Playback::Live => None,
+            Playback::Recording(frames) => {
+                let index = frames.len().checked_sub(1)?;
+                Some((
+                    &frames[index],
+                    index.checked_sub(1).map(|index| &frames[index]),
+                ))
+            }
+            Playback::Replaying(frames, index) => Some((
+                &frames[*index],
+                index.checked_sub(1).map(|index| &frames[index]),
+            )),
+        }
+    }
+}
+
+/// Input recording.
+impl Window {
+    /// Queries the recorded input state, when recording or replaying.
+    pub(crate) fn recorded<T>(&self, query: impl FnOnce(&Frame, &Frame) -> T) -> Option<T> {
+        let state = self.input.borrow();
+        let (frame, previous) = state.playback.frames()?;
+        Some(query(frame, previous.unwrap_or(&Frame::default())))
+    }
+
+    /// Returns the next recorded character of the current frame, when recording or replaying.
+    pub(crate) fn recorded_char(&self) -> Option<Option<char>> {
+        let mut state = self.input.borrow_mut();
+        let cursor = state.char_cursor;
+        let character = state
+            .playback
+            .frames()
+            .map(|(frame, _)| frame.chars.get(cursor).copied())?;
+        if character.is_some() {
+            state.char_cursor += 1;
+        }
+        Some(character)
+    }
+
+    /// Moves to the next input frame, called once the frame has been drawn.
+    pub(crate) fn next_input_frame(&self) {
+        let mut state = self.input.borrow_mut();
+        state.char_cursor = 0;
+        match &mut state.playback {
+            Playback::Live => {}
+            Playback::Recording(frames) => frames.push(Frame::capture()),
+            Playback::Replaying(frames, index) => {
+                // Live characters are discarded to not leak into the replay
+                Frame::capture();
+                if *index + 1 < frames.len() {
+                    *index += 1;
+                } else {
+                    state.playback = Playback::Live;
+                }
+            }
+        }
+    }
+
+    /// Starts recording the input state, replacing any recording or replay in progress.
+    ///
+    /// For a replay to be deterministic, the program must only depend on the recorded state,
+    /// using `frame_time` rather than a fixed time step.
+    pub fn start_input_recording(&mut self) {
+        let mut state = self.input.borrow_mut();
+        state.char_cursor = 0;
+        state.playback = Playback::Recording(vec![Frame::capture()]);
+    }
+
+    /// Checks if the input state is being recorded.
+    pub fn is_input_recording(&self) -> bool {
+        matches!(self.input.borrow().playback, Playback::Recording(_))
+    }
+
+    /// Stops recording the input state and returns the recording.
+    pub fn stop_input_recording(&mut self) -> Option<InputRecording> {
+        let mut state = self.input.borrow_mut();
+        match std::mem::take(&mut state.playback) {
+            Playback::Recording(frames) => Some(InputRecording { frames }),
+            playback => {
+                state.playback = playback;
+                None
+            }
+        }
+    }
+
+    /// Starts replaying an input recording, replacing any recording or replay in progress: the
+    /// input state and timing are read from the recording until its end.
+    pub fn start_replay(&mut self, recording: &InputRecording) {
+        let mut state = self.input.borrow_mut();
+        state.char_cursor = 0;
+        state.playback = if recording.frames.is_empty() {
+            Playback::Live
+        } else {
+            Playback::Replaying(recording.frames.clone(), 0)
+        };
+    }
+
+    /// Checks if an input recording is being replayed.
+    pub fn is_replaying(&self) -> bool {
+        matches!(self.input.borrow().playback, Playback::Replaying(..))
+    }
+
+    /// Stops replaying the input recording.
+    pub fn stop_replay(&mut self) {
+        let mut state = self.input.borrow_mut();
+        if let Playback::Replaying(..) = state.playback {
+            state.playback = Playback::Live;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        math::Vector2,
+        recording::{Frame, GamepadFrame, InputRecording},
+    };
+
+    #[test]
+    fn test_input_recording_bytes() {
+        let recording = InputRecording {
+            frames: vec![
+                Frame::default(),
+                Frame {
+                    frame_time: 0.016,
+                    time: 1.5,
+                    keys: vec![32, 65],
+                    chars: vec!['a', 'é'],
+                    mouse_position: Vector2::new(10.0, 20.0),
+                    mouse_buttons: 0b101,
+                    wheel: -1,
+                    last_gamepad_button: 7,
+                    gamepads: vec![GamepadFrame {
+                        gamepad: 1,
+                        axis_count: 6,
+                        buttons: 0b110,
+                        axes: [0.5, -0.5, 0.0, 0.0, -1.0, 1.0],
+                    }],
+                },
+            ],
+        };
+        let bytes = recording.to_bytes();
+        assert_eq!(InputRecording::from_bytes(&bytes), Some(recording));
+        assert_eq!(InputRecording::from_bytes(&bytes[..bytes.len() - 1]), None);
+        assert_eq!(InputRecording::from_bytes(b"RQIR"), None);
+    }
+}
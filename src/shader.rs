@@ -0,0 +1,220 @@
+//! Shader management.
+
+use crate::{
+    ffi,
+    math::{Matrix, Vector2, Vector3, Vector4},
+    texture::Texture,
+    window::{Handle, Window},
+};
+use std::{ffi::CString, fmt, os::raw::c_uint, path::Path, ptr, rc::Rc};
+
+/// Kinds of shader loading errors.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LoadShaderError {
+    ReadFailed,
+    CompilationFailed,
+}
+
+impl fmt::Display for LoadShaderError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{:?}", self)
+    }
+}
+
+impl std::error::Error for LoadShaderError {}
+
+/// Shader program (GLSL).
+pub struct Shader {
+    pub(crate) _handle: Rc<Handle>,
+    pub(crate) raw: ffi::Shader,
+    /// Textures of sampler uniforms (location and texture identifier), bound to the texture unit
+    /// following their index (unit 0 being used by raylib).
+    pub(crate) samplers: Vec<(i32, c_uint)>,
+}
+
+impl Shader {
+    /// Returns the location of a uniform, if it exists.
+    pub fn location(&self, name: &str) -> Option<i32> {
+        unsafe {
+            let name = CString::new(name).ok()?;
+            let location = ffi::GetShaderLocation(self.raw, name.as_ptr());
+            if location >= 0 {
+                Some(location)
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Sets the value of a uniform.
+    #[inline]
+    pub fn set_uniform(&mut self, location: i32, value: impl UniformValue) {
+        value.set_to(self, location);
+    }
+
+    /// Binds the textures of sampler uniforms to their texture units.
+    pub(crate) fn bind_samplers(&self) {
+        unsafe {
+            if let (Some(active_texture), Some(bind_texture)) =
+                (ffi::glad_glActiveTexture, ffi::glad_glBindTexture)
+            {
+                for (index, &(_, texture)) in self.samplers.iter().enumerate() {
+                    active_texture(ffi::GL_TEXTURE0 + index as c_uint + 1);
+                    bind_texture(ffi::GL_TEXTURE_2D, texture);
+                }
+                active_texture(ffi::GL_TEXTURE0);
+            }
+        }
+    }
+}
+
+impl Drop for Shader {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::UnloadShader(self.raw);
+        }
+    }
+}
+
+/// Value which can be assigned to a shader uniform.
+pub trait UniformValue {
+    /// Sets the value to the uniform at `location` of `shader`.
+    fn set_to(self, shader: &mut Shader, location: i32);
+}
+
+/// Convenient macro to implement `UniformValue` for plain data types.
+macro_rules! uniform_value {
+    ($type:ty, $uniform:expr) => {
+        impl UniformValue for $type {
+            #[inline]
+            fn set_to(self, shader: &mut Shader, location: i32) {
+                unsafe {
+                    ffi::SetShaderValue(
+                        shader.raw,
+                        location,
+                        &self as *const $type as *const _,
+                        $uniform,
+                    );
+                }
+            }
+        }
+    };
+}
+
+uniform_value!(f32, ffi::UNIFORM_FLOAT);
+uniform_value!([f32; 2], ffi::UNIFORM_VEC2);
+uniform_value!([f32; 3], ffi::UNIFORM_VEC3);
+uniform_value!([f32; 4], ffi::UNIFORM_VEC4);
+uniform_value!(Vector2, ffi::UNIFORM_VEC2);
+uniform_value!(Vector3, ffi::UNIFORM_VEC3);
+uniform_value!(Vector4, ffi::UNIFORM_VEC4);
+uniform_value!(i32, ffi::UNIFORM_INT);
+uniform_value!([i32; 2], ffi::UNIFORM_IVEC2);
+uniform_value!([i32; 3], ffi::UNIFORM_IVEC3);
+uniform_value!([i32; 4], ffi::UNIFORM_IVEC4);
+
+impl UniformValue for Matrix {
+    #[inline]
+    fn set_to(self, shader: &mut Shader, location: i32) {
+        unsafe {
+            ffi::SetShaderValueMatrix(shader.raw, location, self.into());
+        }
+    }
+}
+
+/// Binds the texture to a texture unit reserved for the sampler uniform, bound again by
+/// `Canvas::shader_mode`.
+///
+/// The texture must not be dropped while the shader samples it.
+impl UniformValue for &Texture {
+    fn set_to(self, shader: &mut Shader, location: i32) {
+        let index = match shader
+            .samplers
+            .iter()
+            .position(|&(sampler, _)| sampler == location)
+        {
+            Some(index) => {
+                shader.samplers[index].1 = self.raw.id;
+                index
+            }
+            None => {
+                shader.samplers.push((location, self.raw.id));
+                shader.samplers.len() - 1
+            }
+        };
+        (index as i32 + 1).set_to(shader, location);
+        shader.bind_samplers();
+    }
+}
+
+/// Shader.
+impl Window {
+    /// Loads shader from vertex and fragment shader files into GPU memory (VRAM).
+    ///
+    /// Paths are resolved through the mounted asset sources first.
+    pub fn load_shader<P: AsRef<Path>>(
+        &self,
+        vertex: P,
+        fragment: P,
+    ) -> Result<Shader, LoadShaderError> {
+        let vertex = self.read_shader_file(vertex.as_ref())?;
+        let fragment = self.read_shader_file(fragment.as_ref())?;
+        self.load_shader_from_source(Some(&vertex), Some(&fragment))
+    }
+
+    /// Loads shader from a fragment shader file into GPU memory (VRAM), using the default vertex
+    /// shader.
+    ///
+    /// The path is resolved through the mounted asset sources first.
+    pub fn load_fragment_shader<P: AsRef<Path>>(
+        &self,
+        fragment: P,
+    ) -> Result<Shader, LoadShaderError> {
+        let fragment = self.read_shader_file(fragment.as_ref())?;
+        self.load_shader_from_source(None, Some(&fragment))
+    }
+
+    /// Reads shader source code, resolving the path through the mounted asset sources first.
+    fn read_shader_file(&self, path: &Path) -> Result<String, LoadShaderError> {
+        self.read_asset_or_file(path)
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .ok_or(LoadShaderError::ReadFailed)
+    }
+
+    /// Loads shader from source code into GPU memory (VRAM), using the default vertex or
+    /// fragment shader when not provided.
+    pub fn load_shader_from_source(
+        &self,
+        vertex: Option<&str>,
+        fragment: Option<&str>,
+    ) -> Result<Shader, LoadShaderError> {
+        unsafe {
+            let vertex = vertex
+                .map(CString::new)
+                .transpose()
+                .map_err(|_| LoadShaderError::CompilationFailed)?;
+            let fragment = fragment
+                .map(CString::new)
+                .transpose()
+                .map_err(|_| LoadShaderError::CompilationFailed)?;
+            let raw = ffi::LoadShaderCode(
+                vertex
+                    .as_ref()
+                    .map_or(ptr::null(), |vertex| vertex.as_ptr()),
+                fragment
+                    .as_ref()
+                    .map_or(ptr::null(), |fragment| fragment.as_ptr()),
+            );
+            // On failure, the default shader is returned and must not be unloaded
+            if raw.id != 0 && raw.id != ffi::GetShaderDefault().id {
+                Ok(Shader {
+                    _handle: self.handle.clone(),
+                    raw,
+                    samplers: Vec::new(),
+                })
+            } else {
+                Err(LoadShaderError::CompilationFailed)
+            }
+        }
+    }
+}
@@ -0,0 +1,344 @@
+//! 3D shapes utilities.
+
+use crate::{
+    camera::Camera3D,
+    color::Color,
+    drawing::Canvas,
+    ffi,
+    math::{BoundingBox, Rectangle, Vector2, Vector3},
+    texture::Texture,
+};
+
+/// 3D shapes.
+impl Canvas {
+    /// Draws a line in 3D world space.
+    #[inline]
+    pub fn draw_line_3d(
+        &mut self,
+        start: impl Into<Vector3>,
+        end: impl Into<Vector3>,
+        color: impl Into<Color>,
+    ) {
+        unsafe {
+            ffi::DrawLine3D(start.into().into(), end.into().into(), color.into().into());
+        }
+    }
+
+    /// Draws a point in 3D world space.
+    #[inline]
+    pub fn draw_point_3d(&mut self, position: impl Into<Vector3>, color: impl Into<Color>) {
+        unsafe {
+            ffi::DrawPoint3D(position.into().into(), color.into().into());
+        }
+    }
+
+    /// Draws a circle in 3D world space.
+    #[inline]
+    pub fn draw_circle_3d(
+        &mut self,
+        center: impl Into<Vector3>,
+        radius: f32,
+        rotation_axis: impl Into<Vector3>,
+        rotation_angle: f32,
+        color: impl Into<Color>,
+    ) {
+        unsafe {
+            ffi::DrawCircle3D(
+                center.into().into(),
+                radius,
+                rotation_axis.into().into(),
+                rotation_angle,
+                color.into().into(),
+            );
+        }
+    }
+
+    /// Draws a cube.
+    #[inline]
+    pub fn draw_cube(
+        &mut self,
+        position: impl Into<Vector3>,
+        width: f32,
+        height: f32,
+        length: f32,
+        color: impl Into<Color>,
+    ) {
+        unsafe {
+            ffi::DrawCube(
+                position.into().into(),
+                width,
+                height,
+                length,
+                color.into().into(),
+            );
+        }
+    }
+
+    /// Draws a cube (vector version).
+    #[inline]
+    pub fn draw_cube_vec(
+        &mut self,
+        position: impl Into<Vector3>,
+        size: impl Into<Vector3>,
+        color: impl Into<Color>,
+    ) {
+        unsafe {
+            ffi::DrawCubeV(
+                position.into().into(),
+                size.into().into(),
+                color.into().into(),
+            );
+        }
+    }
+
+    /// Draws a cube wires.
+    #[inline]
+    pub fn draw_cube_wires(
+        &mut self,
+        position: impl Into<Vector3>,
+        width: f32,
+        height: f32,
+        length: f32,
+        color: impl Into<Color>,
+    ) {
+        unsafe {
+            ffi::DrawCubeWires(
+                position.into().into(),
+                width,
+                height,
+                length,
+                color.into().into(),
+            );
+        }
+    }
+
+    /// Draws a cube wires (vector version).
+    #[inline]
+    pub fn draw_cube_wires_vec(
+        &mut self,
+        position: impl Into<Vector3>,
+        size: impl Into<Vector3>,
+        color: impl Into<Color>,
+    ) {
+        unsafe {
+            ffi::DrawCubeWiresV(
+                position.into().into(),
+                size.into().into(),
+                color.into().into(),
+            );
+        }
+    }
+
+    /// Draws a textured cube.
+    #[inline]
+    pub fn draw_cube_texture(
+        &mut self,
+        texture: &Texture,
+        position: impl Into<Vector3>,
+        width: f32,
+        height: f32,
+        length: f32,
+        color: impl Into<Color>,
+    ) {
+        unsafe {
+            ffi::DrawCubeTexture(
+                texture.raw,
+                position.into().into(),
+                width,
+                height,
+                length,
+                color.into().into(),
+            );
+        }
+    }
+
+    /// Draws a sphere.
+    #[inline]
+    pub fn draw_sphere(
+        &mut self,
+        center: impl Into<Vector3>,
+        radius: f32,
+        color: impl Into<Color>,
+    ) {
+        unsafe {
+            ffi::DrawSphere(center.into().into(), radius, color.into().into());
+        }
+    }
+
+    /// Draws a sphere with extended parameters.
+    #[inline]
+    pub fn draw_sphere_ex(
+        &mut self,
+        center: impl Into<Vector3>,
+        radius: f32,
+        rings: i32,
+        slices: i32,
+        color: impl Into<Color>,
+    ) {
+        unsafe {
+            ffi::DrawSphereEx(
+                center.into().into(),
+                radius,
+                rings,
+                slices,
+                color.into().into(),
+            );
+        }
+    }
+
+    /// Draws a sphere wires.
+    #[inline]
+    pub fn draw_sphere_wires(
+        &mut self,
+        center: impl Into<Vector3>,
+        radius: f32,
+        rings: i32,
+        slices: i32,
+        color: impl Into<Color>,
+    ) {
+        unsafe {
+            ffi::DrawSphereWires(
+                center.into().into(),
+                radius,
+                rings,
+                slices,
+                color.into().into(),
+            );
+        }
+    }
+
+    /// Draws a cylinder (or a cone).
+    #[inline]
+    pub fn draw_cylinder(
+        &mut self,
+        position: impl Into<Vector3>,
+        radius_top: f32,
+        radius_bottom: f32,
+        height: f32,
+        slices: i32,
+        color: impl Into<Color>,
+    ) {
+        unsafe {
+            ffi::DrawCylinder(
+                position.into().into(),
+                radius_top,
+                radius_bottom,
+                height,
+                slices,
+                color.into().into(),
+            );
+        }
+    }
+
+    /// Draws a cylinder (or a cone) wires.
+    #[inline]
+    pub fn draw_cylinder_wires(
+        &mut self,
+        position: impl Into<Vector3>,
+        radius_top: f32,
+        radius_bottom: f32,
+        height: f32,
+        slices: i32,
+        color: impl Into<Color>,
+    ) {
+        unsafe {
+            ffi::DrawCylinderWires(
+                position.into().into(),
+                radius_top,
+                radius_bottom,
+                height,
+                slices,
+                color.into().into(),
+            );
+        }
+    }
+
+    /// Draws a plane on the XZ axes.
+    #[inline]
+    pub fn draw_plane(
+        &mut self,
+        center: impl Into<Vector3>,
+        size: impl Into<Vector2>,
+        color: impl Into<Color>,
+    ) {
+        unsafe {
+            ffi::DrawPlane(
+                center.into().into(),
+                size.into().into(),
+                color.into().into(),
+            );
+        }
+    }
+
+    /// Draws a grid centered at (0, 0, 0).
+    #[inline]
+    pub fn draw_grid(&mut self, slices: i32, spacing: f32) {
+        unsafe {
+            ffi::DrawGrid(slices, spacing);
+        }
+    }
+
+    /// Draws a simple gizmo.
+    #[inline]
+    pub fn draw_gizmo(&mut self, position: impl Into<Vector3>) {
+        unsafe {
+            ffi::DrawGizmo(position.into().into());
+        }
+    }
+
+    /// Draws a bounding box wires.
+    #[inline]
+    pub fn draw_bounding_box(
+        &mut self,
+        bounding_box: impl Into<BoundingBox>,
+        color: impl Into<Color>,
+    ) {
+        unsafe {
+            ffi::DrawBoundingBox(bounding_box.into().into(), color.into().into());
+        }
+    }
+
+    /// Draws a billboard texture facing the camera.
+    #[inline]
+    pub fn draw_billboard(
+        &mut self,
+        camera: impl Into<Camera3D>,
+        texture: &Texture,
+        center: impl Into<Vector3>,
+        size: f32,
+        tint: impl Into<Color>,
+    ) {
+        unsafe {
+            ffi::DrawBillboard(
+                camera.into().into(),
+                texture.raw,
+                center.into().into(),
+                size,
+                tint.into().into(),
+            );
+        }
+    }
+
+    /// Draws a part of a billboard texture facing the camera.
+    #[inline]
+    pub fn draw_billboard_rec(
+        &mut self,
+        camera: impl Into<Camera3D>,
+        texture: &Texture,
+        source: impl Into<Rectangle>,
+        center: impl Into<Vector3>,
+        size: f32,
+        tint: impl Into<Color>,
+    ) {
+        unsafe {
+            ffi::DrawBillboardRec(
+                camera.into().into(),
+                texture.raw,
+                source.into().into(),
+                center.into().into(),
+                size,
+                tint.into().into(),
+            );
+        }
+    }
+}
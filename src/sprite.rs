@@ -0,0 +1,444 @@
+//! Sprite sheets and frame animations.
+
+use crate::{
+    color::Color,
+    drawing::Canvas,
+    math::{Rectangle, Vector2},
+    texture::Texture,
+    window::Window,
+};
+use std::collections::HashMap;
+
+/// Texture sliced into regions (frames), optionally named.
+pub struct SpriteSheet {
+    texture: Texture,
+    regions: Vec<Rectangle>,
+    names: HashMap<String, usize>,
+}
+
+impl SpriteSheet {
+    /// Creates a `SpriteSheet` without regions.
+    pub fn new(texture: Texture) -> SpriteSheet {
+        SpriteSheet {
+            texture,
+            regions: Vec::new(),
+            names: HashMap::new(),
+        }
+    }
+
+    /// Creates a `SpriteSheet` sliced into a grid of frames, row by row.
+    ///
+    /// `margin` is the space around the grid, `padding` the space between frames.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the frame width or height is not positive.
+    pub fn from_grid(
+        texture: Texture,
+        frame_width: i32,
+        frame_height: i32,
+        margin: i32,
+        padding: i32,
+    ) -> SpriteSheet {
+        assert!(frame_width > 0 && frame_height > 0);
+        let columns = (texture.width() - 2 * margin + padding) / (frame_width + padding);
+        let rows = (texture.height() - 2 * margin + padding) / (frame_height + padding);
+        let mut sheet = SpriteSheet::new(texture);
+        for row in 0..rows.max(0) {
+            for column in 0..columns.max(0) {
+                sheet.add_region(Rectangle::new(
+                    (margin + column * (frame_width + padding)) as f32,
+                    (margin + row * (frame_height + padding)) as f32,
+                    frame_width as f32,
+                    frame_height as f32,
+                ));
+            }
+        }
+        sheet
+    }
+
+    /// Returns the texture.
+    pub fn texture(&self) -> &Texture {
+        &self.texture
+    }
+
+    /// Adds a region and returns its index.
+    pub fn add_region(&mut self, region: impl Into<Rectangle>) -> usize {
+        self.regions.push(region.into());
+        self.regions.len() - 1
+    }
+
+    /// Adds a named region and returns its index.
+    pub fn add_named_region(&mut self, name: &str, region: impl Into<Rectangle>) -> usize {
+        let index = self.add_region(region);
+        self.names.insert(name.to_string(), index);
+        index
+    }
+
+    /// Names an existing region.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the region index is out of bounds.
+    pub fn set_region_name(&mut self, index: usize, name: &str) {
+        assert!(index < self.regions.len());
+        self.names.insert(name.to_string(), index);
+    }
+
+    /// Returns the number of regions.
+    pub fn region_count(&self) -> usize {
+        self.regions.len()
+    }
+
+    /// Returns a region.
+    pub fn region(&self, index: usize) -> Option<Rectangle> {
+        self.regions.get(index).copied()
+    }
+
+    /// Returns the index of a named region.
+    pub fn find_region(&self, name: &str) -> Option<usize> {
+        self.names.get(name).copied()
+    }
+}
+
+/// Transformation applied to drawn sprites.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpriteTransform {
+    /// Rotation and scaling origin, relative to the sprite (in pixels, before scaling).
+    pub origin: Vector2,
+    /// Rotation in degrees.
+    pub rotation: f32,
+    pub scale: Vector2,
+    pub flip_x: bool,
+    pub flip_y: bool,
+}
+
+impl Default for SpriteTransform {
+    fn default() -> SpriteTransform {
+        SpriteTransform {
+            origin: Vector2::ZERO,
+            rotation: 0.0,
+            scale: Vector2::ONE,
+            flip_x: false,
+            flip_y: false,
+        }
+    }
+}
+
+/// Kinds of animation play modes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PlayMode {
+    Once,
+    Loop,
+    PingPong,
+}
+
+/// Frame of an animation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnimationFrame {
+    /// Index of the sprite sheet region.
+    pub region: usize,
+    /// Duration in seconds.
+    pub duration: f32,
+    /// Event fired when the frame is entered.
+    pub event: Option<String>,
+}
+
+/// Frame animation over sprite sheet regions.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Animation {
+    frames: Vec<AnimationFrame>,
+    mode: PlayMode,
+    current: usize,
+    elapsed: f32,
+    backward: bool,
+    finished: bool,
+}
+
+impl Animation {
+    /// Creates an `Animation` without frames.
+    pub fn new(mode: PlayMode) -> Animation {
+        Animation {
+            frames: Vec::new(),
+            mode,
+            current: 0,
+            elapsed: 0.0,
+            backward: false,
+            finished: false,
+        }
+    }
+
+    /// Creates an `Animation` from regions displayed for the same duration.
+    pub fn from_regions(regions: &[usize], duration: f32, mode: PlayMode) -> Animation {
+        let mut animation = Animation::new(mode);
+        for &region in regions {
+            animation.push_frame(region, duration);
+        }
+        animation
+    }
+
+    /// Adds a frame.
+    pub fn push_frame(&mut self, region: usize, duration: f32) {
+        self.frames.push(AnimationFrame {
+            region,
+            duration,
+            event: None,
+        });
+    }
+
+    /// Sets the event fired when a frame is entered.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the frame index is out of bounds.
+    pub fn set_event(&mut self, frame: usize, event: &str) {
+        self.frames[frame].event = Some(event.to_string());
+    }
+
+    /// Returns the frames.
+    pub fn frames(&self) -> &[AnimationFrame] {
+        &self.frames
+    }
+
+    /// Returns the play mode.
+    pub fn mode(&self) -> PlayMode {
+        self.mode
+    }
+
+    /// Sets the play mode.
+    pub fn set_mode(&mut self, mode: PlayMode) {
+        self.mode = mode;
+    }
+
+    /// Returns the current frame index.
+    pub fn current_frame(&self) -> usize {
+        self.current
+    }
+
+    /// Returns the sprite sheet region of the current frame.
+    pub fn current_region(&self) -> Option<usize> {
+        self.frames.get(self.current).map(|frame| frame.region)
+    }
+
+    /// Checks if the animation is over (`PlayMode::Once` only).
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Restarts the animation from the first frame.
+    pub fn reset(&mut self) {
+        self.current = 0;
+        self.elapsed = 0.0;
+        self.backward = false;
+        self.finished = false;
+    }
+
+    /// Advances the animation by the frame time of the window, see `advance`.
+    pub fn update(&mut self, window: &Window) -> Vec<&str> {
+        self.advance(window.frame_time())
+    }
+
+    /// Advances the animation by a duration in seconds and returns the events of the entered
+    /// frames.
+    ///
+    /// Non-finite durations are ignored. Looping animations catch up one cycle at most, skipping
+    /// the frames of the previous cycles.
+    pub fn advance(&mut self, delta: f32) -> Vec<&str> {
+        let mut entered = Vec::new();
+        if self.frames.is_empty() || self.finished || !delta.is_finite() {
+            return Vec::new();
+        }
+        self.elapsed += delta;
+        if let Some(cycle) = self.cycle_duration() {
+            // A cycle ends on the frame it starts from
+            if cycle > 0.0 && self.elapsed > cycle {
+                self.elapsed = cycle + self.elapsed % cycle;
+            }
+        }
+        loop {
+            let duration = self.frames[self.current].duration;
+            // Frames without duration would never let time pass
+            if self.elapsed < duration || (duration <= 0.0 && !entered.is_empty()) {
+                break;
+            }
+            self.elapsed -= duration.max(0.0);
+            match self.next_frame() {
+                Some(frame) => {
+                    self.current = frame;
+                    entered.push(frame);
+                }
+                None => {
+                    self.finished = true;
+                    self.elapsed = 0.0;
+                    break;
+                }
+            }
+        }
+        let frames = &self.frames;
+        entered
+            .into_iter()
+            .filter_map(|frame| frames[frame].event.as_deref())
+            .collect()
+    }
+
+    /// Returns the duration of a cycle of a looping animation.
+    fn cycle_duration(&self) -> Option<f32> {
+        let durations = self.frames.iter().map(|frame| frame.duration.max(0.0));
+        match self.mode {
+            PlayMode::Once => None,
+            PlayMode::Loop => Some(durations.sum()),
+            // Frames between the first and the last ones are played in both directions
+            PlayMode::PingPong => {
+                let last = self.frames.len() - 1;
+                Some(
+                    durations
+                        .enumerate()
+                        .map(|(index, duration)| match index {
+                            0 => duration,
+                            index if index == last => duration,
+                            _ => duration * 2.0,
+                        })
+                        .sum(),
+                )
+            }
+        }
+    }
+
+    /// Returns the frame following the current one, according to the play mode.
+    fn next_frame(&mut self) -> Option<usize> {
+        let last = self.frames.len() - 1;
+        match self.mode {
+            PlayMode::Once if self.current < last => Some(self.current + 1),
+            PlayMode::Once => None,
+            PlayMode::Loop if self.current < last => Some(self.current + 1),
+            PlayMode::Loop => Some(0),
+            PlayMode::PingPong if last == 0 => Some(0),
+            PlayMode::PingPong => {
+                if (self.backward && self.current == 0) || (!self.backward && self.current == last)
+                {
+                    self.backward = !self.backward;
+                }
+                if self.backward {
+                    Some(self.current - 1)
+                } else {
+                    Some(self.current + 1)
+                }
+            }
+        }
+    }
+}
+
+/// Sprite.
+impl Canvas {
+    /// Draws a region of a sprite sheet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the region index is out of bounds.
+    pub fn draw_sprite(
+        &mut self,
+        sheet: &SpriteSheet,
+        region: usize,
+        position: impl Into<Vector2>,
+        transform: &SpriteTransform,
+        color: impl Into<Color>,
+    ) {
+        let mut source = sheet.regions[region];
+        let position = position.into();
+        let destination = Rectangle::new(
+            position.x,
+            position.y,
+            source.width * transform.scale.x,
+            source.height * transform.scale.y,
+        );
+        let origin = Vector2::new(
+            transform.origin.x * transform.scale.x,
+            transform.origin.y * transform.scale.y,
+        );
+        if transform.flip_x {
+            source.width = -source.width;
+        }
+        if transform.flip_y {
+            source.height = -source.height;
+        }
+        self.draw_texture_pro(
+            &sheet.texture,
+            source,
+            destination,
+            origin,
+            transform.rotation,
+            color,
+        );
+    }
+
+    /// Draws the current frame of an animation, if any.
+    pub fn draw_animation(
+        &mut self,
+        sheet: &SpriteSheet,
+        animation: &Animation,
+        position: impl Into<Vector2>,
+        transform: &SpriteTransform,
+        color: impl Into<Color>,
+    ) {
+        if let Some(region) = animation.current_region() {
+            self.draw_sprite(sheet, region, position, transform, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sprite::{Animation, PlayMode};
+
+    #[test]
+    fn test_animation_loop() {
+        let mut animation = Animation::from_regions(&[4, 5, 6], 0.1, PlayMode::Loop);
+        animation.set_event(0, "start");
+        animation.advance(0.05);
+        assert_eq!(animation.current_region(), Some(4));
+        animation.advance(0.1);
+        assert_eq!(animation.current_region(), Some(5));
+        assert_eq!(animation.advance(0.2), vec!["start"]);
+        assert_eq!(animation.current_frame(), 0);
+        assert!(!animation.is_finished());
+    }
+
+    #[test]
+    fn test_animation_once() {
+        let mut animation = Animation::from_regions(&[0, 1, 2], 0.1, PlayMode::Once);
+        animation.set_event(2, "end");
+        assert_eq!(animation.advance(1.0), vec!["end"]);
+        assert_eq!(animation.current_frame(), 2);
+        assert!(animation.is_finished());
+        animation.reset();
+        assert_eq!(animation.current_frame(), 0);
+        assert!(!animation.is_finished());
+    }
+
+    #[test]
+    fn test_animation_ping_pong() {
+        let mut animation = Animation::from_regions(&[0, 1, 2], 1.0, PlayMode::PingPong);
+        let frames: Vec<usize> = (0..6)
+            .map(|_| {
+                animation.advance(1.0);
+                animation.current_frame()
+            })
+            .collect();
+        assert_eq!(frames, vec![1, 2, 1, 0, 1, 2]);
+    }
+
+    #[test]
+    fn test_animation_catch_up() {
+        let mut animation = Animation::from_regions(&[0, 1, 2], 1.0, PlayMode::Loop);
+        animation.set_event(1, "step");
+        assert!(animation.advance(f32::NAN).is_empty());
+        assert!(animation.advance(f32::INFINITY).is_empty());
+        assert_eq!(animation.current_frame(), 0);
+        assert_eq!(animation.advance(1.0e9), vec!["step", "step"]);
+        assert_eq!(animation.current_frame(), 1);
+        let mut animation = Animation::from_regions(&[0, 1, 2], 1.0, PlayMode::PingPong);
+        animation.set_event(1, "step");
+        assert_eq!(animation.advance(1.0e9).len(), 2);
+        assert_eq!(animation.current_frame(), 0);
+    }
+}
@@ -1,18 +1,77 @@
 //! Text utilities.
 
 use crate::{
+    asset, bmfont,
     color::Color,
     drawing::Canvas,
     ffi,
+    image::{self, ExportImageError, Image, PixelFormat},
     math::{Rectangle, Vector2},
+    shader::Shader,
+    texture::Texture,
     window::{Handle, Window},
 };
-use std::{ffi::CString, fmt, path::Path, ptr, rc::Rc};
+use std::{
+    collections::{BTreeSet, HashMap},
+    ffi::CString,
+    fmt, fs,
+    iter::FromIterator,
+    mem,
+    ops::RangeInclusive,
+    os::raw::c_int,
+    path::Path,
+    ptr,
+    rc::Rc,
+    slice,
+};
+
+/// Default size of TTF/OTF fonts.
+const DEFAULT_TTF_FONT_SIZE: i32 = 32;
+
+/// First character of image fonts.
+const DEFAULT_FIRST_CHAR: c_int = 32;
+
+/// Maximum number of characters of image fonts, the size of `LoadFontFromImage` buffers.
+const MAX_IMAGE_FONT_CHARS: usize = 256;
+
+/// Default padding between characters in font atlases.
+const FONT_ATLAS_PADDING: c_int = 2;
+
+/// Padding around SDF characters, where the distance field fades out.
+const SDF_CHAR_PADDING: c_int = 4;
+
+/// SDF value of the characters outline.
+const SDF_ON_EDGE_VALUE: u8 = 128;
+
+/// SDF value change per pixel.
+const SDF_PIXEL_DIST_SCALE: f32 = 64.0;
+
+/// Bitmap font alpha threshold between transparent and opaque pixels.
+const BITMAP_ALPHA_THRESHOLD: u8 = 80;
+
+/// Fragment shader drawing SDF fonts, smoothing the outline according to the scale.
+const SDF_FRAGMENT_SHADER: &str = r#"#version 330
+in vec2 fragTexCoord;
+in vec4 fragColor;
+uniform sampler2D texture0;
+uniform vec4 colDiffuse;
+out vec4 finalColor;
+void main()
+{
+    float distanceFromOutline = texture(texture0, fragTexCoord).a - 0.5;
+    float distanceChangePerFragment = length(vec2(dFdx(distanceFromOutline), dFdy(distanceFromOutline)));
+    float alpha = smoothstep(-distanceChangePerFragment, distanceChangePerFragment, distanceFromOutline);
+    finalColor = vec4(fragColor.rgb, fragColor.a*alpha);
+}
+"#;
 
 /// Kinds of font loading errors.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum LoadFontError {
     ReadFailed,
+    UnsupportedFormat,
+    ShaderFailed,
+    InvalidFormat,
 }
 
 impl fmt::Display for LoadFontError {
@@ -23,10 +82,104 @@ impl fmt::Display for LoadFontError {
 
 impl std::error::Error for LoadFontError {}
 
+/// Kinds of font exporting errors.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExportFontError {
+    UnsupportedFormat,
+    WriteFailed,
+}
+
+impl fmt::Display for ExportFontError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{:?}", self)
+    }
+}
+
+impl std::error::Error for ExportFontError {}
+
+/// Kinds of font rasterizations.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FontType {
+    /// Anti-aliased bitmap.
+    Default = ffi::FONT_DEFAULT,
+    /// Aliased bitmap, best drawn at the base size.
+    Bitmap = ffi::FONT_BITMAP,
+    /// Signed distance field, staying crisp at any size.
+    Sdf = ffi::FONT_SDF,
+}
+
+/// Font loading options.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct FontOptions {
+    size: i32,
+    chars: CharSet,
+    font_type: FontType,
+    padding: i32,
+}
+
+impl FontOptions {
+    /// Creates new `FontOptions`, for ASCII characters rasterized at 32 pixels.
+    pub fn new() -> FontOptions {
+        FontOptions::default()
+    }
+
+    /// Sets the size in pixels.
+    pub fn size(mut self, size: i32) -> FontOptions {
+        self.size = size;
+        self
+    }
+
+    /// Sets the characters to bake.
+    pub fn chars(mut self, chars: CharSet) -> FontOptions {
+        self.chars = chars;
+        self
+    }
+
+    /// Sets the rasterization.
+    pub fn font_type(mut self, font_type: FontType) -> FontOptions {
+        self.font_type = font_type;
+        self
+    }
+
+    /// Sets the padding between characters in the atlas, in pixels.
+    pub fn padding(mut self, padding: i32) -> FontOptions {
+        self.padding = padding;
+        self
+    }
+}
+
+impl Default for FontOptions {
+    fn default() -> FontOptions {
+        FontOptions {
+            size: DEFAULT_TTF_FONT_SIZE,
+            chars: CharSet::ascii(),
+            font_type: FontType::Default,
+            padding: FONT_ATLAS_PADDING,
+        }
+    }
+}
+
+/// Metrics of a font character, in pixels at the font base size.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Glyph {
+    pub char: char,
+    /// Offset of the character image from the drawing position.
+    pub offset: Vector2,
+    /// Horizontal distance to the next character.
+    pub advance: f32,
+    /// Area of the character in the font atlas.
+    pub rectangle: Rectangle,
+}
+
 /// Font.
 pub struct Font {
     pub(crate) _handle: Rc<Handle>,
     pub(crate) raw: ffi::Font,
+    pub(crate) texture: Texture,
+    pub(crate) font_type: FontType,
+    pub(crate) sdf_shader: Option<Rc<Shader>>,
+    pub(crate) kerning: HashMap<(char, char), i32>,
 }
 
 impl Font {
@@ -34,16 +187,292 @@ impl Font {
     pub fn base_size(&self) -> i32 {
         self.raw.baseSize
     }
+
+    /// Returns the font rasterization.
+    pub fn font_type(&self) -> FontType {
+        self.font_type
+    }
+
+    /// Returns the font atlas, containing every character image.
+    pub fn texture(&self) -> &Texture {
+        &self.texture
+    }
+
+    /// Returns the metrics of the font characters.
+    pub fn glyphs(&self) -> impl Iterator<Item = Glyph> + '_ {
+        (0..self.chars_info().len()).filter_map(move |index| self.glyph_at(index))
+    }
+
+    /// Returns the metrics of a character, if the font contains it.
+    pub fn glyph(&self, char: char) -> Option<Glyph> {
+        self.find_glyph(char).and_then(|index| self.glyph_at(index))
+    }
+
+    /// Copies the image of a character, if the font contains it.
+    pub fn glyph_image(&self, char: char) -> Option<Image> {
+        let index = self.find_glyph(char)?;
+        unsafe {
+            Some(Image::from_raw(ffi::ImageCopy(
+                self.chars_info()[index].image,
+            )))
+        }
+    }
+
+    /// Returns the kerning between two consecutive characters, in pixels at the font base size.
+    ///
+    /// Only BMFont fonts define kerning pairs, applied by text layouts but not by text drawing.
+    pub fn kerning(&self, first: char, second: char) -> i32 {
+        self.kerning.get(&(first, second)).copied().unwrap_or(0)
+    }
+
+    /// Exports the font as a BMFont text descriptor, reloadable by `Window::load_bmfont`.
+    ///
+    /// The descriptor is written to `path` and the atlas next to it, as a PNG image with the same
+    /// name.
+    pub fn export_bmfont<P: AsRef<Path>>(&self, path: P) -> Result<(), ExportFontError> {
+        let path = path.as_ref();
+        let atlas_path = path.with_extension("png");
+        self.texture
+            .to_image()
+            .ok_or(ExportFontError::UnsupportedFormat)?
+            .export_png(&atlas_path)
+            .map_err(|error| match error {
+                ExportImageError::UnsupportedFormat => ExportFontError::UnsupportedFormat,
+                ExportImageError::WriteFailed => ExportFontError::WriteFailed,
+            })?;
+        let chars = self
+            .chars_info()
+            .iter()
+            .zip(self.chars_rectangles())
+            .map(|(info, rectangle)| bmfont::BmChar {
+                id: info.value as u32,
+                x: rectangle.x as i32,
+                y: rectangle.y as i32,
+                width: rectangle.width as i32,
+                height: rectangle.height as i32,
+                x_offset: info.offsetX,
+                y_offset: info.offsetY,
+                x_advance: info.advanceX,
+                page: 0,
+            })
+            .collect();
+        let mut kernings: Vec<_> = self
+            .kerning
+            .iter()
+            .map(|(&(first, second), &amount)| bmfont::BmKerning {
+                first: first as u32,
+                second: second as u32,
+                amount,
+            })
+            .collect();
+        kernings.sort_by_key(|kerning| (kerning.first, kerning.second));
+        let descriptor = bmfont::BmFont {
+            line_height: self.raw.baseSize,
+            scale_width: self.texture.width(),
+            scale_height: self.texture.height(),
+            pages: vec![atlas_path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default()],
+            chars,
+            kernings,
+        };
+        fs::write(path, bmfont::to_text(&descriptor)).map_err(|_| ExportFontError::WriteFailed)
+    }
+
+    /// Returns the characters information.
+    pub(crate) fn chars_info(&self) -> &[ffi::CharInfo] {
+        if self.raw.chars.is_null() {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(self.raw.chars, self.raw.charsCount as usize) }
+        }
+    }
+
+    /// Returns the characters rectangles in the atlas.
+    pub(crate) fn chars_rectangles(&self) -> &[Rectangle] {
+        if self.raw.recs.is_null() {
+            &[]
+        } else {
+            unsafe {
+                slice::from_raw_parts(
+                    self.raw.recs as *const Rectangle,
+                    self.raw.charsCount as usize,
+                )
+            }
+        }
+    }
+
+    /// Returns the glyph index of a character, falling back to `?` then to the first glyph.
+    pub(crate) fn glyph_index(&self, char: char) -> Option<usize> {
+        self.find_glyph(char)
+            .or_else(|| self.find_glyph('?'))
+            .or_else(|| self.chars_info().first().map(|_| 0))
+    }
+
+    /// Returns the glyph index of a character, if the font contains it.
+    fn find_glyph(&self, char: char) -> Option<usize> {
+        self.chars_info()
+            .iter()
+            .position(|info| info.value == char as c_int)
+    }
+
+    /// Returns the metrics of the glyph at an index, if its codepoint is valid.
+    fn glyph_at(&self, index: usize) -> Option<Glyph> {
+        let info = &self.chars_info()[index];
+        let rectangle = self.chars_rectangles()[index];
+        Some(Glyph {
+            char: char::from_u32(info.value as u32)?,
+            offset: Vector2::new(info.offsetX as f32, info.offsetY as f32),
+            advance: if info.advanceX != 0 {
+                info.advanceX as f32
+            } else {
+                rectangle.width
+            },
+            rectangle,
+        })
+    }
+
+    /// Returns the horizontal advance of a character drawn at a size.
+    pub(crate) fn advance(&self, char: char, size: f32) -> f32 {
+        let scale = size / self.raw.baseSize as f32;
+        match self.glyph_index(char) {
+            Some(index) if self.chars_info()[index].advanceX != 0 => {
+                self.chars_info()[index].advanceX as f32 * scale
+            }
+            Some(index) => self.chars_rectangles()[index].width * scale,
+            None => 0.0,
+        }
+    }
 }
 
 impl Drop for Font {
     fn drop(&mut self) {
         unsafe {
-            ffi::UnloadFont(self.raw);
+            // The atlas is unloaded along with its texture
+            let mut raw = self.raw;
+            raw.texture.id = 0;
+            ffi::UnloadFont(raw);
+        }
+    }
+}
+
+/// Set of characters baked into a font.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CharSet {
+    chars: BTreeSet<char>,
+}
+
+impl CharSet {
+    /// Creates an empty `CharSet`.
+    pub fn new() -> CharSet {
+        CharSet {
+            chars: BTreeSet::new(),
+        }
+    }
+
+    /// Creates a `CharSet` of the printable ASCII characters (U+0020 to U+007E), loaded by
+    /// default.
+    pub fn ascii() -> CharSet {
+        CharSet::new().add_range(' '..='~')
+    }
+
+    /// Creates a `CharSet` of the printable Latin-1 characters (ASCII, U+00A0 to U+00FF).
+    pub fn latin1() -> CharSet {
+        CharSet::ascii().add_range('\u{A0}'..='\u{FF}')
+    }
+
+    /// Creates a `CharSet` of the Cyrillic characters (U+0400 to U+04FF).
+    pub fn cyrillic() -> CharSet {
+        CharSet::new().add_range('\u{400}'..='\u{4FF}')
+    }
+
+    /// Creates a `CharSet` of the Japanese kana and punctuation (U+3000 to U+30FF).
+    pub fn kana() -> CharSet {
+        CharSet::new().add_range('\u{3000}'..='\u{30FF}')
+    }
+
+    /// Creates a `CharSet` of the characters of a text (e.g. every localized string), to bake large
+    /// scripts such as CJK ideographs partially.
+    pub fn from_text(text: &str) -> CharSet {
+        CharSet::new().add_text(text)
+    }
+
+    /// Adds a range of characters.
+    pub fn add_range(mut self, range: RangeInclusive<char>) -> CharSet {
+        self.chars.extend(range);
+        self
+    }
+
+    /// Adds the characters of a text, except control characters.
+    pub fn add_text(mut self, text: &str) -> CharSet {
+        self.chars
+            .extend(text.chars().filter(|char| !char.is_control()));
+        self
+    }
+
+    /// Adds the characters of another set.
+    pub fn union(mut self, other: &CharSet) -> CharSet {
+        self.chars.extend(&other.chars);
+        self
+    }
+
+    /// Returns the number of characters.
+    pub fn len(&self) -> usize {
+        self.chars.len()
+    }
+
+    /// Checks if the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.chars.is_empty()
+    }
+
+    /// Checks if the set contains a character.
+    pub fn contains(&self, char: char) -> bool {
+        self.chars.contains(&char)
+    }
+
+    /// Returns the characters, in ascending order.
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.chars.iter().copied()
+    }
+
+    /// Returns the codepoints, the default ones if the set is empty.
+    fn codepoints(&self) -> Vec<c_int> {
+        if self.is_empty() {
+            CharSet::ascii().codepoints()
+        } else {
+            self.chars().map(|char| char as c_int).collect()
         }
     }
 }
 
+impl Default for CharSet {
+    fn default() -> CharSet {
+        CharSet::ascii()
+    }
+}
+
+impl FromIterator<char> for CharSet {
+    fn from_iter<I: IntoIterator<Item = char>>(iterator: I) -> CharSet {
+        CharSet {
+            chars: iterator.into_iter().collect(),
+        }
+    }
+}
+
+impl Extend<char> for CharSet {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iterator: I) {
+        self.chars.extend(iterator);
+    }
+}
+
+/// Converts text to a C string, removing NUL characters instead of failing.
+pub(crate) fn text_to_c_string(text: &str) -> CString {
+    let bytes: Vec<u8> = text.bytes().filter(|&byte| byte != 0).collect();
+    unsafe { CString::from_vec_unchecked(bytes) }
+}
+
 /// Text.
 impl Canvas {
     /// Shows current FPS.
@@ -57,12 +486,14 @@ impl Canvas {
     /// Draws text using default font.
     pub fn draw_text(&mut self, text: &str, x: i32, y: i32, size: i32, color: impl Into<Color>) {
         unsafe {
-            let text = CString::new(text).unwrap();
+            let text = text_to_c_string(text);
             ffi::DrawText(text.as_ptr(), x, y, size, color.into().into());
         }
     }
 
     /// Draws text with additional parameters.
+    ///
+    /// SDF fonts are drawn with their shader, the custom shader mode being restored afterwards.
     pub fn draw_text_ex(
         &mut self,
         font: &Font,
@@ -73,7 +504,10 @@ impl Canvas {
         color: impl Into<Color>,
     ) {
         unsafe {
-            let text = CString::new(text).unwrap();
+            let text = text_to_c_string(text);
+            if let Some(shader) = &font.sdf_shader {
+                ffi::BeginShaderMode(shader.raw);
+            }
             ffi::DrawTextEx(
                 font.raw,
                 text.as_ptr(),
@@ -83,9 +517,14 @@ impl Canvas {
                 color.into().into(),
             );
         }
+        if font.sdf_shader.is_some() {
+            self.restore_shader_mode();
+        }
     }
 
     /// Draws text inside rectangle limits.
+    ///
+    /// SDF fonts are drawn with their shader, the custom shader mode being restored afterwards.
     #[allow(clippy::too_many_arguments)]
     pub fn draw_text_rec(
         &mut self,
@@ -98,7 +537,10 @@ impl Canvas {
         color: impl Into<Color>,
     ) {
         unsafe {
-            let text = CString::new(text).unwrap();
+            let text = text_to_c_string(text);
+            if let Some(shader) = &font.sdf_shader {
+                ffi::BeginShaderMode(shader.raw);
+            }
             ffi::DrawTextRec(
                 font.raw,
                 text.as_ptr(),
@@ -109,47 +551,238 @@ impl Canvas {
                 color.into().into(),
             );
         }
+        if font.sdf_shader.is_some() {
+            self.restore_shader_mode();
+        }
     }
 }
 
 /// Text.
 impl Window {
     /// Loads font from file into GPU memory (VRAM).
+    ///
+    /// BMFont descriptors (`.fnt`) are loaded by `load_bmfont`. The path is resolved through the
+    /// mounted asset sources first (TTF, OTF, BMFont and image fonts only).
     pub fn load_font<P: AsRef<Path>>(&self, path: P) -> Result<Font, LoadFontError> {
+        let path = path.as_ref();
+        if is_bmfont(path) {
+            return self.load_bmfont(path);
+        }
+        if let Some(bytes) = self
+            .read_mounted_asset(path)
+            .map_err(|_| LoadFontError::ReadFailed)?
+        {
+            let font = self.load_font_from_memory(
+                &bytes,
+                asset::file_type(path),
+                DEFAULT_TTF_FONT_SIZE,
+                &CharSet::ascii(),
+            )?;
+            unsafe {
+                ffi::SetTextureFilter(font.raw.texture, ffi::FILTER_POINT);
+            }
+            return Ok(font);
+        }
         unsafe {
-            let path = CString::new(path.as_ref().display().to_string()).unwrap();
+            let path = CString::new(path.display().to_string()).unwrap();
             let raw = ffi::LoadFont(path.as_ptr());
-            if raw.texture.id != 0 {
-                Ok(Font {
-                    _handle: self.handle.clone(),
-                    raw,
-                })
-            } else {
-                Err(LoadFontError::ReadFailed)
-            }
+            self.font_from_raw(raw, FontType::Default)
         }
     }
 
-    /// Loads font from file into GPU memory (VRAM) with extended parameters.
-    pub fn load_font_ex<P: AsRef<Path>>(&self, path: P, size: i32) -> Result<Font, LoadFontError> {
+    /// Loads font from file into GPU memory (VRAM) with extended parameters: the size and the
+    /// characters to bake (ASCII if empty).
+    ///
+    /// The path is resolved through the mounted asset sources first (TTF, OTF and image fonts
+    /// only).
+    pub fn load_font_ex<P: AsRef<Path>>(
+        &self,
+        path: P,
+        size: i32,
+        chars: &CharSet,
+    ) -> Result<Font, LoadFontError> {
+        let path = path.as_ref();
+        if let Some(bytes) = self
+            .read_mounted_asset(path)
+            .map_err(|_| LoadFontError::ReadFailed)?
+        {
+            return self.load_font_from_memory(&bytes, asset::file_type(path), size, chars);
+        }
         unsafe {
-            let path = CString::new(path.as_ref().display().to_string()).unwrap();
-            let raw = ffi::LoadFontEx(path.as_ptr(), size, ptr::null_mut(), 0);
-            if raw.texture.id != 0 {
-                Ok(Font {
-                    _handle: self.handle.clone(),
-                    raw,
-                })
-            } else {
-                Err(LoadFontError::ReadFailed)
+            let path = CString::new(path.display().to_string()).unwrap();
+            let mut codepoints = chars.codepoints();
+            let raw = ffi::LoadFontEx(
+                path.as_ptr(),
+                size,
+                codepoints.as_mut_ptr(),
+                codepoints.len() as c_int,
+            );
+            self.font_from_raw(raw, FontType::Default)
+        }
+    }
+
+    /// Loads font from encoded bytes (e.g. `include_bytes!`) into GPU memory (VRAM).
+    ///
+    /// The file type is the extension of the encoded file: `ttf` or `otf` for fonts rasterized at
+    /// `size` with the given characters (ASCII if empty), any format decoded by
+    /// [`Image::from_memory`] (e.g. `png`) for image fonts (characters separated by magenta,
+    /// starting at space, `size` and `chars` being ignored).
+    pub fn load_font_from_memory(
+        &self,
+        bytes: &[u8],
+        file_type: &str,
+        size: i32,
+        chars: &CharSet,
+    ) -> Result<Font, LoadFontError> {
+        let options = FontOptions::new().size(size).chars(chars.clone());
+        self.load_font_from_bytes(bytes, file_type, &options)
+    }
+
+    /// Loads font from file into GPU memory (VRAM) with options, such as SDF rasterization.
+    ///
+    /// The path is resolved through the mounted asset sources first. Options are ignored by image
+    /// fonts (e.g. PNG) and BMFont descriptors (`.fnt`, see `load_bmfont`).
+    pub fn load_font_with<P: AsRef<Path>>(
+        &self,
+        path: P,
+        options: &FontOptions,
+    ) -> Result<Font, LoadFontError> {
+        let path = path.as_ref();
+        if is_bmfont(path) {
+            return self.load_bmfont(path);
+        }
+        let bytes = self
+            .read_asset_or_file(path)
+            .ok_or(LoadFontError::ReadFailed)?;
+        self.load_font_from_bytes(&bytes, asset::file_type(path), options)
+    }
+
+    /// Loads font from encoded bytes with options.
+    fn load_font_from_bytes(
+        &self,
+        bytes: &[u8],
+        file_type: &str,
+        options: &FontOptions,
+    ) -> Result<Font, LoadFontError> {
+        let (raw, font_type) = match image::normalize_file_type(file_type).as_str() {
+            "ttf" | "otf" => (unsafe { load_font_ttf(bytes, options) }, options.font_type),
+            file_type if image::is_decodable(file_type) => {
+                let image = Image::from_memory(bytes).map_err(|_| LoadFontError::ReadFailed)?;
+                let raw =
+                    unsafe { load_image_font_data(&image, Color::MAGENTA, DEFAULT_FIRST_CHAR)? };
+                (raw, FontType::Default)
+            }
+            _ => return Err(LoadFontError::UnsupportedFormat),
+        };
+        let mut font = self.font_from_raw(raw, font_type)?;
+        if font_type == FontType::Sdf {
+            font.sdf_shader = Some(self.sdf_shader()?);
+            unsafe {
+                ffi::SetTextureFilter(font.raw.texture, ffi::FILTER_BILINEAR);
             }
         }
+        Ok(font)
+    }
+
+    /// Loads an AngelCode BMFont font into GPU memory (VRAM), with its kerning pairs.
+    ///
+    /// The descriptor (`.fnt`) is in the text or binary format, and its pages (PNG only) are
+    /// stacked into a single atlas. Paths are resolved through the mounted asset sources first,
+    /// pages being relative to the descriptor.
+    pub fn load_bmfont<P: AsRef<Path>>(&self, path: P) -> Result<Font, LoadFontError> {
+        let path = path.as_ref();
+        let bytes = self
+            .read_asset_or_file(path)
+            .ok_or(LoadFontError::ReadFailed)?;
+        let descriptor = bmfont::parse(&bytes).ok_or(LoadFontError::InvalidFormat)?;
+        if descriptor.pages.is_empty()
+            || descriptor
+                .chars
+                .iter()
+                .any(|char| char.page >= descriptor.pages.len())
+        {
+            return Err(LoadFontError::InvalidFormat);
+        }
+        let directory = path.parent().unwrap_or_else(|| Path::new(""));
+        let mut pages = Vec::new();
+        for page in &descriptor.pages {
+            let bytes = self
+                .read_asset_or_file(&directory.join(page))
+                .ok_or(LoadFontError::ReadFailed)?;
+            pages.push(Image::from_memory(&bytes).map_err(|_| LoadFontError::ReadFailed)?);
+        }
+        if descriptor.chars.iter().any(|char| {
+            let page = &pages[char.page];
+            !char.is_inside(page.width(), page.height())
+        }) {
+            return Err(LoadFontError::InvalidFormat);
+        }
+        let (atlas, offsets) = stack_pages(pages);
+        let raw = unsafe { load_bmfont_data(&descriptor, &atlas, &offsets) };
+        let mut font = self.font_from_raw(raw, FontType::Default)?;
+        font.kerning = descriptor
+            .kernings
+            .iter()
+            .filter_map(|kerning| {
+                let first = char::from_u32(kerning.first)?;
+                let second = char::from_u32(kerning.second)?;
+                Some(((first, second), kerning.amount))
+            })
+            .collect();
+        Ok(font)
+    }
+
+    /// Loads an image font into GPU memory (VRAM): characters separated by a key color, in
+    /// codepoint order from `first_char`.
+    ///
+    /// The path is resolved through the mounted asset sources first (PNG only). Images without a
+    /// key color grid, or with more than 256 characters, are rejected as invalid.
+    pub fn load_image_font<P: AsRef<Path>>(
+        &self,
+        path: P,
+        key: impl Into<Color>,
+        first_char: char,
+    ) -> Result<Font, LoadFontError> {
+        let bytes = self
+            .read_asset_or_file(path.as_ref())
+            .ok_or(LoadFontError::ReadFailed)?;
+        let image = Image::from_memory(&bytes).map_err(|_| LoadFontError::ReadFailed)?;
+        let raw = unsafe { load_image_font_data(&image, key.into(), first_char as c_int)? };
+        self.font_from_raw(raw, FontType::Default)
+    }
+
+    /// Returns the shader drawing SDF fonts, compiled once per window.
+    fn sdf_shader(&self) -> Result<Rc<Shader>, LoadFontError> {
+        let mut sdf_shader = self.sdf_shader.borrow_mut();
+        if let Some(shader) = &*sdf_shader {
+            return Ok(shader.clone());
+        }
+        let shader = self
+            .load_shader_from_source(None, Some(SDF_FRAGMENT_SHADER))
+            .map_err(|_| LoadFontError::ShaderFailed)?;
+        Ok(sdf_shader.get_or_insert(Rc::new(shader)).clone())
+    }
+
+    /// Wraps a loaded font, unloading it if its atlas failed to load.
+    fn font_from_raw(&self, raw: ffi::Font, font_type: FontType) -> Result<Font, LoadFontError> {
+        let raw = unsafe { check_font(raw)? };
+        Ok(Font {
+            _handle: self.handle.clone(),
+            raw,
+            texture: Texture {
+                _handle: self.handle.clone(),
+                raw: raw.texture,
+            },
+            font_type,
+            sdf_shader: None,
+            kerning: HashMap::new(),
+        })
     }
 
     /// Measures text width for default font.
     pub fn measure_text(&self, text: &str, size: i32) -> i32 {
         unsafe {
-            let text = CString::new(text).unwrap();
+            let text = text_to_c_string(text);
             ffi::MeasureText(text.as_ptr(), size)
         }
     }
@@ -157,8 +790,360 @@ impl Window {
     /// Measures text width with extended parameters.
     pub fn measure_text_ex(&self, font: &Font, text: &str, size: f32, spacing: f32) -> Vector2 {
         unsafe {
-            let text = CString::new(text).unwrap();
+            let text = text_to_c_string(text);
             ffi::MeasureTextEx(font.raw, text.as_ptr(), size, spacing).into()
         }
     }
 }
+
+/// Checks that a font loaded by raylib has an atlas, unloading it otherwise.
+///
+/// raylib returns a font without atlas when loading fails, and without characters when the file
+/// can't be read, which `UnloadFont` doesn't expect.
+unsafe fn check_font(raw: ffi::Font) -> Result<ffi::Font, LoadFontError> {
+    if raw.texture.id != 0 {
+        return Ok(raw);
+    }
+    if raw.chars.is_null() {
+        ffi::free(raw.recs as *mut _);
+    } else {
+        ffi::UnloadFont(raw);
+    }
+    Err(LoadFontError::ReadFailed)
+}
+
+/// Checks if a path is the one of a BMFont descriptor.
+fn is_bmfont(path: &Path) -> bool {
+    image::normalize_file_type(asset::file_type(path)) == "fnt"
+}
+
+/// Loads a TTF/OTF font from bytes, as `LoadFontEx` does from a file.
+///
+/// Every buffer is allocated with `malloc`, to be released by `UnloadFont`.
+unsafe fn load_font_ttf(bytes: &[u8], options: &FontOptions) -> ffi::Font {
+    let mut font: ffi::Font = mem::zeroed();
+    let size = options.size;
+    let codepoints = options.chars.codepoints();
+    let chars = load_font_data(bytes, size, &codepoints, options.font_type);
+    if chars.is_null() {
+        return font;
+    }
+    font.baseSize = size;
+    font.charsCount = codepoints.len() as c_int;
+    font.chars = chars;
+    let atlas = ffi::GenImageFontAtlas(
+        font.chars,
+        &mut font.recs,
+        font.charsCount,
+        font.baseSize,
+        options.padding,
+        0,
+    );
+    font.texture = ffi::LoadTextureFromImage(atlas);
+    // Character images taken from the atlas are required by image text drawing
+    for index in 0..codepoints.len() {
+        let char = &mut *font.chars.add(index);
+        ffi::UnloadImage(char.image);
+        char.image = ffi::ImageFromImage(atlas, *font.recs.add(index));
+    }
+    ffi::UnloadImage(atlas);
+    font
+}
+
+/// Stacks BMFont pages vertically into a single atlas, returning it with the pages offsets.
+///
+/// Grayscale pages are the alpha of white characters, as raylib does.
+fn stack_pages(pages: Vec<Image>) -> (Image, Vec<i32>) {
+    let width = pages.iter().map(Image::width).max().unwrap_or(0);
+    let height = pages.iter().map(Image::height).sum();
+    let mut pixels = vec![Color::BLANK; (width * height) as usize];
+    let mut offsets = Vec::new();
+    let mut y = 0;
+    for mut page in pages {
+        let grayscale = page.format() == Some(PixelFormat::Grayscale);
+        page.set_format(PixelFormat::R8G8B8A8);
+        if let Some(page_pixels) = page.pixels() {
+            for (row, line) in page_pixels.chunks(page.width() as usize).enumerate() {
+                let start = ((y + row as i32) * width) as usize;
+                for (pixel, &color) in pixels[start..start + line.len()].iter_mut().zip(line) {
+                    *pixel = if grayscale {
+                        Color::new(255, 255, 255, color.red)
+                    } else {
+                        color
+                    };
+                }
+            }
+        }
+        offsets.push(y);
+        y += page.height();
+    }
+    (Image::from_pixels(width, height, &pixels), offsets)
+}
+
+/// Loads an image font with `LoadFontFromImage`, checking its key color grid first.
+unsafe fn load_image_font_data(
+    image: &Image,
+    key: Color,
+    first_char: c_int,
+) -> Result<ffi::Font, LoadFontError> {
+    let mut converted = image.clone();
+    converted.set_format(PixelFormat::R8G8B8A8);
+    let pixels = converted.pixels().ok_or(LoadFontError::InvalidFormat)?;
+    image_font_char_count(
+        pixels,
+        converted.width() as usize,
+        converted.height() as usize,
+        key,
+    )
+    .ok_or(LoadFontError::InvalidFormat)?;
+    Ok(ffi::LoadFontFromImage(image.raw, key.into(), first_char))
+}
+
+/// Counts the characters of an image font, walking its key color grid as `LoadFontFromImage`
+/// does.
+///
+/// Returns `None` if raylib would read past the pixels, loop forever or overflow its buffers.
+fn image_font_char_count(
+    pixels: &[Color],
+    width: usize,
+    height: usize,
+    key: Color,
+) -> Option<usize> {
+    let is_key = |index: usize| pixels.get(index).map(|&pixel| pixel == key);
+    // Spacings are the key color borders above and left of the first character
+    let (mut char_spacing, mut line_spacing) = (0, 0);
+    while line_spacing < height {
+        char_spacing = 0;
+        while char_spacing < width && is_key(line_spacing * width + char_spacing)? {
+            char_spacing += 1;
+        }
+        if !is_key(line_spacing * width + char_spacing)? {
+            break;
+        }
+        line_spacing += 1;
+    }
+    let mut char_height = 0;
+    while !is_key((line_spacing + char_height) * width + char_spacing)? {
+        char_height += 1;
+    }
+    if char_height + line_spacing == 0 {
+        return None;
+    }
+    let mut count = 0;
+    let mut line = 0;
+    while line_spacing + line * (char_height + line_spacing) < height {
+        let row = (line_spacing + line * (char_height + line_spacing)) * width;
+        let mut x = char_spacing;
+        while x < width && !is_key(row + x)? {
+            if count == MAX_IMAGE_FONT_CHARS {
+                return None;
+            }
+            count += 1;
+            let mut char_width = 0;
+            while !is_key(row + x + char_width)? {
+                char_width += 1;
+            }
+            x += char_width + char_spacing;
+        }
+        line += 1;
+    }
+    if count > 0 {
+        Some(count)
+    } else {
+        None
+    }
+}
+
+/// Creates a font from a BMFont descriptor and its stacked pages, as `LoadBMFont` does.
+///
+/// Character rectangles must lie inside their pages. Every buffer is allocated with `malloc`, to
+/// be released by `UnloadFont`.
+unsafe fn load_bmfont_data(
+    descriptor: &bmfont::BmFont,
+    atlas: &Image,
+    offsets: &[i32],
+) -> ffi::Font {
+    let mut font: ffi::Font = mem::zeroed();
+    let count = descriptor.chars.len();
+    font.baseSize = descriptor.line_height;
+    font.charsCount = count as c_int;
+    font.chars = ffi::malloc(count * mem::size_of::<ffi::CharInfo>()) as *mut ffi::CharInfo;
+    font.recs = ffi::malloc(count * mem::size_of::<ffi::Rectangle>()) as *mut ffi::Rectangle;
+    for (index, char) in descriptor.chars.iter().enumerate() {
+        let rectangle = ffi::Rectangle {
+            x: char.x as f32,
+            y: (char.y + offsets[char.page]) as f32,
+            width: char.width as f32,
+            height: char.height as f32,
+        };
+        font.recs.add(index).write(rectangle);
+        font.chars.add(index).write(ffi::CharInfo {
+            value: char.id as c_int,
+            offsetX: char.x_offset,
+            offsetY: char.y_offset,
+            advanceX: char.x_advance,
+            image: ffi::ImageFromImage(atlas.raw, rectangle),
+        });
+    }
+    font.texture = ffi::LoadTextureFromImage(atlas.raw);
+    font
+}
+
+/// Rasterizes characters of a TTF/OTF font from bytes, as `LoadFontData` does from a file.
+///
+/// Returns a null pointer if the font data is invalid.
+unsafe fn load_font_data(
+    bytes: &[u8],
+    size: i32,
+    codepoints: &[c_int],
+    font_type: FontType,
+) -> *mut ffi::CharInfo {
+    let mut info: ffi::stbtt_fontinfo = mem::zeroed();
+    if ffi::stbtt_InitFont(&mut info, bytes.as_ptr(), 0) == 0 {
+        return ptr::null_mut();
+    }
+    let scale = ffi::stbtt_ScaleForPixelHeight(&info, size as f32);
+    // Ascent is the font baseline
+    let (mut ascent, mut descent, mut line_gap) = (0, 0, 0);
+    ffi::stbtt_GetFontVMetrics(&info, &mut ascent, &mut descent, &mut line_gap);
+    let chars =
+        ffi::malloc(codepoints.len() * mem::size_of::<ffi::CharInfo>()) as *mut ffi::CharInfo;
+    for (index, &codepoint) in codepoints.iter().enumerate() {
+        let (mut width, mut height, mut offset_x, mut offset_y, mut advance_x) = (0, 0, 0, 0, 0);
+        let data = match font_type {
+            FontType::Sdf if codepoint == 32 => ptr::null_mut(),
+            FontType::Sdf => ffi::stbtt_GetCodepointSDF(
+                &info,
+                scale,
+                codepoint,
+                SDF_CHAR_PADDING,
+                SDF_ON_EDGE_VALUE,
+                SDF_PIXEL_DIST_SCALE,
+                &mut width,
+                &mut height,
+                &mut offset_x,
+                &mut offset_y,
+            ),
+            _ => ffi::stbtt_GetCodepointBitmap(
+                &info,
+                scale,
+                scale,
+                codepoint,
+                &mut width,
+                &mut height,
+                &mut offset_x,
+                &mut offset_y,
+            ),
+        };
+        if font_type == FontType::Bitmap && !data.is_null() {
+            // Aliased rasterization, avoiding anti-aliasing
+            for pixel in slice::from_raw_parts_mut(data, (width * height) as usize) {
+                *pixel = if *pixel < BITMAP_ALPHA_THRESHOLD {
+                    0
+                } else {
+                    255
+                };
+            }
+        }
+        ffi::stbtt_GetCodepointHMetrics(&info, codepoint, &mut advance_x, ptr::null_mut());
+        let advance_x = (advance_x as f32 * scale) as c_int;
+        let image = if codepoint == 32 {
+            // Empty image for the space character, required for atlas packing
+            ffi::free(data as *mut _);
+            let mut image = ffi::GenImageColor(advance_x, size, Color::BLANK.into());
+            ffi::ImageFormat(&mut image, ffi::UNCOMPRESSED_GRAYSCALE);
+            image
+        } else {
+            ffi::Image {
+                data: data as *mut _,
+                width,
+                height,
+                mipmaps: 1,
+                format: ffi::UNCOMPRESSED_GRAYSCALE,
+            }
+        };
+        chars.add(index).write(ffi::CharInfo {
+            value: codepoint,
+            offsetX: offset_x,
+            offsetY: offset_y + (ascent as f32 * scale) as c_int,
+            advanceX: advance_x,
+            image,
+        });
+    }
+    chars
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        color::Color,
+        ffi,
+        text::{check_font, image_font_char_count, text_to_c_string, CharSet, LoadFontError},
+    };
+    use std::{ffi::CString, mem, ptr};
+
+    #[test]
+    fn test_char_set() {
+        assert_eq!(CharSet::ascii().len(), 95);
+        assert_eq!(CharSet::default(), CharSet::ascii());
+        let chars = CharSet::ascii()
+            .union(&CharSet::cyrillic())
+            .add_text("こんにちは\n");
+        assert!(chars.contains('Ж'));
+        assert!(chars.contains('ん'));
+        assert!(!chars.contains('\n'));
+        assert_eq!(chars.len(), 95 + 256 + 5);
+        assert_eq!(CharSet::new().codepoints().len(), 95);
+    }
+
+    #[test]
+    fn test_text_to_c_string() {
+        assert_eq!(text_to_c_string("a\0b\0").to_str(), Ok("ab"));
+        assert_eq!(text_to_c_string("Привет").to_str(), Ok("Привет"));
+    }
+
+    #[test]
+    fn test_image_font_char_count() {
+        let (k, a) = (Color::MAGENTA, Color::WHITE);
+        #[rustfmt::skip]
+        let pixels = [
+            k, k, k, k, k, k, k,
+            k, a, a, k, a, k, k,
+            k, a, a, k, a, k, k,
+            k, k, k, k, k, k, k,
+            k, k, k, k, k, k, k,
+        ];
+        assert_eq!(image_font_char_count(&pixels, 7, 5, k), Some(2));
+        assert_eq!(image_font_char_count(&pixels[..21], 7, 3, k), None);
+        assert_eq!(image_font_char_count(&[k; 35], 7, 5, k), None);
+        assert_eq!(image_font_char_count(&[a; 35], 7, 5, k), None);
+        assert_eq!(image_font_char_count(&[], 0, 0, k), None);
+        let row = |width: usize| {
+            let mut pixels = vec![k; 3 * width];
+            for x in (1..width).step_by(2) {
+                pixels[width + x] = a;
+            }
+            pixels
+        };
+        assert_eq!(image_font_char_count(&row(512), 512, 3, k), Some(256));
+        assert_eq!(image_font_char_count(&row(514), 514, 3, k), None);
+    }
+
+    #[test]
+    fn test_check_font_missing_file() {
+        let path = CString::new("missing.ttf").unwrap();
+        let raw = unsafe { ffi::LoadFontEx(path.as_ptr(), 32, ptr::null_mut(), 0) };
+        assert!(raw.chars.is_null());
+        assert_eq!(
+            unsafe { check_font(raw) }.err(),
+            Some(LoadFontError::ReadFailed)
+        );
+    }
+
+    #[test]
+    fn test_stbtt_fontinfo_layout() {
+        assert_eq!(mem::size_of::<ffi::stbtt_fontinfo>(), unsafe {
+            ffi::rayquaza_stbtt_fontinfo_size
+        });
+    }
+}
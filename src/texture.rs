@@ -1,18 +1,22 @@
 //! Texture management.
 
 use crate::{
+    asset::{self, ExtractedAsset},
     color::Color,
     drawing::Canvas,
     ffi,
+    image::{self, Image, PixelFormat},
     math::{Rectangle, Vector2},
     window::{Handle, Window},
 };
-use std::{ffi::CString, fmt, path::Path, rc::Rc};
+use std::{ffi::CString, fmt, mem, path::Path, rc::Rc};
 
 /// Kinds of texture loading errors.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum LoadTextureError {
     ReadFailed,
+    CreationFailed,
+    UnsupportedFormat,
 }
 
 impl fmt::Display for LoadTextureError {
@@ -23,6 +27,85 @@ impl fmt::Display for LoadTextureError {
 
 impl std::error::Error for LoadTextureError {}
 
+/// Kinds of texture filters.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TextureFilter {
+    Point = ffi::FILTER_POINT,
+    Bilinear = ffi::FILTER_BILINEAR,
+    Trilinear = ffi::FILTER_TRILINEAR,
+    Anisotropic4x = ffi::FILTER_ANISOTROPIC_4X,
+    Anisotropic8x = ffi::FILTER_ANISOTROPIC_8X,
+    Anisotropic16x = ffi::FILTER_ANISOTROPIC_16X,
+}
+
+/// Kinds of texture wrapping modes.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TextureWrap {
+    Repeat = ffi::WRAP_REPEAT,
+    Clamp = ffi::WRAP_CLAMP,
+    MirrorRepeat = ffi::WRAP_MIRROR_REPEAT,
+    MirrorClamp = ffi::WRAP_MIRROR_CLAMP,
+}
+
+/// Kinds of n-patch layouts.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NPatchLayout {
+    NinePatch = ffi::NPT_9PATCH,
+    ThreePatchVertical = ffi::NPT_3PATCH_VERTICAL,
+    ThreePatchHorizontal = ffi::NPT_3PATCH_HORIZONTAL,
+}
+
+/// N-patch info: a texture region whose borders don't stretch.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NPatchInfo {
+    pub source: Rectangle,
+    pub left: i32,
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+    pub layout: NPatchLayout,
+}
+
+impl NPatchInfo {
+    /// Creates a `NPatchInfo`.
+    #[inline]
+    pub fn new(
+        source: impl Into<Rectangle>,
+        left: i32,
+        top: i32,
+        right: i32,
+        bottom: i32,
+        layout: NPatchLayout,
+    ) -> NPatchInfo {
+        NPatchInfo {
+            source: source.into(),
+            left,
+            top,
+            right,
+            bottom,
+            layout,
+        }
+    }
+}
+
+impl From<&NPatchInfo> for NPatchInfo {
+    #[inline]
+    fn from(info: &NPatchInfo) -> NPatchInfo {
+        *info
+    }
+}
+
+impl Into<ffi::NPatchInfo> for NPatchInfo {
+    #[inline]
+    fn into(self) -> ffi::NPatchInfo {
+        unsafe { mem::transmute(self) }
+    }
+}
+
 /// 2D texture.
 pub struct Texture {
     pub(crate) _handle: Rc<Handle>,
@@ -39,6 +122,108 @@ impl Texture {
     pub fn height(&self) -> i32 {
         self.raw.height
     }
+
+    /// Returns the texture pixel format, `None` if unknown (e.g. texture failing to load).
+    pub fn format(&self) -> Option<PixelFormat> {
+        PixelFormat::from_raw(self.raw.format)
+    }
+
+    /// Returns the number of mipmap levels.
+    pub fn mipmaps(&self) -> i32 {
+        self.raw.mipmaps
+    }
+
+    /// Sets the scaling filter, trilinear and anisotropic filters require mipmaps.
+    pub fn set_filter(&mut self, filter: TextureFilter) {
+        unsafe {
+            ffi::SetTextureFilter(self.raw, filter as i32);
+        }
+    }
+
+    /// Sets the wrapping mode, used when drawing outside of the texture coordinates.
+    pub fn set_wrap(&mut self, wrap: TextureWrap) {
+        unsafe {
+            ffi::SetTextureWrap(self.raw, wrap as i32);
+        }
+    }
+
+    /// Generates the mipmaps of the texture.
+    pub fn generate_mipmaps(&mut self) {
+        unsafe {
+            ffi::GenTextureMipmaps(&mut self.raw);
+        }
+    }
+
+    /// Uploads new pixel data, in the texture format, to the whole texture.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the format is compressed or unknown, or if the data size does not match the
+    /// texture size.
+    pub fn update(&mut self, pixels: &[u8]) {
+        assert!(matches!(self.format(), Some(format) if !format.is_compressed()));
+        unsafe {
+            let size = ffi::GetPixelDataSize(self.raw.width, self.raw.height, self.raw.format);
+            assert_eq!(pixels.len(), size as usize);
+            ffi::UpdateTexture(self.raw, pixels.as_ptr() as *const _);
+        }
+    }
+
+    /// Uploads new pixel data, in the texture format, to a part of the texture.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the format is compressed or unknown, if the rectangle is out of the texture or if
+    /// the data size does not match the rectangle size.
+    pub fn update_rect(&mut self, rectangle: impl Into<Rectangle>, pixels: &[u8]) {
+        assert!(matches!(self.format(), Some(format) if !format.is_compressed()));
+        let rectangle = rectangle.into();
+        let (x, y) = (rectangle.x as i32, rectangle.y as i32);
+        let (width, height) = (rectangle.width as i32, rectangle.height as i32);
+        assert!(x >= 0 && y >= 0 && width >= 0 && height >= 0);
+        assert!(x + width <= self.raw.width && y + height <= self.raw.height);
+        unsafe {
+            let size = ffi::GetPixelDataSize(width, height, self.raw.format);
+            assert_eq!(pixels.len(), size as usize);
+            let (mut internal_format, mut format, mut type_) = (0, 0, 0);
+            ffi::rlGetGlTextureFormats(
+                self.raw.format,
+                &mut internal_format,
+                &mut format,
+                &mut type_,
+            );
+            // raylib 3.0 has no partial texture update, so OpenGL is called directly
+            if let (Some(bind_texture), Some(texture_sub_image)) =
+                (ffi::glad_glBindTexture, ffi::glad_glTexSubImage2D)
+            {
+                bind_texture(ffi::GL_TEXTURE_2D, self.raw.id);
+                texture_sub_image(
+                    ffi::GL_TEXTURE_2D,
+                    0,
+                    x,
+                    y,
+                    width,
+                    height,
+                    format,
+                    type_,
+                    pixels.as_ptr() as *const _,
+                );
+                bind_texture(ffi::GL_TEXTURE_2D, 0);
+            }
+        }
+    }
+
+    /// Reads the texture back into an image, `None` for compressed formats.
+    pub fn to_image(&self) -> Option<Image> {
+        unsafe {
+            let raw = ffi::GetTextureData(self.raw);
+            if !raw.data.is_null() {
+                Some(Image::from_raw(raw))
+            } else {
+                None
+            }
+        }
+    }
 }
 
 impl Drop for Texture {
@@ -49,6 +234,74 @@ impl Drop for Texture {
     }
 }
 
+/// Render texture (framebuffer) to draw offscreen.
+pub struct RenderTexture {
+    pub(crate) texture: Texture,
+    pub(crate) raw: ffi::RenderTexture2D,
+}
+
+impl RenderTexture {
+    /// Returns the render texture width.
+    pub fn width(&self) -> i32 {
+        self.texture.width()
+    }
+
+    /// Returns the render texture height.
+    pub fn height(&self) -> i32 {
+        self.texture.height()
+    }
+
+    /// Returns the color buffer attachment as a texture.
+    ///
+    /// The texture is stored upside down (OpenGL convention), so it must be drawn with a negative
+    /// source height to appear as rendered.
+    pub fn texture(&self) -> &Texture {
+        &self.texture
+    }
+
+    /// Sets the scaling filter of the color buffer attachment, trilinear and anisotropic filters
+    /// require mipmaps.
+    pub fn set_filter(&mut self, filter: TextureFilter) {
+        self.texture.set_filter(filter);
+    }
+
+    /// Sets the wrapping mode of the color buffer attachment.
+    pub fn set_wrap(&mut self, wrap: TextureWrap) {
+        self.texture.set_wrap(wrap);
+    }
+
+    /// Generates the mipmaps of the color buffer attachment, to be done again after drawing.
+    pub fn generate_mipmaps(&mut self) {
+        self.texture.generate_mipmaps();
+        self.raw.texture = self.texture.raw;
+    }
+
+    /// Reads the color buffer back into an image, as rendered (not upside down), `None` for
+    /// compressed formats.
+    pub fn to_image(&self) -> Option<Image> {
+        unsafe {
+            let mut raw = ffi::GetTextureData(self.raw.texture);
+            if !raw.data.is_null() {
+                ffi::ImageFlipVertical(&mut raw);
+                Some(Image::from_raw(raw))
+            } else {
+                None
+            }
+        }
+    }
+}
+
+impl Drop for RenderTexture {
+    fn drop(&mut self) {
+        unsafe {
+            // The color buffer attachment is unloaded along with its texture
+            let mut raw = self.raw;
+            raw.texture.id = 0;
+            ffi::UnloadRenderTexture(raw);
+        }
+    }
+}
+
 /// Texture.
 impl Canvas {
     /// Draws a texture.
@@ -59,6 +312,40 @@ impl Canvas {
         }
     }
 
+    /// Draws a texture (vector version).
+    #[inline]
+    pub fn draw_texture_vec(
+        &mut self,
+        texture: &Texture,
+        position: impl Into<Vector2>,
+        color: impl Into<Color>,
+    ) {
+        unsafe {
+            ffi::DrawTextureV(texture.raw, position.into().into(), color.into().into());
+        }
+    }
+
+    /// Draws a texture with rotation (in degrees) and scale.
+    #[inline]
+    pub fn draw_texture_ex(
+        &mut self,
+        texture: &Texture,
+        position: impl Into<Vector2>,
+        rotation: f32,
+        scale: f32,
+        color: impl Into<Color>,
+    ) {
+        unsafe {
+            ffi::DrawTextureEx(
+                texture.raw,
+                position.into().into(),
+                rotation,
+                scale,
+                color.into().into(),
+            );
+        }
+    }
+
     // Draws a part of a texture defined by a rectangle.
     #[inline]
     pub fn draw_texture_rec(
@@ -77,14 +364,151 @@ impl Canvas {
             );
         }
     }
+
+    /// Draws a texture in a quad, repeated `tiling` times and shifted by `offset` (in texture
+    /// coordinates).
+    #[inline]
+    pub fn draw_texture_quad(
+        &mut self,
+        texture: &Texture,
+        tiling: impl Into<Vector2>,
+        offset: impl Into<Vector2>,
+        quad: impl Into<Rectangle>,
+        color: impl Into<Color>,
+    ) {
+        unsafe {
+            ffi::DrawTextureQuad(
+                texture.raw,
+                tiling.into().into(),
+                offset.into().into(),
+                quad.into().into(),
+                color.into().into(),
+            );
+        }
+    }
+
+    /// Draws a part of a texture into a destination rectangle, rotated (in degrees) around an
+    /// origin relative to the destination.
+    ///
+    /// A negative source width or height flips the texture.
+    #[inline]
+    pub fn draw_texture_pro(
+        &mut self,
+        texture: &Texture,
+        source: impl Into<Rectangle>,
+        destination: impl Into<Rectangle>,
+        origin: impl Into<Vector2>,
+        rotation: f32,
+        color: impl Into<Color>,
+    ) {
+        unsafe {
+            ffi::DrawTexturePro(
+                texture.raw,
+                source.into().into(),
+                destination.into().into(),
+                origin.into().into(),
+                rotation,
+                color.into().into(),
+            );
+        }
+    }
+
+    /// Draws a part of a texture repeated to fill a destination rectangle, with scaled tiles,
+    /// rotated (in degrees) around an origin relative to the destination.
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_texture_tiled(
+        &mut self,
+        texture: &Texture,
+        source: impl Into<Rectangle>,
+        destination: impl Into<Rectangle>,
+        origin: impl Into<Vector2>,
+        rotation: f32,
+        scale: f32,
+        color: impl Into<Color>,
+    ) {
+        let source = source.into();
+        let destination = destination.into();
+        let origin = origin.into();
+        let color = color.into();
+        let tile_width = source.width.abs() * scale;
+        let tile_height = source.height.abs() * scale;
+        if tile_width <= 0.0 || tile_height <= 0.0 {
+            return;
+        }
+        let mut y = 0.0;
+        while y < destination.height {
+            let height = tile_height.min(destination.height - y);
+            let mut x = 0.0;
+            while x < destination.width {
+                // Last tiles are cut, every tile rotates around the same origin
+                let width = tile_width.min(destination.width - x);
+                self.draw_texture_pro(
+                    texture,
+                    Rectangle::new(
+                        source.x,
+                        source.y,
+                        source.width * width / tile_width,
+                        source.height * height / tile_height,
+                    ),
+                    Rectangle::new(destination.x, destination.y, width, height),
+                    (origin.x - x, origin.y - y),
+                    rotation,
+                    color,
+                );
+                x += tile_width;
+            }
+            y += tile_height;
+        }
+    }
+
+    /// Draws a texture region into a destination rectangle, stretching only its center (n-patch),
+    /// rotated (in degrees) around an origin relative to the destination.
+    #[inline]
+    pub fn draw_texture_npatch(
+        &mut self,
+        texture: &Texture,
+        info: impl Into<NPatchInfo>,
+        destination: impl Into<Rectangle>,
+        origin: impl Into<Vector2>,
+        rotation: f32,
+        color: impl Into<Color>,
+    ) {
+        unsafe {
+            ffi::DrawTextureNPatch(
+                texture.raw,
+                info.into().into(),
+                destination.into().into(),
+                origin.into().into(),
+                rotation,
+                color.into().into(),
+            );
+        }
+    }
 }
 
 /// Texture.
 impl Window {
     /// Loads texture from file into GPU memory (VRAM).
+    ///
+    /// The path is resolved through the mounted asset sources first.
     pub fn load_texture<P: AsRef<Path>>(&self, path: P) -> Result<Texture, LoadTextureError> {
+        let path = path.as_ref();
+        let file_type = asset::file_type(path);
+        if image::is_decodable(&image::normalize_file_type(file_type)) {
+            if let Some(bytes) = self
+                .read_mounted_asset(path)
+                .map_err(|_| LoadTextureError::ReadFailed)?
+            {
+                return self.load_texture_from_memory(&bytes, file_type);
+            }
+        }
+        // Compressed formats (DDS, KTX...) are read by raylib from files
+        let extracted = self
+            .extract_asset(path)
+            .map_err(|_| LoadTextureError::ReadFailed)?;
+        let path = extracted.as_ref().map_or(path, ExtractedAsset::path);
         unsafe {
-            let path = CString::new(path.as_ref().display().to_string()).unwrap();
+            let path = CString::new(path.display().to_string()).unwrap();
             let raw = ffi::LoadTexture(path.as_ptr());
             if raw.id != 0 {
                 Ok(Texture {
@@ -96,4 +520,57 @@ impl Window {
             }
         }
     }
+
+    /// Loads texture from encoded bytes (e.g. `include_bytes!`) into GPU memory (VRAM).
+    ///
+    /// The file type is the extension of the encoded file, any format decoded by
+    /// [`Image::from_memory`] (e.g. `png`, `jpg` or `hdr`).
+    pub fn load_texture_from_memory(
+        &self,
+        bytes: &[u8],
+        file_type: &str,
+    ) -> Result<Texture, LoadTextureError> {
+        if !image::is_decodable(&image::normalize_file_type(file_type)) {
+            return Err(LoadTextureError::UnsupportedFormat);
+        }
+        let image = Image::from_memory(bytes).map_err(|_| LoadTextureError::ReadFailed)?;
+        self.load_texture_from_image(&image)
+    }
+
+    /// Loads texture from an image into GPU memory (VRAM).
+    pub fn load_texture_from_image(&self, image: &Image) -> Result<Texture, LoadTextureError> {
+        unsafe {
+            let raw = ffi::LoadTextureFromImage(image.raw);
+            if raw.id != 0 {
+                Ok(Texture {
+                    _handle: self.handle.clone(),
+                    raw,
+                })
+            } else {
+                Err(LoadTextureError::ReadFailed)
+            }
+        }
+    }
+
+    /// Loads a render texture (framebuffer) into GPU memory (VRAM).
+    pub fn load_render_texture(
+        &self,
+        width: i32,
+        height: i32,
+    ) -> Result<RenderTexture, LoadTextureError> {
+        unsafe {
+            let raw = ffi::LoadRenderTexture(width, height);
+            if raw.id != 0 {
+                Ok(RenderTexture {
+                    texture: Texture {
+                        _handle: self.handle.clone(),
+                        raw: raw.texture,
+                    },
+                    raw,
+                })
+            } else {
+                Err(LoadTextureError::CreationFailed)
+            }
+        }
+    }
 }
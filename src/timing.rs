@@ -19,14 +19,18 @@ impl Window {
     }
 
     /// Returns time in seconds for last frame drawn.
+    ///
+    /// Recorded along with the input state, see `Window::start_input_recording`.
     #[inline]
     pub fn frame_time(&self) -> f32 {
-        unsafe { ffi::GetFrameTime() }
+        self.recorded(|frame, _| frame.frame_time)
+            .unwrap_or_else(|| unsafe { ffi::GetFrameTime() })
     }
 
     /// Returns elapsed time in seconds since window creation.
     #[inline]
     pub fn time(&self) -> f64 {
-        unsafe { ffi::GetTime() }
+        self.recorded(|frame, _| frame.time)
+            .unwrap_or_else(|| unsafe { ffi::GetTime() })
     }
 }
@@ -1,7 +1,11 @@
 //! The `Window` type.
 
-use crate::{ffi, math::Vector2};
+use crate::{
+    asset::VirtualFileSystem, capture::ScreenRecording, ffi, input::InputState, math::Vector2,
+    shader::Shader, text,
+};
 use std::{
+    cell::RefCell,
     error::Error,
     ffi::{CStr, CString},
     fmt,
@@ -38,6 +42,10 @@ impl Error for CreateWindowError {}
 /// Window.
 pub struct Window {
     pub(crate) handle: Rc<Handle>,
+    pub(crate) input: RefCell<InputState>,
+    pub(crate) capture: RefCell<Option<ScreenRecording>>,
+    pub(crate) assets: Rc<RefCell<VirtualFileSystem>>,
+    pub(crate) sdf_shader: RefCell<Option<Rc<Shader>>>,
 }
 
 impl Window {
@@ -202,7 +210,7 @@ impl Window {
             if clipboard.is_null() {
                 None
             } else {
-                Some(CStr::from_ptr(clipboard).to_str().unwrap().to_string())
+                Some(CStr::from_ptr(clipboard).to_string_lossy().into_owned())
             }
         }
     }
@@ -210,7 +218,7 @@ impl Window {
     /// Sets clipboard text content.
     pub fn set_clipboard(&mut self, text: &str) {
         unsafe {
-            let text = CString::new(text).unwrap();
+            let text = text::text_to_c_string(text);
             ffi::SetClipboardText(text.as_ptr());
         }
     }
@@ -275,6 +283,23 @@ impl WindowBuilder {
         self
     }
 
+    /// Sets to render offscreen, without display server nor GPU (e.g. for tests on CI machines).
+    ///
+    /// Requires the `headless` feature (Linux only), which builds raylib against the OSMesa
+    /// software renderer (`libOSMesa`, loaded at runtime) instead of a windowing system: windows
+    /// are never shown and receive no input. Draw into a `RenderTexture` and read it back with
+    /// `RenderTexture::to_image`.
+    ///
+    /// The feature is not additive: it replaces the windowing backend of the whole program, so
+    /// every window renders offscreen once it is enabled, headless or not. Enable it only in
+    /// binaries or test configurations that never need a visible window.
+    #[cfg(feature = "headless")]
+    pub fn headless(mut self) -> WindowBuilder {
+        self.flags |= ffi::FLAG_WINDOW_ALWAYS_RUN;
+        self.flags &= !(ffi::FLAG_FULLSCREEN_MODE | ffi::FLAG_VSYNC_HINT);
+        self
+    }
+
     /// Sets to allow window running while minimized.
     pub fn always_run(mut self) -> WindowBuilder {
         self.flags |= ffi::FLAG_WINDOW_ALWAYS_RUN;
@@ -307,6 +332,10 @@ impl WindowBuilder {
                     INITIALIZED.store(true, Ordering::Relaxed);
                     Ok(Window {
                         handle: Rc::new(Handle),
+                        input: Default::default(),
+                        capture: Default::default(),
+                        assets: Default::default(),
+                        sdf_shader: Default::default(),
                     })
                 } else {
                     Err(CreateWindowError::InitializationFailed)